@@ -58,7 +58,8 @@
 //! cyclic_list = { default-features = false }
 //! ```
 //!
-//! Each node of the list `List<T>` is allocated on heap, which contains:
+//! Each node of the list `List<T>` is allocated on heap (or by the allocator
+//! passed to [`List::new_in`], see [`list::alloc`]), which contains:
 //! - the `next` pointer that points to the next element (or the ghost node if it
 //!   is the last element in the list);
 //! - the `prev` pointer that points to the previous element (or the ghost node if
@@ -78,6 +79,45 @@
 //! *n* - 1, and the ghost node is always indexed by *n*. (In an empty list, the
 //! ghost nodes is indexed by 0, which is equal to its length 0).
 //!
+//! # Runtime Validation
+//!
+//! With `debug_assertions`, every node attached to or detached from a list is
+//! checked to be properly linked with its neighbours.
+//!
+//! The `paranoid` feature keeps these checks in release builds, and additionally
+//! checks that every node (or range of nodes) being attached or detached belongs
+//! to the list:
+//! ```text
+//! [dependencies]
+//! cyclic_list = { features = ["paranoid"] }
+//! ```
+//!
+//! **Warning**: The ownership checks walk through the whole list, so attaching
+//! or detaching nodes takes *O*(*n*) time instead of *O*(1) with this feature.
+//!
+//! Besides, every node is stamped with a unique generation in such builds, so that
+//! a stale position saved before (e.g. a [`CursorMark`] whose element has been
+//! removed, while another element reuses its memory) is detected with a panic
+//! instead of silently pointing at the wrong node.
+//!
+//! # Serialization
+//!
+//! The `rkyv` feature implements the `Archive`, `Serialize` and `Deserialize`
+//! traits of [rkyv](https://docs.rs/rkyv) for `List<T>`. A list is archived as a
+//! contiguous `ArchivedVec<T::Archived>`, just like a `Vec<T>`, and the nodes are
+//! rebuilt when it is deserialized:
+//! ```text
+//! [dependencies]
+//! cyclic_list = { features = ["rkyv"] }
+//! ```
+//!
+//! # Fuzzing
+//!
+//! The `arbitrary` feature implements `Arbitrary` of
+//! [arbitrary](https://docs.rs/arbitrary) for `List<T>`, and adds the `list::fuzz`
+//! module with a `ListOp` enum to drive a list with random operations in fuzz
+//! targets.
+//!
 //! # Iteration
 //!
 //! Iterating over a list is by the [`Iter`] and [`IterMut`] iterators. These are
@@ -185,6 +225,7 @@
 //! [`IterMut`]: crate::IterMut
 //! [`Cursor`]: crate::list::cursor::Cursor
 //! [`CursorMut`]: crate::list::cursor::CursorMut
+//! [`CursorMark`]: crate::list::cursor::CursorMark
 //! [`CursorIter`]: crate::list::cursor::CursorIter
 //! [`CursorIterMut`]: crate::list::cursor::CursorIterMut
 //! [`CursorBackIter`]: crate::list::cursor::CursorBackIter
@@ -199,6 +240,8 @@
 #[doc(inline)]
 pub use list::iterator::{IntoIter, Iter, IterMut};
 #[doc(inline)]
+pub use list::view::{ListView, ListViewMut};
+#[doc(inline)]
 pub use list::List;
 
 pub mod list;
@@ -1,53 +1,63 @@
-use crate::list::algorithms::drain::{Drain, DrainFilter};
+use crate::list::algorithms::drain::{Drain, DrainFilter, RemoveEveryNth};
+use crate::list::alloc::RawAlloc;
+use crate::list::cursor::{Cursor, CursorMut, Entry, OccupiedEntry, VacantEntry};
 use crate::list::List;
+use crate::IntoIter;
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
+use std::ops::RangeBounds;
 
 mod drain;
 mod sort;
 
-impl<T: PartialEq> PartialEq for List<T> {
+impl<T: PartialEq, A: RawAlloc> PartialEq for List<T, A> {
     fn eq(&self, other: &Self) -> bool {
         self.iter().eq(other)
     }
 }
 
-impl<T: Eq> Eq for List<T> {}
+impl<T: Eq, A: RawAlloc> Eq for List<T, A> {}
 
-impl<T: PartialOrd> PartialOrd for List<T> {
+impl<T: PartialOrd, A: RawAlloc> PartialOrd for List<T, A> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.iter().partial_cmp(other)
     }
 }
 
-impl<T: Ord> Ord for List<T> {
+impl<T: Ord, A: RawAlloc> Ord for List<T, A> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         self.iter().cmp(other)
     }
 }
 
-impl<T: Clone> Clone for List<T> {
+impl<T: Clone, A: RawAlloc + Clone> Clone for List<T, A> {
     fn clone(&self) -> Self {
-        self.iter().cloned().collect()
+        List::from_iter_in(self.iter().cloned(), self.alloc.clone())
     }
 
+    /// Reuses the nodes and the elements of `self` as many as possible: the
+    /// common prefix is overwritten by [`Clone::clone_from`], and then the
+    /// list is either truncated or extended to the length of `other`.
     fn clone_from(&mut self, other: &Self) {
-        let iter_other = other.iter();
+        let mut iter_other = other.iter();
         let mut cursor_mut = self.cursor_start_mut();
-        for elem_other in iter_other {
-            // FIXME incorrect cursor moves
-            match cursor_mut.current_mut() {
-                None => cursor_mut.insert(elem_other.clone()),
-                Some(elem) => elem.clone_from(elem_other),
+        while let Some(elem) = cursor_mut.current_mut() {
+            match iter_other.next() {
+                Some(elem_other) => elem.clone_from(elem_other),
+                None => break,
             }
             cursor_mut.move_next_cyclic();
         }
+        // Drop the rest of `self` if `other` is shorter; otherwise, the
+        // cursor is at the ghost node and nothing is split off.
         cursor_mut.split();
+        self.extend(iter_other.cloned());
     }
 }
 
-impl<T: Hash> Hash for List<T> {
+impl<T: Hash, A: RawAlloc> Hash for List<T, A> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         let mut len = 0_usize;
         for elt in self {
@@ -58,7 +68,7 @@ impl<T: Hash> Hash for List<T> {
     }
 }
 
-impl<T> List<T> {
+impl<T, A: RawAlloc> List<T, A> {
     /// Returns `true` if the `List` contains an element equal to the given value.
     ///
     /// # Examples
@@ -82,193 +92,1745 @@ impl<T> List<T> {
         self.iter().any(|e| e == x)
     }
 
+    /// Removes the first element that is equal to `x` from the list, and
+    /// returns it, or returns `None` if there is no such element.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([1, 2, 3, 2]);
+    ///
+    /// assert_eq!(list.remove_item(&2), Some(2));
+    /// assert_eq!(list.remove_item(&5), None);
+    /// assert_eq!(list.into_vec(), vec![1, 3, 2]);
+    /// ```
+    pub fn remove_item(&mut self, x: &T) -> Option<T>
+    where
+        T: PartialEq<T>,
+    {
+        let mut cursor = self.cursor_start_mut();
+        while let Some(current) = cursor.current() {
+            if current == x {
+                return cursor.remove();
+            }
+            cursor.move_next_cyclic();
+        }
+        None
+    }
+
+    /// Removes all the elements that are equal to `x` from the list, and
+    /// returns the number of the removed elements.
+    ///
+    /// The removed nodes are unlinked from the list, and the other nodes
+    /// stay put.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([1, 2, 3, 2, 2]);
+    ///
+    /// assert_eq!(list.remove_all(&2), 3);
+    /// assert_eq!(list.remove_all(&5), 0);
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(list.len(), 2);
+    /// assert_eq!(list.into_vec(), vec![1, 3]);
+    /// ```
+    pub fn remove_all(&mut self, x: &T) -> usize
+    where
+        T: PartialEq<T>,
+    {
+        let mut removed = 0;
+        self.retain_elements(|element| {
+            let keep = *element != *x;
+            removed += usize::from(!keep);
+            keep
+        });
+        removed
+    }
+
+    /// Replaces all the elements that are equal to `old` with clones of
+    /// `new`, and returns the number of the replaced elements.
+    ///
+    /// The elements are overwritten in place, so the nodes stay put.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut tokens = List::from(["let", "x", "=", "x", "+", "1"]);
+    ///
+    /// assert_eq!(tokens.replace_all(&"x", "y"), 2);
+    /// assert_eq!(tokens.into_vec(), vec!["let", "y", "=", "y", "+", "1"]);
+    /// ```
+    pub fn replace_all(&mut self, old: &T, new: T) -> usize
+    where
+        T: PartialEq<T> + Clone,
+    {
+        let mut replaced = 0;
+        self.iter_mut()
+            .filter(|element| *element == old)
+            .for_each(|element| {
+                *element = new.clone();
+                replaced += 1;
+            });
+        replaced
+    }
+
+    /// Walks this list and `other` in lockstep, calling `f` with a mutable
+    /// reference to each element of this list and a reference to the element
+    /// of `other` at the same position.
+    ///
+    /// It stops at the end of the shorter list, so the rest of the longer list
+    /// is untouched.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(min(*m*, *n*)) time, where *m*
+    /// and *n* are the lengths of the two lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut totals = List::from([10, 20, 30, 40]);
+    /// let deltas = List::from([1, -2, 3]);
+    ///
+    /// totals.zip_mut_with(&deltas, |total, delta| *total += delta);
+    /// assert_eq!(totals.into_vec(), vec![11, 18, 33, 40]);
+    /// ```
+    pub fn zip_mut_with<U, B: RawAlloc, F>(&mut self, other: &List<U, B>, mut f: F)
+    where
+        F: FnMut(&mut T, &U),
+    {
+        self.iter_mut().zip(other).for_each(|(a, b)| f(a, b));
+    }
+
     /// Creates a draining iterator that removes and yields all
     /// the elements in the list.
     ///
-    /// When the iterator is dropped, all elements are removed
-    /// from the list, even if the iterator was not fully consumed.
-    /// If the iterator is not dropped (with mem::forget for example),
-    /// it is unspecified how many elements are removed.
+    /// When the iterator is dropped, all elements are removed
+    /// from the list, even if the iterator was not fully consumed.
+    /// If the iterator is not dropped (with mem::forget for example),
+    /// it is unspecified how many elements are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut v = List::from_iter([1, 2, 3]);
+    /// let u: Vec<_> = v.drain().collect();
+    ///
+    /// assert!(v.is_empty());
+    /// assert_eq!(u, &[1, 2, 3]);
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T, A> {
+        Drain::new(self)
+    }
+
+    /// Removes the specified range from the list, and returns all removed
+    /// elements as an owning iterator.
+    ///
+    /// The nodes in the range are detached from the list at once, so the
+    /// elements are removed even if the iterator is not consumed.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time for seeking the range,
+    /// and *O*(1) time for detaching it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(0..10);
+    /// let drained: Vec<_> = list.drain_range(3..7).collect();
+    ///
+    /// assert_eq!(drained, vec![3, 4, 5, 6]);
+    /// assert_eq!(list.into_vec(), vec![0, 1, 2, 7, 8, 9]);
+    /// ```
+    pub fn drain_range<R>(&mut self, range: R) -> IntoIter<T, A>
+    where
+        R: RangeBounds<usize>,
+        A: Clone,
+    {
+        let (start, end) = self.resolve_range(range);
+        self.split_range(start, end).into_iter()
+    }
+
+    /// Creates an iterator which uses a closure to determine
+    /// if an element should be removed.
+    ///
+    /// If the closure returns true, then the element is removed
+    /// and yielded. If the closure returns false, the element
+    /// will remain in the list and will not be yielded by the
+    /// iterator.
+    ///
+    /// Note that `drain_filter` lets you mutate every element
+    /// in the filter closure, regardless of whether you choose
+    /// to keep or remove it.
+    ///
+    /// # Examples
+    ///
+    /// Splitting a list into evens and odds, reusing the original
+    /// list:
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut numbers = List::<u32>::new();
+    /// numbers.extend(&[1, 2, 3, 4, 5, 6, 8, 9, 11, 13, 14, 15]);
+    ///
+    /// let evens = numbers.drain_filter(|x| *x % 2 == 0).collect::<List<_>>();
+    /// let odds = numbers;
+    ///
+    /// assert_eq!(Vec::from_iter(evens), vec![2, 4, 6, 8, 14]);
+    /// assert_eq!(Vec::from_iter(odds), vec![1, 3, 5, 9, 11, 13, 15]);
+    /// ```
+    pub fn drain_filter<F>(&mut self, f: F) -> DrainFilter<'_, T, F, A>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        DrainFilter::new(self, f)
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element
+    /// in the given range should be removed.
+    ///
+    /// It is the same as [`drain_filter`], except that only the elements
+    /// in `range` are visited. The elements outside `range` are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list (only the
+    /// starting point is checked without `feature = "length"`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut numbers = List::from_iter(0..10);
+    ///
+    /// let evens = numbers.drain_filter_range(2..8, |x| *x % 2 == 0).collect::<List<_>>();
+    ///
+    /// assert_eq!(Vec::from_iter(evens), vec![2, 4, 6]);
+    /// assert_eq!(Vec::from_iter(numbers), vec![0, 1, 3, 5, 7, 8, 9]);
+    /// ```
+    ///
+    /// [`drain_filter`]: List::drain_filter
+    #[doc(alias = "extract_if_range")]
+    pub fn drain_filter_range<R, F>(&mut self, range: R, f: F) -> DrainFilter<'_, T, F, A>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool,
+    {
+        let (start, end) = self.resolve_range(range);
+        DrainFilter::new_range(self, start, end, f)
+    }
+
+    /// Moves all the elements `e` such that `pred(&mut e)` returns `true` to
+    /// the back of `target`, keeping their order.
+    ///
+    /// Unlike [`drain_filter`], the matched nodes are unlinked from the list
+    /// and linked into `target` directly, so no elements are moved and no
+    /// nodes are allocated.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocator of `target` is not equal to that of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([1, 2, 3, 4, 5, 6]);
+    /// let mut evens = List::from([0]);
+    ///
+    /// list.drain_matching_into(|x| *x % 2 == 0, &mut evens);
+    ///
+    /// assert_eq!(list.into_vec(), vec![1, 3, 5]);
+    /// assert_eq!(evens.into_vec(), vec![0, 2, 4, 6]);
+    /// ```
+    ///
+    /// [`drain_filter`]: List::drain_filter
+    pub fn drain_matching_into<F>(&mut self, mut pred: F, target: &mut List<T, A>)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.check_same_alloc(target);
+        let ghost = self.ghost_node();
+        let mut node = self.front_node();
+        while node != ghost {
+            // SAFETY: `node` is a valid non-ghost node in the list, and it is
+            // relinked to `target` as a whole, so both lists are well-formed.
+            unsafe {
+                let next = node.as_ref().next;
+                if pred(&mut node.as_mut().element) {
+                    let node = self.detach_node(node);
+                    target.attach_node(target.ghost_node(), node);
+                }
+                node = next;
+            }
+        }
+    }
+
+    /// Creates an iterator that removes and yields the elements of the list
+    /// in the order of the [Josephus problem]: counting cyclically from the
+    /// front, every `n`-th element is removed, until the list is empty.
+    ///
+    /// Counting continues from the element after the removed one, and wraps
+    /// around from the back to the front of the list.
+    ///
+    /// If the iterator is dropped before it is exhausted, the remaining
+    /// elements stay in the list.
+    ///
+    /// # Complexity
+    ///
+    /// Each removal should compute in *O*(min(*n*, *len*)) time with
+    /// `feature = "length"`, or *O*(*n*) time otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([1, 2, 3, 4, 5, 6, 7]);
+    ///
+    /// let order: Vec<_> = list.remove_every_nth(3).collect();
+    /// assert_eq!(order, vec![3, 6, 2, 7, 5, 1, 4]);
+    /// assert!(list.is_empty());
+    ///
+    /// let mut list = List::from([1, 2, 3, 4, 5, 6, 7]);
+    ///
+    /// // The survivor of the Josephus problem.
+    /// list.remove_every_nth(3).take(6).for_each(drop);
+    /// assert_eq!(list.into_vec(), vec![4]);
+    /// ```
+    ///
+    /// [Josephus problem]: https://en.wikipedia.org/wiki/Josephus_problem
+    pub fn remove_every_nth(&mut self, n: usize) -> RemoveEveryNth<'_, T, A> {
+        assert!(n != 0, "Cannot remove every 0th element");
+        RemoveEveryNth::new(self, n)
+    }
+
+    /// Removes consecutive repeated elements in the list according to the
+    /// [`PartialEq`] trait implementation.
+    ///
+    /// If the list is sorted, this removes all duplicates.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([1, 2, 2, 3, 2]);
+    ///
+    /// list.dedup();
+    ///
+    /// assert_eq!(list.into_vec(), vec![1, 2, 3, 2]);
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Removes all but the first of consecutive elements in the list that
+    /// resolve to the same key.
+    ///
+    /// If the list is sorted, this removes all duplicates.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([10, 20, 21, 30, 20]);
+    ///
+    /// list.dedup_by_key(|i| *i / 10);
+    ///
+    /// assert_eq!(list.into_vec(), vec![10, 20, 30, 20]);
+    /// ```
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes all but the first of consecutive elements in the list
+    /// satisfying a given equality relation.
+    ///
+    /// The `same_bucket` function is passed references to two elements
+    /// from the list and must determine if the elements compare equal.
+    /// The elements are passed in opposite order from their order in the
+    /// list, so if `same_bucket(a, b)` returns `true`, `a` is removed.
+    ///
+    /// If the list is sorted, this removes all duplicates.
+    ///
+    /// The removed nodes are unlinked from the list, and the other nodes
+    /// stay put.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from(["foo", "bar", "Bar", "baz", "bar"]);
+    ///
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    ///
+    /// assert_eq!(list.into_vec(), vec!["foo", "bar", "baz", "bar"]);
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let ghost = self.ghost_node();
+        let mut previous = self.front_node();
+        if previous == ghost {
+            return;
+        }
+        // SAFETY: `previous` and `node` are distinct valid non-ghost nodes in
+        // the list, so the mutable references to their elements do not alias,
+        // and they are released before `node` is removed.
+        unsafe {
+            let mut node = previous.as_ref().next;
+            while node != ghost {
+                let next = node.as_ref().next;
+                if same_bucket(
+                    &mut (*node.as_ptr()).element,
+                    &mut (*previous.as_ptr()).element,
+                ) {
+                    drop(self.remove_node(node));
+                } else {
+                    previous = node;
+                }
+                node = next;
+            }
+        }
+    }
+
+    /// Removes all but the first occurrence of each element in the list,
+    /// i.e. all the duplicates, not only the consecutive ones.
+    ///
+    /// The removed nodes are unlinked from the list, and the other nodes
+    /// stay put.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) expected time and *O*(*n*)
+    /// memory, as the distinct elements are collected into a hash set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([3, 1, 3, 2, 1, 4]);
+    ///
+    /// list.unique();
+    ///
+    /// assert_eq!(list.into_vec(), vec![3, 1, 2, 4]);
+    /// ```
+    pub fn unique(&mut self)
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::new();
+        // The elements to keep are found before removing any, since `seen`
+        // borrows the elements.
+        let keep: Vec<_> = self.iter().map(|element| seen.insert(element)).collect();
+        let mut keep = keep.into_iter();
+        self.retain_elements(|_| keep.next() == Some(true));
+    }
+
+    /// Removes all but the first of the elements in the list that resolve to
+    /// the same key, i.e. all the duplicates, not only the consecutive ones.
+    ///
+    /// The removed nodes are unlinked from the list, and the other nodes
+    /// stay put.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) expected time and *O*(*n*)
+    /// memory, as the distinct keys are collected into a hash set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from(["apple", "avocado", "banana", "blueberry", "cherry"]);
+    ///
+    /// list.unique_by_key(|s| s.chars().next());
+    ///
+    /// assert_eq!(list.into_vec(), vec!["apple", "banana", "cherry"]);
+    /// ```
+    pub fn unique_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Hash + Eq,
+    {
+        let mut seen = HashSet::new();
+        self.retain_elements(|element| seen.insert(key(element)));
+    }
+
+    /// Splits the list into the maximal runs of adjacent elements, where the
+    /// predicate `same_group` returns `true` for each pair of adjacent elements
+    /// in a run.
+    ///
+    /// The predicate is called on two elements following themselves, that
+    /// means the predicate is called on `list[0]` and `list[1]` then on
+    /// `list[1]` and `list[2]` and so on.
+    ///
+    /// The nodes of the list are reused by the returned lists, so no elements
+    /// are moved or cloned. An empty list returns an empty list of groups.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([1, 1, 1, 3, 3, 2, 2, 2]);
+    ///
+    /// let groups = list.group_by(|a, b| a == b);
+    /// assert_eq!(
+    ///     groups,
+    ///     List::from([List::from([1, 1, 1]), List::from([3, 3]), List::from([2, 2, 2])]),
+    /// );
+    ///
+    /// // Split the list into ascending runs
+    /// let list = List::from([1, 2, 2, 3, 1, 2, 0]);
+    ///
+    /// let groups = list.group_by(|a, b| a <= b);
+    /// assert_eq!(
+    ///     groups,
+    ///     List::from([List::from([1, 2, 2, 3]), List::from([1, 2]), List::from([0])]),
+    /// );
+    /// ```
+    #[doc(alias = "chunk_by")]
+    pub fn group_by<F>(mut self, mut same_group: F) -> List<List<T, A>, A>
+    where
+        F: FnMut(&T, &T) -> bool,
+        A: Clone,
+    {
+        let mut groups = List::new_in(self.alloc.clone());
+        let mut cursor = self.cursor_start_mut();
+        if cursor.move_next().is_err() {
+            return groups;
+        }
+        while let (Some(previous), Some(current)) = (cursor.previous(), cursor.current()) {
+            if !same_group(previous, current) {
+                groups.push_back(
+                    cursor
+                        .split_before()
+                        .expect("The cursor must not be at the front"),
+                );
+            }
+            cursor.move_next_cyclic();
+        }
+        groups.push_back(self);
+        groups
+    }
+
+    /// Splits the list into lists separated by the elements that match
+    /// `pred`. The matched elements are dropped.
+    ///
+    /// Like [`slice::split`], if the first or the last element is matched,
+    /// an empty list is the first or the last piece, and if two matched
+    /// elements are adjacent, an empty list is between them. An empty list
+    /// returns a single empty piece.
+    ///
+    /// The nodes of the list are reused by the returned lists, so no elements
+    /// are moved or cloned.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([10, 40, 33, 20, 1, 0]);
+    ///
+    /// let pieces = list.split_when(|x| x % 3 == 0);
+    /// assert_eq!(
+    ///     pieces,
+    ///     vec![List::from([10, 40]), List::from([20, 1]), List::new()],
+    /// );
+    /// ```
+    pub fn split_when<P>(mut self, mut pred: P) -> Vec<List<T, A>>
+    where
+        P: FnMut(&T) -> bool,
+        A: Clone,
+    {
+        let alloc = self.alloc.clone();
+        let mut pieces = Vec::new();
+        let mut cursor = self.cursor_start_mut();
+        while let Some(current) = cursor.current() {
+            if pred(current) {
+                pieces.push(
+                    cursor
+                        .split_before()
+                        .unwrap_or_else(|| List::new_in(alloc.clone())),
+                );
+                cursor.remove();
+            } else {
+                cursor.move_next_cyclic();
+            }
+        }
+        pieces.push(self);
+        pieces
+    }
+
+    /// Splits the list into lists ended by the elements that match `pred`.
+    /// The matched elements are kept at the end of each piece.
+    ///
+    /// Like [`slice::split_inclusive`], if the last element is matched, it
+    /// ends the last piece, and no empty piece follows it. An empty list
+    /// returns no pieces.
+    ///
+    /// The nodes of the list are reused by the returned lists, so no elements
+    /// are moved or cloned.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([10, 40, 33, 20, 1, 0]);
+    ///
+    /// let pieces = list.split_when_inclusive(|x| x % 3 == 0);
+    /// assert_eq!(
+    ///     pieces,
+    ///     vec![List::from([10, 40, 33]), List::from([20, 1, 0])],
+    /// );
+    /// ```
+    pub fn split_when_inclusive<P>(mut self, mut pred: P) -> Vec<List<T, A>>
+    where
+        P: FnMut(&T) -> bool,
+        A: Clone,
+    {
+        let mut pieces = Vec::new();
+        let mut cursor = self.cursor_start_mut();
+        while let Some(current) = cursor.current() {
+            let matched = pred(current);
+            cursor.move_next_cyclic();
+            if matched {
+                pieces.push(
+                    cursor
+                        .split_before()
+                        .expect("The cursor must not be at the front"),
+                );
+            }
+        }
+        if !self.is_empty() {
+            pieces.push(self);
+        }
+        pieces
+    }
+
+    /// Sort the list.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n* * log(*n*)) time and *O*(1) memory.
+    ///
+    /// # Current Implementation
+    ///
+    /// The current algorithm is a natural merge sort. It detects the sorted
+    /// (or strictly descending) runs of the list, and merges them pairwise until
+    /// the whole list is sorted, so a sorted or nearly sorted list is sorted in
+    /// nearly *O*(*n*) time. There is no extra temporary storage during merging.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    /// let mut list = List::from_iter([5, 2, 4, 3, 1]);
+    ///
+    /// list.sort();
+    ///
+    /// assert_eq!(list.into_vec(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        sort::merge_sort(self, |a, b| a.lt(b));
+    }
+
+    /// Sort the list with a comparator function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// The comparator function must define a total ordering for the
+    /// elements in the list. If the ordering is not total, the order
+    /// of the elements is unspecified. An order is a total order if
+    /// it is (for all `a`, `b` and `c`):
+    /// - total and antisymmetric: exactly one of `a < b`, `a == b`
+    ///   or `a > b` is true, and
+    /// - transitive, `a < b` and `b < c` implies `a < c`. The same
+    ///   must hold for both `==` and `>`.
+    ///
+    /// For example, while [`f64`] doesn’t implement [`Ord`] because
+    /// `NaN != NaN`, we can use `partial_cmp` as our sort function
+    /// when we know the list doesn’t contain a `NaN`.
+    /// ```
+    /// use cyclic_list::List;
+    /// let mut floats = List::from([5f64, 4.0, 1.0, 3.0, 2.0]);
+    /// floats.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// assert_eq!(floats.into_vec(), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `compare` panics, the panic is propagated, and the list is left in
+    /// an unspecified order, but it still contains all the original elements.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n* * log(*n*)) time and *O*(1) memory.
+    ///
+    /// # Current Implementation
+    ///
+    /// The current algorithm is a natural merge sort. It detects the sorted
+    /// (or strictly descending) runs of the list, and merges them pairwise until
+    /// the whole list is sorted, so a sorted or nearly sorted list is sorted in
+    /// nearly *O*(*n*) time. There is no extra temporary storage during merging.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// let mut v = List::from([5, 4, 1, 3, 2]);
+    /// v.sort_by(|a, b| a.cmp(b));
+    /// assert_eq!(v.to_vec(), vec![1, 2, 3, 4, 5]);
+    ///
+    /// // reverse sorting
+    /// v.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(v.to_vec(), vec![5, 4, 3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::merge_sort(self, |a, b| compare(a, b) == Ordering::Less)
+    }
+
+    /// Sorts the elements in the specified range of the list with a comparator
+    /// function, leaving the rest of the list untouched.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements). See
+    /// [`sort_by`](List::sort_by) for the requirements of the comparator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// If `compare` panics, the panic is propagated, and the range is left in
+    /// an unspecified order, but it still contains all the original elements.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n* * log(*n*)) time and *O*(1) memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// let mut v = List::from([9, 5, 4, 1, 3, 2, 0]);
+    /// v.sort_range_by(1..6, |a, b| a.cmp(b));
+    /// assert_eq!(v.to_vec(), vec![9, 1, 2, 3, 4, 5, 0]);
+    ///
+    /// v.sort_range_by(3.., |a, b| b.cmp(a));
+    /// assert_eq!(v.to_vec(), vec![9, 1, 2, 5, 4, 3, 0]);
+    /// ```
+    pub fn sort_range_by<R, F>(&mut self, range: R, mut compare: F)
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (start, end) = self.resolve_range(range);
+        let (start_node, end_node) = self.range_nodes(start, end);
+        // SAFETY: `start_node..end_node` is a valid range of the list.
+        unsafe {
+            sort::merge_sort_range(start_node.as_ref().prev, end_node, |a, b| {
+                compare(a, b) == Ordering::Less
+            })
+        };
+    }
+
+    /// Sorts the list with a key extraction function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements)
+    /// and *O*(*m* \* *n* \* log(*n*)) worst-case, where the
+    /// key function is *O*(*m*).
+    ///
+    /// For expensive key functions (e.g. functions that are not simple
+    /// property accesses or basic operations),
+    /// [`sort_by_cached_key`](List::sort_by_cached_key) is likely to be
+    /// significantly faster, as it does not recompute element keys.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n* * log(*n*)) time and *O*(1) memory.
+    ///
+    /// # Current Implementation
+    ///
+    /// The current algorithm is a natural merge sort. It detects the sorted
+    /// (or strictly descending) runs of the list, and merges them pairwise until
+    /// the whole list is sorted, so a sorted or nearly sorted list is sorted in
+    /// nearly *O*(*n*) time. There is no extra temporary storage during merging.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// let mut v = List::from([-5i32, 4, 1, -3, 2]);
+    ///
+    /// v.sort_by_key(|k| k.abs());
+    /// assert_eq!(v.into_vec(), vec![1, 2, -3, 4, -5]);
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        sort::merge_sort(self, |a, b| f(a).lt(&f(b)));
+    }
+
+    /// Sorts the list, but might not preserve the order of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n* * log(*n*)) time and *O*(*n*) memory.
+    ///
+    /// # Current Implementation
+    ///
+    /// The current algorithm collects the pointers of all the nodes into a
+    /// temporary buffer, sorts the buffer with [`slice::sort_unstable_by`],
+    /// and then relinks the nodes in the sorted order. It is usually faster
+    /// than [`sort`] since it avoids chasing pointers through the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// let mut v = List::from([-5, 4, 1, -3, 2]);
+    ///
+    /// v.sort_unstable();
+    /// assert_eq!(v.into_vec(), vec![-5, -3, 1, 2, 4]);
+    /// ```
+    ///
+    /// [`sort`]: List::sort
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        sort::buffered_sort(self, T::cmp);
+    }
+
+    /// Sorts the list with a comparator function, but might not preserve the
+    /// order of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// The comparator function must define a total ordering for the elements
+    /// in the list. If the ordering is not total, the order of the elements
+    /// is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// If `compare` panics, the panic is propagated, and the list is left in
+    /// an unspecified order, but it still contains all the original elements.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n* * log(*n*)) time and *O*(*n*) memory.
+    ///
+    /// # Current Implementation
+    ///
+    /// See [`sort_unstable`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// let mut v = List::from([5, 4, 1, 3, 2]);
+    /// v.sort_unstable_by(|a, b| a.cmp(b));
+    /// assert_eq!(v.to_vec(), vec![1, 2, 3, 4, 5]);
+    ///
+    /// // reverse sorting
+    /// v.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(v.to_vec(), vec![5, 4, 3, 2, 1]);
+    /// ```
+    ///
+    /// [`sort_unstable`]: List::sort_unstable
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::buffered_sort(self, compare);
+    }
+
+    /// Sorts the list with a key extraction function, but might not preserve
+    /// the order of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*m* \* *n* \* log(*n*)) time and
+    /// *O*(*n*) memory, where the key function is *O*(*m*).
+    ///
+    /// # Current Implementation
+    ///
+    /// See [`sort_unstable`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// let mut v = List::from([-5i32, 4, 1, -3, 2]);
+    ///
+    /// v.sort_unstable_by_key(|k| k.abs());
+    /// assert_eq!(v.into_vec(), vec![1, 2, -3, 4, -5]);
+    /// ```
+    ///
+    /// [`sort_unstable`]: List::sort_unstable
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        sort::buffered_sort(self, |a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the list with an integer key extraction function, by a radix
+    /// sort without comparisons.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// The key function is called at most 9 times for each element, so it
+    /// should be cheap (e.g. a simple property access).
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time and *O*(1) memory.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated, and the list is left in an
+    /// unspecified order, but it still contains all the original elements.
+    ///
+    /// # Current Implementation
+    ///
+    /// The current algorithm is a LSD (least significant digit) radix sort
+    /// with 8-bit digits. For each digit, the nodes are distributed into 256
+    /// buckets, which are then linked back in order. The digits shared by all
+    /// the keys are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// let mut v = List::from([(3, 'a'), (1, 'b'), (1000, 'c'), (3, 'd'), (0, 'e')]);
+    ///
+    /// v.sort_by_u64_key(|&(k, _)| k);
+    /// assert_eq!(v.into_vec(), vec![(0, 'e'), (1, 'b'), (3, 'a'), (3, 'd'), (1000, 'c')]);
+    /// ```
+    pub fn sort_by_u64_key<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> u64,
+    {
+        sort::radix_sort(self, f);
+    }
+
+    /// TODO
+    pub fn sort_by_cached_key<K, F>(&mut self, _f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        unimplemented!()
+    }
+
+    /// Merges another sorted list into the list, so that the result is
+    /// also sorted.
+    ///
+    /// This merge is stable (i.e., equal elements from `self` are placed
+    /// before those from `other`). If either list is not sorted, the order
+    /// of the elements is unspecified.
+    ///
+    /// The nodes of `other` are relinked into the list without allocations.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n* + *m*) time and *O*(1) memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([1, 3, 5, 7]);
+    /// list.merge(List::from([2, 3, 4, 8]));
+    /// assert_eq!(list.into_vec(), vec![1, 2, 3, 3, 4, 5, 7, 8]);
+    /// ```
+    pub fn merge(&mut self, other: List<T, A>)
+    where
+        T: Ord,
+    {
+        sort::merge(self, other, |a, b| a.lt(b));
+    }
+
+    /// Merges another sorted list into the list with a comparator function,
+    /// so that the result is also sorted.
+    ///
+    /// Both lists must be sorted by the same comparator. This merge is stable
+    /// (i.e., equal elements from `self` are placed before those from `other`).
+    ///
+    /// The nodes of `other` are relinked into the list without allocations.
+    ///
+    /// # Panics
+    ///
+    /// If `compare` panics, the panic is propagated, and the list is left in
+    /// an unspecified order, but it still contains all the elements of both
+    /// lists.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n* + *m*) time and *O*(1) memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([7, 5, 3, 1]);
+    /// list.merge_by(List::from([8, 4, 3, 2]), |a, b| b.cmp(a));
+    /// assert_eq!(list.into_vec(), vec![8, 7, 5, 4, 3, 3, 2, 1]);
+    /// ```
+    pub fn merge_by<F>(&mut self, other: List<T, A>, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::merge(self, other, |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Inserts an element into a sorted list, so that the list stays sorted,
+    /// and returns a cursor pointing to the inserted element.
+    ///
+    /// The element is inserted before the first element that is greater than
+    /// it, i.e. after all the elements equal to it. If the list is not sorted,
+    /// the position of the insertion is unspecified.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([1, 3, 5, 7]);
+    ///
+    /// let cursor = list.insert_sorted(4);
+    /// assert_eq!(cursor.current(), Some(&4));
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 2);
+    ///
+    /// assert_eq!(list.into_vec(), vec![1, 3, 4, 5, 7]);
+    /// ```
+    pub fn insert_sorted(&mut self, elem: T) -> CursorMut<'_, T, A>
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(elem, T::cmp)
+    }
+
+    /// Inserts an element into a list sorted with a comparator function, so
+    /// that the list stays sorted, and returns a cursor pointing to the
+    /// inserted element.
+    ///
+    /// The element is inserted before the first element that is greater than
+    /// it, i.e. after all the elements equal to it. If the list is not sorted
+    /// by `compare`, the position of the insertion is unspecified.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([(7, 'a'), (5, 'b'), (3, 'c'), (1, 'd')]);
+    ///
+    /// let cursor = list.insert_sorted_by((5, 'e'), |a, b| b.0.cmp(&a.0));
+    /// assert_eq!(cursor.current(), Some(&(5, 'e')));
+    ///
+    /// assert_eq!(list.into_vec(), vec![(7, 'a'), (5, 'b'), (5, 'e'), (3, 'c'), (1, 'd')]);
+    /// ```
+    pub fn insert_sorted_by<F>(&mut self, elem: T, mut compare: F) -> CursorMut<'_, T, A>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut cursor = self.cursor_start_mut();
+        while let Some(current) = cursor.current() {
+            if compare(&elem, current) == Ordering::Less {
+                break;
+            }
+            cursor.move_next_cyclic();
+        }
+        cursor.insert(elem);
+        cursor
+            .move_prev()
+            .expect("The inserted element must be before the cursor");
+        cursor
+    }
+
+    /// Searches a sorted list with a comparator function, and returns a
+    /// cursor for the result.
+    ///
+    /// The comparator function should return an order code that indicates
+    /// whether its argument is `Less`, `Equal` or `Greater` the desired
+    /// target, like [`slice::binary_search_by`].
+    ///
+    /// If a matching element is found, then [`Result::Ok`] is returned,
+    /// containing a cursor pointing to the first matching element. If no match
+    /// is found, then [`Result::Err`] is returned, containing a cursor pointing
+    /// to the position where a matching element could be inserted while
+    /// maintaining sorted order (possibly the ghost node).
+    ///
+    /// Since a list cannot be accessed randomly, the list is scanned from the
+    /// front, and the scan stops at the first element that is not `Less`.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([1, 2, 3, 3, 5, 8]);
+    ///
+    /// let found = list.search_by(|x| x.cmp(&3)).unwrap();
+    /// assert_eq!(found.current(), Some(&3));
+    /// assert_eq!(found.previous(), Some(&2));
+    ///
+    /// let not_found = list.search_by(|x| x.cmp(&4)).unwrap_err();
+    /// assert_eq!(not_found.current(), Some(&5));
+    ///
+    /// let not_found = list.search_by(|x| x.cmp(&10)).unwrap_err();
+    /// assert_eq!(not_found.current(), None);
+    /// ```
+    pub fn search_by<F>(&self, mut f: F) -> Result<Cursor<'_, T, A>, Cursor<'_, T, A>>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut cursor = self.cursor_start();
+        while let Some(current) = cursor.current() {
+            match f(current) {
+                Ordering::Less => cursor.move_next_cyclic(),
+                Ordering::Equal => return Ok(cursor),
+                Ordering::Greater => return Err(cursor),
+            }
+        }
+        Err(cursor)
+    }
+
+    /// Searches a sorted list with a comparator function, and returns a
+    /// cursor with editing operations for the result.
+    ///
+    /// It is the same as [`search_by`], except that the returned cursor can
+    /// be used to insert or remove elements at the found position.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([1, 2, 3, 5, 8]);
+    ///
+    /// match list.search_by_mut(|x| x.cmp(&4)) {
+    ///     Ok(mut cursor) => *cursor.current_mut().unwrap() = 4,
+    ///     Err(mut cursor) => cursor.insert(4),
+    /// }
+    /// assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5, 8]);
+    ///
+    /// if let Ok(mut cursor) = list.search_by_mut(|x| x.cmp(&2)) {
+    ///     cursor.remove();
+    /// }
+    /// assert_eq!(list.to_vec(), vec![1, 3, 4, 5, 8]);
+    /// ```
+    ///
+    /// [`search_by`]: List::search_by
+    pub fn search_by_mut<F>(&mut self, mut f: F) -> Result<CursorMut<'_, T, A>, CursorMut<'_, T, A>>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut cursor = self.cursor_start_mut();
+        while let Some(current) = cursor.current() {
+            match f(current) {
+                Ordering::Less => cursor.move_next_cyclic(),
+                Ordering::Equal => return Ok(cursor),
+                Ordering::Greater => return Err(cursor),
+            }
+        }
+        Err(cursor)
+    }
+
+    /// Returns a mutable cursor at the first element of a sorted list that
+    /// is not `Less` than the desired target, according to a comparator
+    /// function like [`search_by`].
+    ///
+    /// If all the elements are `Less`, the cursor points to the ghost node.
+    /// In either case, inserting before the cursor keeps the list sorted.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([1, 2, 4, 4, 8]);
+    ///
+    /// let mut cursor = list.lower_bound_mut(|x| x.cmp(&4));
+    /// assert_eq!(cursor.current(), Some(&4));
+    /// assert_eq!(cursor.previous(), Some(&2));
+    /// cursor.insert(3);
+    ///
+    /// let cursor = list.lower_bound_mut(|x| x.cmp(&9));
+    /// assert_eq!(cursor.current(), None);
+    ///
+    /// assert_eq!(list.into_vec(), vec![1, 2, 3, 4, 4, 8]);
+    /// ```
+    ///
+    /// [`search_by`]: List::search_by
+    pub fn lower_bound_mut<F>(&mut self, f: F) -> CursorMut<'_, T, A>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.search_by_mut(f).unwrap_or_else(|cursor| cursor)
+    }
+
+    /// Searches a sorted list with a comparator function like [`search_by`],
+    /// and returns the [`Entry`] at the result for in-place manipulation.
+    ///
+    /// The entry is occupied if a matching element is found, pointing to the
+    /// first one. Otherwise, it is vacant, and inserting into it keeps the
+    /// list sorted. Either way, the entry is updated or inserted without
+    /// searching again.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use cyclic_list::list::cursor::Entry;
+    ///
+    /// let mut counts: List<(&str, usize)> = List::new();
+    ///
+    /// for word in ["b", "a", "c", "a", "b", "a"] {
+    ///     counts
+    ///         .entry_by(|(w, _)| w.cmp(&word))
+    ///         .and_modify(|(_, n)| *n += 1)
+    ///         .or_insert((word, 1));
+    /// }
+    /// assert_eq!(counts.to_vec(), vec![("a", 3), ("b", 2), ("c", 1)]);
+    ///
+    /// match counts.entry_by(|(w, _)| w.cmp(&"c")) {
+    ///     Entry::Occupied(entry) => assert_eq!(entry.remove(), ("c", 1)),
+    ///     Entry::Vacant(_) => unreachable!(),
+    /// }
+    /// assert_eq!(counts.to_vec(), vec![("a", 3), ("b", 2)]);
+    /// ```
+    ///
+    /// [`search_by`]: List::search_by
+    pub fn entry_by<F>(&mut self, f: F) -> Entry<'_, T, A>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        match self.search_by_mut(f) {
+            Ok(cursor) => Entry::Occupied(OccupiedEntry::new(cursor)),
+            Err(cursor) => Entry::Vacant(VacantEntry::new(cursor)),
+        }
+    }
+
+    /// Returns a cursor at the minimum element of the list, or `None` if
+    /// the list is empty.
+    ///
+    /// If several elements are equally minimum, the cursor points to the
+    /// first one, like [`Iterator::min`].
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([3, 1, 4, 1, 5]);
+    ///
+    /// let cursor = list.min_cursor().unwrap();
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// assert_eq!(cursor.previous(), Some(&3));
+    ///
+    /// assert!(List::<i32>::new().min_cursor().is_none());
+    /// ```
+    pub fn min_cursor(&self) -> Option<Cursor<'_, T, A>>
+    where
+        T: Ord,
+    {
+        self.min_cursor_by(T::cmp)
+    }
+
+    /// Returns a cursor at the maximum element of the list, or `None` if
+    /// the list is empty.
+    ///
+    /// If several elements are equally maximum, the cursor points to the
+    /// last one, like [`Iterator::max`].
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([3, 5, 4, 5, 1]);
+    ///
+    /// let cursor = list.max_cursor().unwrap();
+    /// assert_eq!(cursor.current(), Some(&5));
+    /// assert_eq!(cursor.previous(), Some(&4));
+    ///
+    /// assert!(List::<i32>::new().max_cursor().is_none());
+    /// ```
+    pub fn max_cursor(&self) -> Option<Cursor<'_, T, A>>
+    where
+        T: Ord,
+    {
+        self.max_cursor_by(T::cmp)
+    }
+
+    /// Returns a cursor at the element that gives the minimum value with
+    /// respect to the specified comparison function, or `None` if the list
+    /// is empty.
+    ///
+    /// If several elements are equally minimum, the cursor points to the
+    /// first one, like [`Iterator::min_by`].
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
-    /// use std::iter::FromIterator;
     ///
-    /// let mut v = List::from_iter([1, 2, 3]);
-    /// let u: Vec<_> = v.drain().collect();
+    /// let list = List::from([-3.0, 0.5, -1.5, 2.0]);
     ///
-    /// assert!(v.is_empty());
-    /// assert_eq!(u, &[1, 2, 3]);
+    /// let cursor = list.min_cursor_by(|a, b| a.partial_cmp(b).unwrap()).unwrap();
+    /// assert_eq!(cursor.current(), Some(&-3.0));
     /// ```
-    pub fn drain(&mut self) -> Drain<'_, T> {
-        Drain::new(self)
+    pub fn min_cursor_by<F>(&self, mut compare: F) -> Option<Cursor<'_, T, A>>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.extremum_cursor(|best, current| compare(best, current) == Ordering::Greater)
     }
 
-    /// Creates an iterator which uses a closure to determine
-    /// if an element should be removed.
+    /// Returns a cursor at the element that gives the maximum value with
+    /// respect to the specified comparison function, or `None` if the list
+    /// is empty.
     ///
-    /// If the closure returns true, then the element is removed
-    /// and yielded. If the closure returns false, the element
-    /// will remain in the list and will not be yielded by the
-    /// iterator.
+    /// If several elements are equally maximum, the cursor points to the
+    /// last one, like [`Iterator::max_by`].
     ///
-    /// Note that `drain_filter` lets you mutate every element
-    /// in the filter closure, regardless of whether you choose
-    /// to keep or remove it.
+    /// # Complexity
     ///
-    /// # Examples
+    /// This operation should compute in *O*(*n*) time.
     ///
-    /// Splitting a list into evens and odds, reusing the original
-    /// list:
+    /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
-    /// use std::iter::FromIterator;
     ///
-    /// let mut numbers = List::<u32>::new();
-    /// numbers.extend(&[1, 2, 3, 4, 5, 6, 8, 9, 11, 13, 14, 15]);
-    ///
-    /// let evens = numbers.drain_filter(|x| *x % 2 == 0).collect::<List<_>>();
-    /// let odds = numbers;
+    /// let list = List::from([-3.0, 0.5, -1.5, 2.0]);
     ///
-    /// assert_eq!(Vec::from_iter(evens), vec![2, 4, 6, 8, 14]);
-    /// assert_eq!(Vec::from_iter(odds), vec![1, 3, 5, 9, 11, 13, 15]);
+    /// let cursor = list.max_cursor_by(|a, b| a.partial_cmp(b).unwrap()).unwrap();
+    /// assert_eq!(cursor.current(), Some(&2.0));
     /// ```
-    pub fn drain_filter<F>(&mut self, f: F) -> DrainFilter<'_, T, F>
+    pub fn max_cursor_by<F>(&self, mut compare: F) -> Option<Cursor<'_, T, A>>
     where
-        F: FnMut(&mut T) -> bool,
+        F: FnMut(&T, &T) -> Ordering,
     {
-        DrainFilter::new(self, f)
+        self.extremum_cursor(|best, current| compare(best, current) != Ordering::Greater)
     }
 
-    /// Sort the list.
+    /// Returns a cursor at the element that gives the minimum value from the
+    /// specified key function, or `None` if the list is empty.
     ///
-    /// This sort is stable (i.e., does not reorder equal elements).
+    /// If several elements are equally minimum, the cursor points to the
+    /// first one, like [`Iterator::min_by_key`].
     ///
     /// # Complexity
     ///
-    /// This operation should compute in *O*(*n* * log(*n*)) time and *O*(1) memory.
+    /// This operation should compute in *O*(*n*) time, where the key function
+    /// is called twice for each element.
     ///
-    /// # Current Implementation
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([-3, 0, 1, 5, -10]);
+    ///
+    /// let cursor = list.min_cursor_by_key(|x: &i32| x.abs()).unwrap();
+    /// assert_eq!(cursor.current(), Some(&0));
+    /// ```
+    pub fn min_cursor_by_key<K, F>(&self, mut f: F) -> Option<Cursor<'_, T, A>>
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.min_cursor_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Returns a cursor at the element that gives the maximum value from the
+    /// specified key function, or `None` if the list is empty.
+    ///
+    /// If several elements are equally maximum, the cursor points to the
+    /// last one, like [`Iterator::max_by_key`].
     ///
-    /// The current algorithm is done by a naive merge sort. There is no extra
-    /// temporary storage during merging.
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time, where the key function
+    /// is called twice for each element.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
-    /// use std::iter::FromIterator;
-    /// let mut list = List::from_iter([5, 2, 4, 3, 1]);
     ///
-    /// list.sort();
+    /// let list = List::from([-3, 0, 1, 5, -10]);
     ///
-    /// assert_eq!(list.into_vec(), vec![1, 2, 3, 4, 5]);
+    /// let cursor = list.max_cursor_by_key(|x: &i32| x.abs()).unwrap();
+    /// assert_eq!(cursor.current(), Some(&-10));
     /// ```
-    pub fn sort(&mut self)
+    pub fn max_cursor_by_key<K, F>(&self, mut f: F) -> Option<Cursor<'_, T, A>>
     where
-        T: Ord,
+        F: FnMut(&T) -> K,
+        K: Ord,
     {
-        sort::merge_sort(self, |a, b| a.lt(b));
+        self.max_cursor_by(|a, b| f(a).cmp(&f(b)))
     }
 
-    /// Sort the list with a comparator function.
+    /// Returns a cursor with editing operations at the minimum element of
+    /// the list, or `None` if the list is empty.
     ///
-    /// This sort is stable (i.e., does not reorder equal elements).
+    /// It is the same as [`min_cursor`], except that the returned cursor can
+    /// be used to mutate or remove the found element.
     ///
-    /// The comparator function must define a total ordering for the
-    /// elements in the list. If the ordering is not total, the order
-    /// of the elements is unspecified. An order is a total order if
-    /// it is (for all `a`, `b` and `c`):
-    /// - total and antisymmetric: exactly one of `a < b`, `a == b`
-    ///   or `a > b` is true, and
-    /// - transitive, `a < b` and `b < c` implies `a < c`. The same
-    /// must hold for both `==` and `>`.
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
     ///
-    /// For example, while [`f64`] doesn’t implement [`Ord`] because
-    /// `NaN != NaN`, we can use `partial_cmp` as our sort function
-    /// when we know the list doesn’t contain a `NaN`.
     /// ```
     /// use cyclic_list::List;
-    /// let mut floats = List::from([5f64, 4.0, 1.0, 3.0, 2.0]);
-    /// floats.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    /// assert_eq!(floats.into_vec(), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    ///
+    /// let mut list = List::from([3, 1, 4, 1, 5]);
+    ///
+    /// assert_eq!(list.min_cursor_mut().unwrap().remove(), Some(1));
+    /// assert_eq!(list.to_vec(), vec![3, 4, 1, 5]);
     /// ```
     ///
-    /// # Complexity
+    /// [`min_cursor`]: List::min_cursor
+    pub fn min_cursor_mut(&mut self) -> Option<CursorMut<'_, T, A>>
+    where
+        T: Ord,
+    {
+        self.upgrade_cursor(List::min_cursor)
+    }
+
+    /// Returns a cursor with editing operations at the maximum element of
+    /// the list, or `None` if the list is empty.
     ///
-    /// This operation should compute in *O*(*n* * log(*n*)) time and *O*(1) memory.
+    /// It is the same as [`max_cursor`], except that the returned cursor can
+    /// be used to mutate or remove the found element.
     ///
-    /// # Current Implementation
+    /// # Complexity
     ///
-    /// The current algorithm is done by a naive merge sort. There is no extra
-    /// temporary storage during merging.
+    /// This operation should compute in *O*(*n*) time.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
-    /// let mut v = List::from([5, 4, 1, 3, 2]);
-    /// v.sort_by(|a, b| a.cmp(b));
-    /// assert_eq!(v.to_vec(), vec![1, 2, 3, 4, 5]);
     ///
-    /// // reverse sorting
-    /// v.sort_by(|a, b| b.cmp(a));
-    /// assert_eq!(v.to_vec(), vec![5, 4, 3, 2, 1]);
+    /// let mut list = List::from([3, 5, 4, 5, 1]);
+    ///
+    /// assert_eq!(list.max_cursor_mut().unwrap().remove(), Some(5));
+    /// assert_eq!(list.to_vec(), vec![3, 5, 4, 1]);
     /// ```
-    pub fn sort_by<F>(&mut self, mut compare: F)
+    ///
+    /// [`max_cursor`]: List::max_cursor
+    pub fn max_cursor_mut(&mut self) -> Option<CursorMut<'_, T, A>>
     where
-        F: FnMut(&T, &T) -> Ordering,
+        T: Ord,
     {
-        sort::merge_sort(self, |a, b| compare(a, b) == Ordering::Less)
+        self.upgrade_cursor(List::max_cursor)
     }
 
-    /// Sorts the list with a key extraction function.
+    /// Returns a cursor with editing operations at the element that gives
+    /// the minimum value with respect to the specified comparison function,
+    /// or `None` if the list is empty.
     ///
-    /// This sort is stable (i.e., does not reorder equal elements)
-    /// and *O*(*m* \* *n* \* log(*n*)) worst-case, where the
-    /// key function is *O*(*m*).
+    /// It is the same as [`min_cursor_by`], except that the returned cursor
+    /// can be used to mutate or remove the found element.
     ///
-    /// For expensive key functions (e.g. functions that are not simple
-    /// property accesses or basic operations),
-    /// [`sort_by_cached_key`](List::sort_by_cached_key) is likely to be
-    /// significantly faster, as it does not recompute element keys.
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// [`min_cursor_by`]: List::min_cursor_by
+    pub fn min_cursor_by_mut<F>(&mut self, compare: F) -> Option<CursorMut<'_, T, A>>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.upgrade_cursor(|list| list.min_cursor_by(compare))
+    }
+
+    /// Returns a cursor with editing operations at the element that gives
+    /// the maximum value with respect to the specified comparison function,
+    /// or `None` if the list is empty.
+    ///
+    /// It is the same as [`max_cursor_by`], except that the returned cursor
+    /// can be used to mutate or remove the found element.
     ///
     /// # Complexity
     ///
-    /// This operation should compute in *O*(*n* * log(*n*)) time and *O*(1) memory.
+    /// This operation should compute in *O*(*n*) time.
     ///
-    /// # Current Implementation
+    /// [`max_cursor_by`]: List::max_cursor_by
+    pub fn max_cursor_by_mut<F>(&mut self, compare: F) -> Option<CursorMut<'_, T, A>>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.upgrade_cursor(|list| list.max_cursor_by(compare))
+    }
+
+    /// Returns a cursor with editing operations at the element that gives
+    /// the minimum value from the specified key function, or `None` if the
+    /// list is empty.
+    ///
+    /// It is the same as [`min_cursor_by_key`], except that the returned
+    /// cursor can be used to mutate or remove the found element.
+    ///
+    /// # Complexity
     ///
-    /// The current algorithm is done by a naive merge sort. There is no extra
-    /// temporary storage during merging.
+    /// This operation should compute in *O*(*n*) time.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
-    /// let mut v = List::from([-5i32, 4, 1, -3, 2]);
     ///
-    /// v.sort_by_key(|k| k.abs());
-    /// assert_eq!(v.into_vec(), vec![1, 2, -3, 4, -5]);
+    /// let mut list = List::from([-3, 2, 1, 5, -10]);
+    ///
+    /// *list.min_cursor_by_key_mut(|x: &i32| x.abs()).unwrap().current_mut().unwrap() = 0;
+    /// assert_eq!(list.to_vec(), vec![-3, 2, 0, 5, -10]);
     /// ```
-    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    ///
+    /// [`min_cursor_by_key`]: List::min_cursor_by_key
+    pub fn min_cursor_by_key_mut<K, F>(&mut self, f: F) -> Option<CursorMut<'_, T, A>>
     where
         F: FnMut(&T) -> K,
         K: Ord,
     {
-        sort::merge_sort(self, |a, b| f(a).lt(&f(b)));
+        self.upgrade_cursor(|list| list.min_cursor_by_key(f))
     }
 
-    /// TODO
-    pub fn sort_by_cached_key<K, F>(&mut self, _f: F)
+    /// Returns a cursor with editing operations at the element that gives
+    /// the maximum value from the specified key function, or `None` if the
+    /// list is empty.
+    ///
+    /// It is the same as [`max_cursor_by_key`], except that the returned
+    /// cursor can be used to mutate or remove the found element.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// [`max_cursor_by_key`]: List::max_cursor_by_key
+    pub fn max_cursor_by_key_mut<K, F>(&mut self, f: F) -> Option<CursorMut<'_, T, A>>
     where
         F: FnMut(&T) -> K,
         K: Ord,
     {
-        unimplemented!()
+        self.upgrade_cursor(|list| list.max_cursor_by_key(f))
+    }
+
+    /// Searches for the last element that satisfies a predicate, and returns
+    /// a cursor at it, or `None` if no element matches.
+    ///
+    /// The list is scanned from the back, so it is efficient if the matches
+    /// are likely to be near the end of the list.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time, or *O*(*k*) time if
+    /// the match is the *k*-th element from the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([1, 2, 3, 4, 5, 6]);
+    ///
+    /// let cursor = list.rfind_cursor(|x| x % 2 == 1).unwrap();
+    /// assert_eq!(cursor.current(), Some(&5));
+    /// assert_eq!(cursor.previous(), Some(&4));
+    ///
+    /// assert!(list.rfind_cursor(|&x| x > 6).is_none());
+    /// ```
+    pub fn rfind_cursor<P>(&self, mut predicate: P) -> Option<Cursor<'_, T, A>>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut cursor = self.cursor_end();
+        while let Some(prev) = cursor.previous() {
+            cursor.move_prev_cyclic();
+            if predicate(prev) {
+                return Some(cursor);
+            }
+        }
+        None
+    }
+
+    /// Searches for the last element that satisfies a predicate, and returns
+    /// a cursor with editing operations at it, or `None` if no element matches.
+    ///
+    /// It is the same as [`rfind_cursor`], except that the returned cursor
+    /// can be used to mutate or remove the found element.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time, or *O*(*k*) time if
+    /// the match is the *k*-th element from the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from(["let", "x", "=", "1", ";", "let", "y", ";"]);
+    ///
+    /// let mut cursor = list.rfind_cursor_mut(|&s| s == "let").unwrap();
+    /// assert_eq!(cursor.split(), Some(List::from(["let", "y", ";"])));
+    /// assert_eq!(list.to_vec(), vec!["let", "x", "=", "1", ";"]);
+    /// ```
+    ///
+    /// [`rfind_cursor`]: List::rfind_cursor
+    pub fn rfind_cursor_mut<P>(&mut self, predicate: P) -> Option<CursorMut<'_, T, A>>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.upgrade_cursor(|list| list.rfind_cursor(predicate))
     }
 
     /// Checks if the elements of this list are sorted.
@@ -366,4 +1928,175 @@ impl<T> List<T> {
     {
         self.is_sorted_by(|a, b| f(a).partial_cmp(&f(b)))
     }
+
+    /// Rotates the list in-place such that it starts at its lexicographically
+    /// least rotation, and returns the number of elements rotated to the left.
+    ///
+    /// Lists that are rotations of each other become equal after this
+    /// operation, so the cyclic sequences can be compared or hashed regardless
+    /// of where they start. If there are several least rotations (e.g. the
+    /// list is periodic), the one that rotates the fewest elements is chosen.
+    ///
+    /// Since the list is cyclic, only the ghost node is relinked.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time and *O*(*n*) memory.
+    ///
+    /// # Current Implementation
+    ///
+    /// The current algorithm is [Booth's algorithm], which finds the least
+    /// rotation in *O*(*n*) comparisons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut a = List::from([3, 1, 2, 1, 1]);
+    /// let mut b = List::from([1, 2, 1, 1, 3]);
+    ///
+    /// assert_eq!(a.canonicalize_rotation(), 3);
+    /// assert_eq!(b.canonicalize_rotation(), 2);
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.into_vec(), vec![1, 1, 3, 1, 2]);
+    /// ```
+    ///
+    /// [Booth's algorithm]: https://en.wikipedia.org/wiki/Lexicographically_minimal_string_rotation#Booth's_Algorithm
+    pub fn canonicalize_rotation(&mut self) -> usize
+    where
+        T: Ord,
+    {
+        let nodes: Vec<_> = self.nodes().collect();
+        // SAFETY: all the nodes are valid non-ghost nodes in the list.
+        let elements: Vec<_> = nodes
+            .iter()
+            .map(|node| unsafe { &node.as_ref().element })
+            .collect();
+        let mid = least_rotation(&elements);
+        if mid != 0 {
+            // SAFETY: `nodes[mid]` is a valid non-ghost node in the list.
+            unsafe { self.rebase_ghost(nodes[mid]) };
+        }
+        mid
+    }
+}
+
+impl<A, B> List<(A, B)> {
+    /// Converts a list of pairs into a pair of lists.
+    ///
+    /// The elements are moved into newly allocated nodes of the two lists
+    /// one by one, keeping their order, and the nodes of this list are freed
+    /// along the way. No intermediate buffer is needed.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    ///
+    /// let (numbers, letters) = list.unzip();
+    /// assert_eq!(numbers, List::from([1, 2, 3]));
+    /// assert_eq!(letters, List::from(['a', 'b', 'c']));
+    /// ```
+    pub fn unzip(self) -> (List<A>, List<B>) {
+        self.into_iter().unzip()
+    }
+}
+
+/// Private helpers of the algorithms.
+impl<T, A: RawAlloc> List<T, A> {
+    /// Returns a cursor at the extremal element, where `replace(best, current)`
+    /// tells whether `current` should replace the extremal element found so far.
+    fn extremum_cursor<F>(&self, mut replace: F) -> Option<Cursor<'_, T, A>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut best = self.cursor_start();
+        let mut best_elem = best.current()?;
+        let mut cursor = best.clone();
+        cursor.move_next_cyclic();
+        while let Some(current) = cursor.current() {
+            if replace(best_elem, current) {
+                best = cursor.clone();
+                best_elem = current;
+            }
+            cursor.move_next_cyclic();
+        }
+        Some(best)
+    }
+
+    /// Turns the cursor found by `find` into a cursor with editing operations.
+    fn upgrade_cursor<F>(&mut self, find: F) -> Option<CursorMut<'_, T, A>>
+    where
+        F: FnOnce(&Self) -> Option<Cursor<'_, T, A>>,
+    {
+        let cursor = find(self)?;
+        let current = cursor.current;
+        #[cfg(feature = "length")]
+        let index = cursor.index();
+        Some(CursorMut::new(
+            self,
+            current,
+            #[cfg(feature = "length")]
+            index,
+        ))
+    }
+
+    /// Removes the elements for which `keep` returns `false`.
+    fn retain_elements<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let ghost = self.ghost_node();
+        let mut node = self.front_node();
+        while node != ghost {
+            // SAFETY: `node` is a non-ghost node in the list, and the reference
+            // to its element does not outlive the call of `keep`.
+            unsafe {
+                let next = node.as_ref().next;
+                if !keep(&mut (*node.as_ptr()).element) {
+                    drop(self.remove_node(node));
+                }
+                node = next;
+            }
+        }
+    }
+}
+
+/// Find the start of the lexicographically least rotation of `s` by Booth's
+/// algorithm.
+fn least_rotation<T: Ord>(s: &[T]) -> usize {
+    let n = s.len();
+    // The failure function of the least rotation found so far, where `None`
+    // stands for -1.
+    let mut failure: Vec<Option<usize>> = vec![None; 2 * n];
+    let mut k = 0;
+    for j in 1..2 * n {
+        let mut i = failure[j - k - 1];
+        loop {
+            let next = i.map_or(0, |i| i + 1);
+            match s[j % n].cmp(&s[(k + next) % n]) {
+                Ordering::Equal => {
+                    failure[j - k] = Some(next);
+                    break;
+                }
+                Ordering::Less => k = j - next,
+                Ordering::Greater => {}
+            }
+            match i {
+                Some(prev) => i = failure[prev],
+                None => {
+                    failure[j - k] = None;
+                    break;
+                }
+            }
+        }
+    }
+    k
 }
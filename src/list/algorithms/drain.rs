@@ -1,18 +1,20 @@
+use crate::list::alloc::{Global, RawAlloc};
 use crate::list::cursor::CursorMut;
 use crate::List;
 use std::fmt;
+use std::iter::FusedIterator;
 
-pub struct Drain<'a, T: 'a> {
-    list: &'a mut List<T>,
+pub struct Drain<'a, T: 'a, A: RawAlloc = Global> {
+    list: &'a mut List<T, A>,
 }
 
-impl<'a, T: 'a> Drain<'a, T> {
-    pub(crate) fn new(list: &'a mut List<T>) -> Self {
+impl<'a, T: 'a, A: RawAlloc> Drain<'a, T, A> {
+    pub(crate) fn new(list: &'a mut List<T, A>) -> Self {
         Self { list }
     }
 }
 
-impl<T> Iterator for Drain<'_, T> {
+impl<T, A: RawAlloc> Iterator for Drain<'_, T, A> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -20,37 +22,59 @@ impl<T> Iterator for Drain<'_, T> {
     }
 }
 
-impl<T> Drop for Drain<'_, T> {
+impl<T, A: RawAlloc> Drop for Drain<'_, T, A> {
     fn drop(&mut self) {
         self.list.clear();
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for Drain<'_, T> {
+impl<T: fmt::Debug, A: RawAlloc> fmt::Debug for Drain<'_, T, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("Drain").field(self.list).finish()
     }
 }
 
-pub struct DrainFilter<'a, T: 'a, F: 'a>
+pub struct DrainFilter<'a, T: 'a, F: 'a, A: RawAlloc = Global>
 where
     F: FnMut(&mut T) -> bool,
 {
-    cursor: CursorMut<'a, T>,
+    cursor: CursorMut<'a, T, A>,
     filter: F,
+    /// The number of elements left to visit, or `None` if visiting until
+    /// the end of the list.
+    remaining: Option<usize>,
 }
 
-impl<'a, T, F> DrainFilter<'a, T, F>
+impl<'a, T, F, A: RawAlloc> DrainFilter<'a, T, F, A>
 where
     F: FnMut(&mut T) -> bool,
 {
-    pub(crate) fn new(list: &'a mut List<T>, filter: F) -> Self {
+    pub(crate) fn new(list: &'a mut List<T, A>, filter: F) -> Self {
         let cursor = list.cursor_start_mut();
-        Self { cursor, filter }
+        Self {
+            cursor,
+            filter,
+            remaining: None,
+        }
+    }
+
+    pub(crate) fn new_range(
+        list: &'a mut List<T, A>,
+        start: usize,
+        end: Option<usize>,
+        filter: F,
+    ) -> Self {
+        let cursor = list.cursor_mut(start);
+        let remaining = end.map(|end| end - start);
+        Self {
+            cursor,
+            filter,
+            remaining,
+        }
     }
 }
 
-impl<T, F> Iterator for DrainFilter<'_, T, F>
+impl<T, F, A: RawAlloc> Iterator for DrainFilter<'_, T, F, A>
 where
     F: FnMut(&mut T) -> bool,
 {
@@ -58,7 +82,14 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if (self.filter)(self.cursor.current_mut()?) {
+            if self.remaining == Some(0) {
+                return None;
+            }
+            let current = self.cursor.current_mut()?;
+            if let Some(remaining) = &mut self.remaining {
+                *remaining -= 1;
+            }
+            if (self.filter)(current) {
                 return self.cursor.remove();
             }
             self.cursor.move_next_cyclic();
@@ -66,7 +97,7 @@ where
     }
 }
 
-impl<T, F> Drop for DrainFilter<'_, T, F>
+impl<T, F, A: RawAlloc> Drop for DrainFilter<'_, T, F, A>
 where
     F: FnMut(&mut T) -> bool,
 {
@@ -75,7 +106,7 @@ where
     }
 }
 
-impl<T: fmt::Debug, F> fmt::Debug for DrainFilter<'_, T, F>
+impl<T: fmt::Debug, F, A: RawAlloc> fmt::Debug for DrainFilter<'_, T, F, A>
 where
     F: FnMut(&mut T) -> bool,
 {
@@ -85,3 +116,61 @@ where
             .finish()
     }
 }
+
+pub struct RemoveEveryNth<'a, T: 'a, A: RawAlloc = Global> {
+    cursor: CursorMut<'a, T, A>,
+    n: usize,
+}
+
+impl<'a, T: 'a, A: RawAlloc> RemoveEveryNth<'a, T, A> {
+    pub(crate) fn new(list: &'a mut List<T, A>, n: usize) -> Self {
+        let cursor = list.cursor_start_mut();
+        Self { cursor, n }
+    }
+}
+
+impl<T, A: RawAlloc> Iterator for RemoveEveryNth<'_, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor.is_empty() {
+            return None;
+        }
+        // Counting from the current element (which is the first one, or the
+        // one after the last removed), skip `n - 1` elements cyclically.
+        #[cfg(feature = "length")]
+        let steps = (self.n - 1) % self.cursor.list.len();
+        #[cfg(not(feature = "length"))]
+        let steps = self.n - 1;
+        // The cursor is at the ghost node after removing the back element.
+        if self.cursor.current().is_none() {
+            self.cursor.move_next_cyclic();
+        }
+        for _ in 0..steps {
+            self.cursor.move_next_cyclic();
+            if self.cursor.current().is_none() {
+                self.cursor.move_next_cyclic();
+            }
+        }
+        self.cursor.remove()
+    }
+
+    #[cfg(feature = "length")]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.cursor.list.len();
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "length")]
+impl<T, A: RawAlloc> ExactSizeIterator for RemoveEveryNth<'_, T, A> {}
+
+impl<T, A: RawAlloc> FusedIterator for RemoveEveryNth<'_, T, A> {}
+
+impl<T: fmt::Debug, A: RawAlloc> fmt::Debug for RemoveEveryNth<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RemoveEveryNth")
+            .field(self.cursor.list)
+            .finish()
+    }
+}
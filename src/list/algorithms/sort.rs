@@ -1,70 +1,222 @@
-use crate::list::{connect, Node};
+use crate::list::alloc::RawAlloc;
+use crate::list::{connect, reverse_nodes, Node};
 use crate::List;
+use std::cmp::Ordering;
 use std::ptr::NonNull;
 
 const INSERTION_SORT_THRESHOLD: usize = 8;
 
-pub fn merge_sort<T, F>(list: &mut List<T>, mut less: F)
+// Panic safety: the comparators are only called while the list is well-formed,
+// i.e. every relinking of nodes (see `move_nodes`) is done between two calls of
+// the comparator. So if a comparator panics, the list still contains all its
+// original nodes, in an unspecified order.
+
+pub fn merge_sort<T, A: RawAlloc, F>(list: &mut List<T, A>, less: F)
 where
     F: FnMut(&T, &T) -> bool,
 {
-    let (start, end) = (list.front_node(), list.ghost_node());
-    #[cfg(feature = "length")]
-    if list.len() < 2 {
-    } else if list.len() <= INSERTION_SORT_THRESHOLD {
-        unsafe { insertion_sort_range(start, end, &mut less) };
-    } else {
-        unsafe { merge_sort_range(start, end, &mut less) };
+    let ghost = list.ghost_node();
+    // SAFETY: `ghost.next..ghost` is the whole range of the list.
+    unsafe { merge_sort_range(ghost, ghost, less) };
+}
+
+/// Sort the range `before.next..end` by merge sort, where `before` and `end`
+/// stay put.
+///
+/// It is unsafe because `before.next..end` must be a valid range of a list.
+pub unsafe fn merge_sort_range<T, F>(before: NonNull<Node<T>>, end: NonNull<Node<T>>, mut less: F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    // Each pass merges every two adjacent runs, until there is only one run.
+    loop {
+        let mut start = before.as_ref().next;
+        let mut merged = false;
+        while start != end {
+            // SAFETY: `start..end` is a non-empty valid range in the list.
+            unsafe {
+                let (run_start, mid) = next_run(start, end, &mut less);
+                if mid == end {
+                    break;
+                }
+                let (mid, run_end) = next_run(mid, end, &mut less);
+                merge_range(run_start, mid, run_end, &mut less);
+                start = run_end;
+            }
+            merged = true;
+        }
+        if !merged {
+            break;
+        }
+    }
+}
+
+pub fn buffered_sort<T, A: RawAlloc, F>(list: &mut List<T, A>, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut nodes: Vec<_> = list.nodes().collect();
+    // SAFETY: all the nodes in the buffer are valid non-ghost nodes in the list.
+    // The list is not changed until sorting is finished, so the list is still
+    // well-formed if `compare` panics.
+    nodes.sort_unstable_by(|a, b| unsafe { compare(&a.as_ref().element, &b.as_ref().element) });
+    // SAFETY: `nodes` is a permutation of all the non-ghost nodes in the list.
+    unsafe { list.relink_nodes(nodes) };
+}
+
+pub fn radix_sort<T, A: RawAlloc, F>(list: &mut List<T, A>, mut key: F)
+where
+    F: FnMut(&T) -> u64,
+{
+    // Find the bits that differ among the keys, and skip the passes
+    // for the digits that are the same for all the keys.
+    let mut iter = list.iter();
+    let first = match iter.next() {
+        Some(first) => key(first),
+        None => return,
+    };
+    let diff = iter.fold(0, |diff, elem| diff | (key(elem) ^ first));
+
+    for shift in (0..u64::BITS).step_by(RADIX_BITS as usize) {
+        if (diff >> shift) & RADIX_MASK == 0 {
+            continue;
+        }
+        let mut buckets = Buckets::new(list);
+        let ghost = buckets.list.ghost_node();
+        while buckets.rest != ghost {
+            let node = buckets.rest;
+            // SAFETY: `node` is a valid non-ghost node in the list.
+            unsafe {
+                let digit = (key(&node.as_ref().element) >> shift) & RADIX_MASK;
+                buckets.rest = node.as_ref().next;
+                buckets.push(digit as usize, node);
+            }
+        }
     }
+}
 
-    #[cfg(not(feature = "length"))]
-    if !list.is_empty() || start != list.back_node() {
-        unsafe { merge_sort_range(start, end, &mut less) };
+const RADIX_BITS: u32 = 8;
+const RADIX_MASK: u64 = (1 << RADIX_BITS) - 1;
+
+/// The `front..=back` range of nodes in a bucket, or `None` if it is empty.
+type Bucket<T> = Option<(NonNull<Node<T>>, NonNull<Node<T>>)>;
+
+/// Buckets of nodes for a pass of radix sort.
+///
+/// The nodes are moved from `rest..ghost` into the buckets one by one.
+/// When dropped (even if the key function panics), the nodes in all the
+/// buckets and the rest nodes are linked back to the list in order.
+struct Buckets<'a, T, A: RawAlloc> {
+    list: &'a mut List<T, A>,
+    buckets: Vec<Bucket<T>>,
+    /// The first node that is not moved into any bucket yet.
+    rest: NonNull<Node<T>>,
+}
+
+impl<'a, T, A: RawAlloc> Buckets<'a, T, A> {
+    fn new(list: &'a mut List<T, A>) -> Self {
+        let rest = list.front_node();
+        Self {
+            list,
+            buckets: vec![None; 1 << RADIX_BITS],
+            rest,
+        }
+    }
+
+    /// Move `node` to the back of the bucket `digit`.
+    ///
+    /// It is unsafe because `node` must be a node in the list that has
+    /// been removed from `rest..ghost`.
+    unsafe fn push(&mut self, digit: usize, node: NonNull<Node<T>>) {
+        match &mut self.buckets[digit] {
+            Some((_, back)) => {
+                connect(*back, node);
+                *back = node;
+            }
+            bucket @ None => *bucket = Some((node, node)),
+        }
     }
 }
 
-unsafe fn mid_of_range<T>(
-    mut start: NonNull<Node<T>>,
-    end: NonNull<Node<T>>,
-) -> (NonNull<Node<T>>, usize) {
-    let mut mid = start;
-    let mut len = 0;
-    while start != end {
-        len += 1;
-        start = start.as_ref().next;
-        if start != end {
-            len += 1;
-            start = start.as_ref().next;
-            mid = mid.as_ref().next;
+impl<T, A: RawAlloc> Drop for Buckets<'_, T, A> {
+    fn drop(&mut self) {
+        let ghost = self.list.ghost_node();
+        let mut prev = ghost;
+        for &(front, back) in self.buckets.iter().flatten() {
+            // SAFETY: `prev` and `front` are valid nodes in the list.
+            unsafe { connect(prev, front) };
+            prev = back;
         }
+        // SAFETY: `rest..ghost` is still linked, so linking `prev` and `rest`
+        // closes the ring.
+        unsafe { connect(prev, self.rest) };
+    }
+}
+
+pub fn merge<T, A: RawAlloc, F>(list: &mut List<T, A>, mut other: List<T, A>, mut less: F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    if other.is_empty() {
+        return;
+    }
+    let was_empty = list.is_empty();
+    let mid = other.front_node();
+    list.append(&mut other);
+    if !was_empty {
+        // SAFETY: both `start..mid` and `mid..end` are non-empty valid ranges.
+        unsafe { merge_range(list.front_node(), mid, list.ghost_node(), &mut less) };
     }
-    (mid, len)
 }
 
-unsafe fn merge_sort_range<T, F>(
+/// Find a run of sorted nodes from `start`, and return the sorted range
+/// `run_start..run_end`, where `run_start` is the new first node of the run.
+///
+/// A strictly descending run is reversed, and a run shorter than
+/// `INSERTION_SORT_THRESHOLD` is extended by insertion sort.
+unsafe fn next_run<T, F>(
     mut start: NonNull<Node<T>>,
     end: NonNull<Node<T>>,
     less: &mut F,
-) -> NonNull<Node<T>>
+) -> (NonNull<Node<T>>, NonNull<Node<T>>)
 where
     F: FnMut(&T, &T) -> bool,
 {
-    let (mut mid, len) = mid_of_range(start, end);
-    if len <= INSERTION_SORT_THRESHOLD {
-        return insertion_sort_range(start, end, less);
-    }
-
-    if start != mid && start.as_ref().next != mid {
-        start = merge_sort_range(start, mid, less);
-    }
-    if mid != end && mid.as_ref().next != end {
-        mid = merge_sort_range(mid, end, less);
+    let mut run_end = start.as_ref().next;
+    let mut len = 1;
+    if run_end != end && less(&run_end.as_ref().element, &start.as_ref().element) {
+        // Strictly descending, so reversing it keeps the sort stable.
+        while run_end != end
+            && less(
+                &run_end.as_ref().element,
+                &run_end.as_ref().prev.as_ref().element,
+            )
+        {
+            run_end = run_end.as_ref().next;
+            len += 1;
+        }
+        let before = start.as_ref().prev;
+        reverse_nodes(start, run_end);
+        start = before.as_ref().next;
+    } else {
+        while run_end != end
+            && !less(
+                &run_end.as_ref().element,
+                &run_end.as_ref().prev.as_ref().element,
+            )
+        {
+            run_end = run_end.as_ref().next;
+            len += 1;
+        }
     }
-
-    if start != mid && mid != end {
-        start = merge_range(start, mid, end, less);
+    if len < INSERTION_SORT_THRESHOLD && run_end != end {
+        while run_end != end && len < INSERTION_SORT_THRESHOLD {
+            run_end = run_end.as_ref().next;
+            len += 1;
+        }
+        start = insertion_sort_range(start, run_end, less);
     }
-    start
+    (start, run_end)
 }
 
 unsafe fn merge_range<T, F>(
@@ -163,3 +315,183 @@ unsafe fn move_nodes<T>(
     connect(to.as_ref().prev, from_front);
     connect(from_back, to);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::List;
+    use std::cell::Cell;
+    use std::cmp::Ordering;
+    use std::iter::FromIterator;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[test]
+    fn sort_stable() {
+        fn test_sort_stable(keys: impl IntoIterator<Item = usize>) {
+            let mut vec = Vec::from_iter(keys.into_iter().enumerate().map(|(i, k)| (k, i)));
+            let mut list = List::from_iter(vec.iter().copied());
+            vec.sort_by_key(|&(k, _)| k);
+            list.sort_by_key(|&(k, _)| k);
+            assert_eq!(list, List::from_iter(vec.iter().copied()));
+            assert!(list.iter().rev().eq(vec.iter().rev()));
+        }
+        test_sort_stable(0..100);
+        test_sort_stable((0..100).rev());
+        test_sort_stable((0..100).map(|i| i % 7));
+        test_sort_stable((0..100).map(|i| (i * 37 + 11) % 101));
+        test_sort_stable((0..100).map(|i| if i < 50 { i } else { 100 - i }));
+        test_sort_stable((0..100).map(|i| (100 - i) / 3));
+        test_sort_stable([1, 0]);
+        test_sort_stable(Some(0));
+        test_sort_stable(None);
+    }
+
+    #[test]
+    fn sort_range_stable() {
+        let keys = Vec::from_iter((0..40).map(|i| (i * 37 + 11) % 13));
+        for (start, end) in [
+            (0, 40),
+            (0, 0),
+            (5, 5),
+            (0, 17),
+            (13, 40),
+            (7, 31),
+            (39, 40),
+        ] {
+            let mut vec = Vec::from_iter(keys.iter().copied().enumerate().map(|(i, k)| (k, i)));
+            let mut list = List::from_iter(vec.iter().copied());
+            vec[start..end].sort_by_key(|&(k, _)| k);
+            list.sort_range_by(start..end, |a, b| a.0.cmp(&b.0));
+            assert_eq!(list, List::from_iter(vec.iter().copied()));
+            assert!(list.iter().rev().eq(vec.iter().rev()));
+        }
+    }
+
+    #[test]
+    fn sort_radix() {
+        fn test_sort_radix(keys: impl IntoIterator<Item = u64>) {
+            let mut vec = Vec::from_iter(keys.into_iter().enumerate().map(|(i, k)| (k, i)));
+            let mut list = List::from_iter(vec.iter().copied());
+            vec.sort_by_key(|&(k, _)| k);
+            list.sort_by_u64_key(|&(k, _)| k);
+            assert_eq!(list, List::from_iter(vec.iter().copied()));
+            assert!(list.iter().rev().eq(vec.iter().rev()));
+        }
+        test_sort_radix((0..1000).rev());
+        test_sort_radix((0..1000).map(|i| i % 7));
+        test_sort_radix((0..1000_u64).map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15) >> (i % 64)));
+        test_sort_radix((0..100).map(|i| u64::MAX - i % 3));
+        test_sort_radix((0..100).map(|i| (i % 5) << 40));
+        test_sort_radix([1, 0]);
+        test_sort_radix(Some(0));
+        test_sort_radix(None);
+
+        // A panicking key function keeps all the elements in the list.
+        let mut list = List::from_iter((0..300).rev());
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.sort_by_u64_key(|&k| {
+                calls += 1;
+                if calls == 500 {
+                    panic!("key function panics");
+                }
+                k
+            })
+        }));
+        assert!(result.is_err());
+        let mut values = Vec::from_iter(list.iter().copied());
+        assert!(list.iter().rev().eq(values.iter().rev()));
+        values.sort_unstable();
+        assert_eq!(values, Vec::from_iter(0..300));
+    }
+
+    #[test]
+    fn merge_stable() {
+        fn test_merge_stable(left: &[usize], right: &[usize]) {
+            let tag = |tag: usize| move |&k: &usize| (k, tag);
+            let mut list = List::from_iter(left.iter().map(tag(0)));
+            list.merge_by(List::from_iter(right.iter().map(tag(1))), |a, b| {
+                a.0.cmp(&b.0)
+            });
+            let mut vec = Vec::from_iter(left.iter().map(tag(0)).chain(right.iter().map(tag(1))));
+            vec.sort_by_key(|&(k, _)| k);
+            assert!(list.iter().eq(vec.iter()));
+            assert!(list.iter().rev().eq(vec.iter().rev()));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), vec.len());
+        }
+        test_merge_stable(&[], &[]);
+        test_merge_stable(&[1], &[]);
+        test_merge_stable(&[], &[1]);
+        test_merge_stable(&[1], &[1]);
+        test_merge_stable(&[2], &[1]);
+        test_merge_stable(&[1, 2, 3], &[4, 5]);
+        test_merge_stable(&[4, 5], &[1, 2, 3]);
+        test_merge_stable(&[1, 1, 3, 5], &[0, 1, 3, 3, 6]);
+    }
+
+    #[test]
+    fn sort_panic_safety() {
+        struct DropCounter<'a> {
+            value: usize,
+            dropped: &'a Cell<usize>,
+        }
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.dropped.set(self.dropped.get() + 1);
+            }
+        }
+
+        fn test_sort_panic_safety<S>(len: usize, panic_at: usize, sort: S)
+        where
+            S: Fn(&mut List<DropCounter<'_>>, &mut dyn FnMut(&usize, &usize) -> Ordering),
+        {
+            let dropped = Cell::new(0);
+            let mut list = List::from_iter((0..len).map(|i| DropCounter {
+                value: (i * 7 + 3) % len,
+                dropped: &dropped,
+            }));
+            let mut calls = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                sort(&mut list, &mut |a, b| {
+                    calls += 1;
+                    if calls == panic_at {
+                        panic!("comparator panics");
+                    }
+                    a.cmp(b)
+                })
+            }));
+            assert_eq!(result.is_err(), calls >= panic_at);
+            assert_eq!(dropped.get(), 0);
+
+            let mut values = Vec::from_iter(list.iter().map(|item| item.value));
+            assert!(list
+                .iter()
+                .rev()
+                .map(|item| item.value)
+                .eq(values.iter().rev().copied()));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), len);
+            values.sort_unstable();
+            assert_eq!(values, Vec::from_iter(0..len));
+
+            drop(list);
+            assert_eq!(dropped.get(), len);
+        }
+
+        for (len, panic_at) in [(5, 1), (5, 4), (50, 1), (50, 30), (50, 100)] {
+            test_sort_panic_safety(len, panic_at, |list, compare| {
+                list.sort_by(|a, b| compare(&a.value, &b.value))
+            });
+            test_sort_panic_safety(len, panic_at, |list, compare| {
+                list.sort_unstable_by(|a, b| compare(&a.value, &b.value))
+            });
+            test_sort_panic_safety(len, panic_at, |list, compare| {
+                list.sort_range_by(1..len - 1, |a, b| compare(&a.value, &b.value))
+            });
+            test_sort_panic_safety(len, panic_at, |list, compare| {
+                let other = list.split_off(len / 2);
+                list.merge_by(other, |a, b| compare(&a.value, &b.value))
+            });
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Allocators for the nodes of a [`List`](crate::List).
+//!
+//! Every node of a list, including the ghost node, is allocated and freed by
+//! the allocator of the list, which is [`Global`] by default. A list can be
+//! placed in an arena or a tracked heap by implementing [`RawAlloc`] and
+//! creating the list with [`List::new_in`].
+//!
+//! Only the constructors ending with `_in`, i.e. [`List::new_in`] and
+//! [`List::from_iter_in`], take an allocator. The other constructors and
+//! conversions, such as [`List::new`], [`List::from_fn`], `FromIterator`,
+//! `Default` and `From`, as well as deserializing with `feature = "rkyv"` and
+//! the `Arbitrary` support with `feature = "arbitrary"`, are for lists with the
+//! [`Global`] allocator only. The operations creating a list from another one,
+//! e.g. [`List::split_off`] and [`List::clone`](Clone::clone), use a clone of
+//! its allocator, and [`List::concat`] uses the allocator of the first list.
+//!
+//! [`List::new_in`]: crate::List::new_in
+//! [`List::from_iter_in`]: crate::List::from_iter_in
+//! [`List::new`]: crate::List::new
+//! [`List::from_fn`]: crate::List::from_fn
+//! [`List::split_off`]: crate::List::split_off
+//! [`List::concat`]: crate::List::concat
+//!
+//! # Examples
+//!
+//! ```
+//! use cyclic_list::list::alloc::{Global, RawAlloc};
+//! use cyclic_list::List;
+//! use std::alloc::Layout;
+//! use std::cell::Cell;
+//! use std::ptr::NonNull;
+//!
+//! /// Counts the live allocations made through it.
+//! struct Counting<'a>(&'a Cell<usize>);
+//!
+//! // Allocators sharing a counter can free the blocks of each other.
+//! impl PartialEq for Counting<'_> {
+//!     fn eq(&self, other: &Self) -> bool {
+//!         std::ptr::eq(self.0, other.0)
+//!     }
+//! }
+//!
+//! unsafe impl RawAlloc for Counting<'_> {
+//!     fn allocate(&self, layout: Layout) -> NonNull<u8> {
+//!         self.0.set(self.0.get() + 1);
+//!         Global.allocate(layout)
+//!     }
+//!
+//!     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+//!         self.0.set(self.0.get() - 1);
+//!         Global.deallocate(ptr, layout)
+//!     }
+//! }
+//!
+//! let live = Cell::new(0);
+//! let mut list = List::new_in(Counting(&live));
+//! list.extend([1, 2, 3]);
+//! // Three elements and the ghost node.
+//! assert_eq!(live.get(), 4);
+//!
+//! list.pop_front();
+//! assert_eq!(live.get(), 3);
+//!
+//! drop(list);
+//! assert_eq!(live.get(), 0);
+//! ```
+
+use std::alloc::Layout;
+use std::ptr::NonNull;
+
+/// A raw memory allocator for the nodes of a [`List`](crate::List).
+///
+/// # Safety
+///
+/// - `allocate` must return a block of memory that fits `layout`, and stays
+///   valid until it is passed to `deallocate`, or the lifetime bound of the
+///   allocator type ends.
+/// - A block allocated by an instance must be able to be deallocated by any
+///   other instance that compares equal to it, and a clone of an instance
+///   must compare equal to it.
+///
+/// The nodes can be moved between two lists only if their allocators compare
+/// equal. Otherwise, the operations that move nodes, e.g.
+/// [`List::append`](crate::List::append), panic.
+pub unsafe trait RawAlloc: PartialEq {
+    /// Allocate a block of memory for `layout`, which is never zero-sized.
+    ///
+    /// On failure, it should diverge, e.g. by calling
+    /// [`std::alloc::handle_alloc_error`].
+    fn allocate(&self, layout: Layout) -> NonNull<u8>;
+
+    /// Deallocate the block of memory at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be allocated by an allocator equal to this one with the
+    /// same `layout`, and has not been deallocated yet.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+}
+
+/// The global memory allocator, which is the default allocator of a
+/// [`List`](crate::List).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Global;
+
+unsafe impl RawAlloc for Global {
+    fn allocate(&self, layout: Layout) -> NonNull<u8> {
+        debug_assert!(layout.size() > 0, "Cannot allocate a zero-sized block");
+        // SAFETY: `layout` is never zero-sized.
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        NonNull::new(ptr).unwrap_or_else(|| std::alloc::handle_alloc_error(layout))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        std::alloc::dealloc(ptr.as_ptr(), layout)
+    }
+}
+
+unsafe impl<A: RawAlloc + ?Sized> RawAlloc for &A {
+    fn allocate(&self, layout: Layout) -> NonNull<u8> {
+        (**self).allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        (**self).deallocate(ptr, layout)
+    }
+}
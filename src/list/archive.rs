@@ -0,0 +1,88 @@
+//! Support for [`rkyv`], enabled by `feature = "rkyv"`.
+//!
+//! A [`List`] is archived as a contiguous [`ArchivedVec`], like a `Vec`, and
+//! the nodes are rebuilt when deserializing it. A list with any allocator can
+//! be archived, but it is always deserialized with the
+//! [`Global`](crate::list::alloc::Global) allocator.
+
+use crate::list::alloc::RawAlloc;
+use crate::List;
+use rkyv::rancor::Fallible;
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, Place, Serialize};
+
+impl<T: Archive, A: RawAlloc> Archive for List<T, A> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        #[cfg(feature = "length")]
+        let len = self.len();
+        #[cfg(not(feature = "length"))]
+        let len = self.iter().count();
+        ArchivedVec::resolve_from_len(len, resolver, out);
+    }
+}
+
+impl<T, A: RawAlloc, S> Serialize<S> for List<T, A>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        // `Iter` is an `ExactSizeIterator` only with the `length` feature.
+        #[cfg(feature = "length")]
+        let iter = self.iter();
+        #[cfg(not(feature = "length"))]
+        let iter = self.iter().collect::<Vec<_>>().into_iter();
+        ArchivedVec::<T::Archived>::serialize_from_iter::<T, _, _>(iter, serializer)
+    }
+}
+
+impl<T, D> Deserialize<List<T>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<List<T>, D::Error> {
+        let mut list = List::new();
+        for item in self.iter() {
+            list.push_back(item.deserialize(deserializer)?);
+        }
+        Ok(list)
+    }
+}
+
+impl<T: PartialEq<U>, U, A: RawAlloc> PartialEq<List<U, A>> for ArchivedVec<T> {
+    fn eq(&self, other: &List<U, A>) -> bool {
+        self.iter().eq(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::List;
+    use rkyv::rancor::Error;
+    use rkyv::vec::ArchivedVec;
+    use rkyv::Archived;
+    use std::iter::FromIterator;
+
+    #[test]
+    fn archive_round_trip() {
+        for len in 0..5 {
+            let list = List::from_iter((0..len).map(|i| i.to_string()));
+            let bytes = rkyv::to_bytes::<Error>(&list).unwrap();
+            let archived = rkyv::access::<ArchivedVec<Archived<String>>, Error>(&bytes).unwrap();
+            assert_eq!(archived.len(), len);
+            assert_eq!(archived, &list);
+
+            let deserialized = rkyv::deserialize::<List<String>, Error>(archived).unwrap();
+            assert_eq!(deserialized, list);
+            assert!(deserialized.iter().rev().eq(list.iter().rev()));
+            #[cfg(feature = "length")]
+            assert_eq!(deserialized.len(), len);
+        }
+    }
+}
@@ -1,8 +1,14 @@
-use crate::list::{List, Node};
+use crate::list::alloc::{Global, RawAlloc};
+use crate::list::iterator::TakeCycles;
+use crate::list::view::ListView;
+use crate::list::{DetachedNodes, List, Node};
+use crate::Iter;
 #[cfg(feature = "length")]
 use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 use std::ptr::NonNull;
 
 /// A cursor over a [`List`].
@@ -47,12 +53,31 @@ use std::ptr::NonNull;
 /// cursor.move_next_cyclic();
 /// assert_eq!(cursor.current(), Some(&'A'));
 /// ```
-#[derive(Clone)]
-pub struct Cursor<'a, T: 'a> {
+pub struct Cursor<'a, T: 'a, A: RawAlloc = Global> {
+    /// The index of the cursor, or `None` if it is not computed yet, e.g. the
+    /// cursor is created from a node by [`List::cursor_at`].
     #[cfg(feature = "length")]
-    index: usize,
+    index: Option<usize>,
+    /// The index of the cursor if it is known, which allows seeking relative
+    /// to the current position without the `length` feature.
+    #[cfg(not(feature = "length"))]
+    index_hint: Option<usize>,
     pub(crate) current: NonNull<Node<T>>,
-    pub(crate) list: &'a List<T>,
+    pub(crate) list: &'a List<T, A>,
+}
+
+// Not derived, since cloning a cursor does not require `T: Clone`.
+impl<'a, T: 'a, A: RawAlloc> Clone for Cursor<'a, T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(feature = "length")]
+            index: self.index,
+            #[cfg(not(feature = "length"))]
+            index_hint: self.index_hint,
+            current: self.current,
+            list: self.list,
+        }
+    }
 }
 
 /// Compare cursors by its position.
@@ -80,13 +105,13 @@ pub struct Cursor<'a, T: 'a> {
 /// // Different list, different positions.
 /// assert_ne!(cursor1, cursor3);
 /// ```
-impl<'a, T: 'a> PartialEq for Cursor<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> PartialEq for Cursor<'a, T, A> {
     fn eq(&self, other: &Self) -> bool {
         self.same_list_with(other) && self.current == other.current
     }
 }
 
-impl<'a, T: 'a> Eq for Cursor<'a, T> {}
+impl<'a, T: 'a, A: RawAlloc> Eq for Cursor<'a, T, A> {}
 
 /// Compare cursors by its position.
 ///
@@ -111,7 +136,7 @@ impl<'a, T: 'a> Eq for Cursor<'a, T> {}
 /// assert_eq!(cursor1.partial_cmp(&cursor3), None);
 /// ```
 #[cfg(feature = "length")]
-impl<'a, T: 'a> PartialOrd for Cursor<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> PartialOrd for Cursor<'a, T, A> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         if !self.same_list_with(other) {
             return None;
@@ -145,17 +170,175 @@ impl<'a, T: 'a> PartialOrd for Cursor<'a, T> {
 /// println!("{:?}", list.back());
 /// println!("{:?}", cursor.current());
 /// ```
-pub struct CursorMut<'a, T: 'a> {
+pub struct CursorMut<'a, T: 'a, A: RawAlloc = Global> {
+    /// The index of the cursor, or `None` if it is not computed yet, e.g. the
+    /// cursor is created from a node by [`List::cursor_at`].
     #[cfg(feature = "length")]
-    index: usize,
+    index: Option<usize>,
+    /// The index of the cursor if it is known, which allows seeking relative
+    /// to the current position without the `length` feature.
+    #[cfg(not(feature = "length"))]
+    index_hint: Option<usize>,
     pub(crate) current: NonNull<Node<T>>,
-    pub(crate) list: &'a mut List<T>,
+    pub(crate) list: &'a mut List<T, A>,
+    /// Set if the cursor is created by [`CursorMut::reborrow`].
+    #[cfg(feature = "length")]
+    _index_sync: Option<IndexSync<T, A>>,
+}
+
+/// Resynchronize the index of a cursor parked on the ghost node by
+/// [`CursorMut::reborrow`] with the length of the list, when the sub-cursor
+/// that may have inserted or removed elements is dropped.
+#[cfg(feature = "length")]
+struct IndexSync<T, A: RawAlloc> {
+    index: NonNull<Option<usize>>,
+    list: NonNull<List<T, A>>,
+}
+
+#[cfg(feature = "length")]
+impl<T, A: RawAlloc> Drop for IndexSync<T, A> {
+    fn drop(&mut self) {
+        // SAFETY: the parent cursor and its list are mutably borrowed by the
+        // sub-cursor owning `self`, which can no longer change the list.
+        unsafe { *self.index.as_ptr() = Some(self.list.as_ref().len()) }
+    }
+}
+
+/// A saved position of a cursor, created by [`Cursor::mark`] or [`CursorMut::mark`].
+///
+/// A mark records the node and the index of the cursor, so that a cursor can
+/// return to it later by `seek_to_mark`, without any manual bookkeeping of indices.
+///
+/// # Invalidation
+///
+/// A mark stays valid as long as the marked element is still in the list, and
+/// the number of elements before it is unchanged, i.e. the list is only edited
+/// after the marked element. A mark of the ghost node is always valid.
+///
+/// Otherwise, seeking to the mark fails. Note that if the marked element is
+/// removed and another element happens to be allocated at the same address and
+/// the same index, seeking to the mark panics with `debug_assertions` or
+/// `feature = "paranoid"`, or succeeds but points to the new element otherwise.
+pub struct CursorMark<T> {
+    #[cfg(feature = "length")]
+    index: Option<usize>,
+    #[cfg(not(feature = "length"))]
+    index_hint: Option<usize>,
+    node: NonNull<Node<T>>,
+    #[cfg(any(debug_assertions, feature = "paranoid"))]
+    generation: usize,
+}
+
+// Not derived, since copying a mark does not require `T: Copy`.
+impl<T> Clone for CursorMark<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CursorMark<T> {}
+
+impl<T> fmt::Debug for CursorMark<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("CursorMark");
+        f.field("node", &self.node);
+        #[cfg(feature = "length")]
+        f.field("index", &self.index);
+        f.finish()
+    }
+}
+
+/// A handle to an element of a [`List`], created by [`Cursor::handle`],
+/// [`CursorMut::handle`] or [`CursorMut::insert_with_handle`].
+///
+/// Unlike a [`CursorMark`], a handle keeps identifying the same element across
+/// insertions or removals of other elements in the list, since it does not
+/// record the index of the element. It is not tied to the lifetime of the list,
+/// and it does not keep the element alive either: after the element is removed,
+/// the handle is dangling, and [`List::contains_handle`] returns `false`.
+pub struct NodeHandle<T> {
+    node: NonNull<Node<T>>,
+    #[cfg(any(debug_assertions, feature = "paranoid"))]
+    generation: usize,
+}
+
+impl<T> NodeHandle<T> {
+    pub(crate) fn new(node: NonNull<Node<T>>) -> Self {
+        Self {
+            node,
+            // SAFETY: a handle is always created from a valid node of a list.
+            #[cfg(any(debug_assertions, feature = "paranoid"))]
+            generation: unsafe { node.as_ref().generation },
+        }
+    }
+
+    pub(crate) fn node(&self) -> NonNull<Node<T>> {
+        self.node
+    }
+
+    /// Returns `true` if the handle refers to `node`.
+    ///
+    /// With `debug_assertions` or `feature = "paranoid"`, a handle of a removed
+    /// node does not refer to another node that reuses its memory.
+    ///
+    /// It is unsafe because `node` must be a valid node.
+    pub(crate) unsafe fn refers_to(&self, node: NonNull<Node<T>>) -> bool {
+        #[cfg(any(debug_assertions, feature = "paranoid"))]
+        {
+            self.node == node && node.as_ref().generation == self.generation
+        }
+        #[cfg(not(any(debug_assertions, feature = "paranoid")))]
+        {
+            self.node == node
+        }
+    }
+}
+
+// Not derived, since copying a handle does not require `T: Copy`.
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeHandle<T> {}
+
+/// Like [`List::contains_handle`], the generation of the node is also compared
+/// with `debug_assertions` or `feature = "paranoid"`, so the handle of a removed
+/// element is not equal to the handle of another element that reuses its memory.
+impl<T> PartialEq for NodeHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(any(debug_assertions, feature = "paranoid"))]
+        {
+            self.node == other.node && self.generation == other.generation
+        }
+        #[cfg(not(any(debug_assertions, feature = "paranoid")))]
+        {
+            self.node == other.node
+        }
+    }
+}
+
+impl<T> Eq for NodeHandle<T> {}
+
+impl<T> Hash for NodeHandle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+        #[cfg(any(debug_assertions, feature = "paranoid"))]
+        self.generation.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for NodeHandle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NodeHandle").field(&self.node).finish()
+    }
 }
 
 macro_rules! impl_cursor {
     ($CURSOR:ident) => {
         // Private methods
-        impl<'a, T: 'a> $CURSOR<'a, T> {
+        impl<'a, T: 'a, A: RawAlloc> $CURSOR<'a, T, A> {
             pub(crate) fn is_ghost_node(&self) -> bool {
                 self.current == self.list.ghost_node()
             }
@@ -178,7 +361,7 @@ macro_rules! impl_cursor {
             /// the index will be invalid.
             #[cfg(feature = "length")]
             unsafe fn seek_forward_fast(&mut self, steps: usize) {
-                self.index = self.index.saturating_add(steps);
+                self.index = self.index.map(|index| index.saturating_add(steps));
                 (0..steps).for_each(|_| self.current = self.next_node());
             }
 
@@ -189,17 +372,39 @@ macro_rules! impl_cursor {
             /// the index will be invalid.
             #[cfg(feature = "length")]
             unsafe fn seek_backward_fast(&mut self, steps: usize) {
-                self.index = self.index.saturating_sub(steps);
+                self.index = self.index.map(|index| index.saturating_sub(steps));
                 (0..steps).for_each(|_| self.current = self.prev_node());
             }
+
+            /// Return the index of the cursor, and cache it if it is not
+            /// computed yet.
+            #[cfg(feature = "length")]
+            fn resolve_index(&mut self) -> usize {
+                match self.index {
+                    Some(index) => index,
+                    None => {
+                        let index = self.index();
+                        self.index = Some(index);
+                        index
+                    }
+                }
+            }
         }
 
         /// Public methods of cursor moving or locating
-        impl<'a, T: 'a> $CURSOR<'a, T> {
+        impl<'a, T: 'a, A: RawAlloc> $CURSOR<'a, T, A> {
             #[cfg(feature = "length")]
             /// Return the index of the cursor
+            ///
+            /// # Complexity
+            ///
+            /// This operation should compute in *O*(1) time, or in *O*(*n*) time
+            /// if the cursor is created by [`List::cursor_at`] and has not moved
+            /// to a known index yet.
             pub fn index(&self) -> usize {
+                // SAFETY: `self.current` is always a valid node in the list.
                 self.index
+                    .unwrap_or_else(|| unsafe { self.list.index_of_node(self.current) })
             }
 
             /// Returns `true` if the `List` is empty. See [`List::is_empty`].
@@ -240,9 +445,15 @@ macro_rules! impl_cursor {
                 }
                 #[cfg(feature = "length")]
                 if self.is_ghost_node() {
-                    self.index = 0;
+                    self.index = Some(0);
+                } else {
+                    self.index = self.index.map(|index| index + 1);
+                }
+                #[cfg(not(feature = "length"))]
+                if self.is_ghost_node() {
+                    self.index_hint = Some(0);
                 } else {
-                    self.index += 1;
+                    self.index_hint = self.index_hint.map(|index| index + 1);
                 }
                 self.current = self.next_node();
             }
@@ -276,9 +487,16 @@ macro_rules! impl_cursor {
                 }
                 #[cfg(feature = "length")]
                 if self.is_front_node() {
-                    self.index = self.list.len();
+                    self.index = Some(self.list.len());
+                } else {
+                    self.index = self.index.map(|index| index - 1);
+                }
+                // The index of the ghost node is unknown without the `length` feature.
+                #[cfg(not(feature = "length"))]
+                if self.is_front_node() {
+                    self.index_hint = None;
                 } else {
-                    self.index -= 1;
+                    self.index_hint = self.index_hint.map(|index| index - 1);
                 }
                 self.current = self.prev_node();
             }
@@ -413,11 +631,55 @@ macro_rules! impl_cursor {
                 (0..steps).try_for_each(|i| self.move_prev().map_err(|_| i))
             }
 
+            /// Move the cursor by given signed `offset`, forward if it is positive,
+            /// or backward if it is negative, or return an error which tells the
+            /// actual steps it has moved, when passing through the ghost node is
+            /// happened.
+            ///
+            /// See [`seek_forward`](Self::seek_forward) and
+            /// [`seek_backward`](Self::seek_backward) for details.
+            ///
+            /// # Complexity
+            ///
+            /// This operation should compute in *O*(*n*) time.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use cyclic_list::List;
+            /// use std::iter::FromIterator;
+            ///
+            /// let list = List::from_iter([1, 2, 3]);
+            /// let mut cursor = list.cursor_start();
+            ///
+            /// assert!(cursor.seek_by(2).is_ok());
+            /// assert_eq!(cursor.current(), Some(&3));
+            ///
+            /// assert!(cursor.seek_by(-1).is_ok());
+            /// assert_eq!(cursor.current(), Some(&2));
+            ///
+            /// // Forbid to move passing through the ghost node
+            /// assert_eq!(cursor.seek_by(-5), Err(1));
+            /// assert_eq!(cursor.current(), Some(&1));
+            /// ```
+            pub fn seek_by(&mut self, offset: isize) -> Result<(), usize> {
+                if offset < 0 {
+                    self.seek_backward(offset.unsigned_abs())
+                } else {
+                    self.seek_forward(offset as usize)
+                }
+            }
+
             /// Move the cursor to the given position `target`, or return the `target`
             /// as an error when `target > len`.
             ///
             /// If an error occurs, the cursor will stay put.
             ///
+            /// Without the `length` feature, the cursor moves relative to its
+            /// current position if its index is known, i.e. it has not passed
+            /// through the ghost node backward (or moved to the end) since the
+            /// last time it was at the start.
+            ///
             /// # Complexity
             ///
             /// This operation should compute in *O*(*n*) time.
@@ -447,16 +709,32 @@ macro_rules! impl_cursor {
             pub fn try_seek_to(&mut self, target: usize) -> Result<(), usize> {
                 #[cfg(not(feature = "length"))]
                 {
-                    let current = self.current;
-                    self.move_to_start();
-                    if self.seek_forward(target).is_err() {
+                    let (current, index_hint) = (self.current, self.index_hint);
+                    // current=c, target=t, ghost=#
+                    let result = match index_hint {
+                        // target is at the right side of current: [   c----->t   #]
+                        Some(index) if target >= index => self.seek_forward(target - index),
+                        // target is near the left side of current: [    t<--c     #]
+                        Some(index) if index - target <= target => {
+                            self.seek_backward(index - target)
+                        }
+                        // target is far from the left side of current, or the index
+                        // of current is unknown: [-->t      c #]
+                        _ => {
+                            self.move_to_start();
+                            self.seek_forward(target)
+                        }
+                    };
+                    if result.is_err() {
                         self.current = current;
+                        self.index_hint = index_hint;
                         return Err(target);
                     }
                 }
                 #[cfg(feature = "length")]
                 {
-                    if target == self.index {
+                    let index = self.resolve_index();
+                    if target == index {
                         return Ok(());
                     }
                     let len = self.list.len();
@@ -466,11 +744,11 @@ macro_rules! impl_cursor {
                         target if target == len => self.move_to_end(),
                         _ => unsafe {
                             // current=c, target=t, ghost=#
-                            if target > self.index {
+                            if target > index {
                                 // target is at the right side of current: [   c----->t   #]
-                                if target - self.index <= len - target {
+                                if target - index <= len - target {
                                     // target is near the right side of current: [    c-->t     #]
-                                    self.seek_forward_fast(target - self.index);
+                                    self.seek_forward_fast(target - index);
                                 } else {
                                     // target is far from the right side of current: [ c     t<--#]
                                     self.move_to_end();
@@ -478,9 +756,9 @@ macro_rules! impl_cursor {
                                 }
                             } else {
                                 // target is at the left side of current: [   t<-----c   #]
-                                if self.index - target <= target {
+                                if index - target <= target {
                                     // target is near the left side of current: [    t<--c     #]
-                                    self.seek_backward_fast(self.index - target);
+                                    self.seek_backward_fast(index - target);
                                 } else {
                                     // target is far from the left side of current: [-->t      c #]
                                     self.move_to_start();
@@ -520,6 +798,162 @@ macro_rules! impl_cursor {
                     .expect("Cannot seek to nonexistent place");
             }
 
+            /// Save the position of the cursor, which can be returned to later by
+            /// [`seek_to_mark`](Self::seek_to_mark).
+            ///
+            /// See [`CursorMark`] for the invalidation rules of marks.
+            ///
+            /// # Complexity
+            ///
+            /// This operation should compute in *O*(*1*) time.
+            pub fn mark(&self) -> CursorMark<T> {
+                CursorMark {
+                    #[cfg(feature = "length")]
+                    index: self.index,
+                    #[cfg(not(feature = "length"))]
+                    index_hint: self.index_hint,
+                    node: self.current,
+                    // SAFETY: `self.current` is always a valid node in the list.
+                    #[cfg(any(debug_assertions, feature = "paranoid"))]
+                    generation: unsafe { self.current.as_ref().generation },
+                }
+            }
+
+            /// Return a handle to the current element, or return `None` if the
+            /// cursor is located at the ghost node.
+            ///
+            /// See [`NodeHandle`] for details.
+            ///
+            /// # Complexity
+            ///
+            /// This operation should compute in *O*(*1*) time.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use cyclic_list::List;
+            /// use std::iter::FromIterator;
+            ///
+            /// let mut list = List::from_iter([1, 2, 3]);
+            /// let handle = list.cursor(1).handle().unwrap();
+            /// assert!(list.cursor_end().handle().is_none());
+            ///
+            /// // The handle survives edits of other elements.
+            /// list.push_front(0);
+            /// assert_eq!(list.pop_back(), Some(3));
+            /// assert!(list.contains_handle(&handle));
+            ///
+            /// list.clear();
+            /// assert!(!list.contains_handle(&handle));
+            /// ```
+            pub fn handle(&self) -> Option<NodeHandle<T>> {
+                if self.is_ghost_node() {
+                    return None;
+                }
+                Some(NodeHandle::new(self.current))
+            }
+
+            /// Move the cursor to the position saved by `mark`, or return the
+            /// `mark` as an error if it is invalidated.
+            ///
+            /// If an error occurs, the cursor will stay put. See [`CursorMark`]
+            /// for the invalidation rules of marks.
+            ///
+            /// # Complexity
+            ///
+            /// This operation should compute in *O*(*n*) time.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use cyclic_list::List;
+            /// use std::iter::FromIterator;
+            ///
+            /// let mut list = List::from_iter([1, 2, 3, 4]);
+            /// let mut cursor = list.cursor_mut(1);
+            /// let mark = cursor.mark();
+            ///
+            /// // Editing after the marked element keeps the mark valid.
+            /// cursor.seek_to(3);
+            /// cursor.insert(5);
+            /// assert!(cursor.try_seek_to_mark(mark).is_ok());
+            /// assert_eq!(cursor.current(), Some(&2));
+            ///
+            /// // Editing before the marked element invalidates the mark.
+            /// cursor.push_front(0);
+            /// assert!(cursor.try_seek_to_mark(mark).is_err());
+            /// assert_eq!(cursor.current(), Some(&2));
+            /// ```
+            pub fn try_seek_to_mark(&mut self, mark: CursorMark<T>) -> Result<(), CursorMark<T>> {
+                if mark.node == self.list.ghost_node() {
+                    self.move_to_end();
+                    return Ok(());
+                }
+                let saved = self.mark();
+                #[cfg(feature = "length")]
+                let index_hint = mark.index;
+                #[cfg(not(feature = "length"))]
+                let index_hint = mark.index_hint;
+                let found = match index_hint {
+                    Some(index) => self.try_seek_to(index).is_ok() && self.current == mark.node,
+                    // The index is unknown, so search the node from the start.
+                    None => {
+                        self.move_to_start();
+                        while self.current != mark.node && self.move_next().is_ok() {}
+                        self.current == mark.node
+                    }
+                };
+                // SAFETY: `self.current` is always a valid node in the list.
+                #[cfg(any(debug_assertions, feature = "paranoid"))]
+                assert!(
+                    !found || unsafe { self.current.as_ref().generation } == mark.generation,
+                    "Cannot seek to a stale mark, whose element has been removed"
+                );
+                if !found {
+                    self.current = saved.node;
+                    #[cfg(feature = "length")]
+                    {
+                        self.index = saved.index;
+                    }
+                    #[cfg(not(feature = "length"))]
+                    {
+                        self.index_hint = saved.index_hint;
+                    }
+                    return Err(mark);
+                }
+                Ok(())
+            }
+
+            /// Move the cursor to the position saved by `mark`.
+            ///
+            /// # Complexity
+            ///
+            /// This operation should compute in *O*(*n*) time.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `mark` is invalidated. See [`CursorMark`] for the
+            /// invalidation rules of marks.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use cyclic_list::List;
+            /// use std::iter::FromIterator;
+            ///
+            /// let list = List::from_iter([1, 2, 3]);
+            /// let mut cursor = list.cursor(2);
+            /// let mark = cursor.mark();
+            ///
+            /// cursor.move_to_start();
+            /// cursor.seek_to_mark(mark);
+            /// assert_eq!(cursor.current(), Some(&3));
+            /// ```
+            pub fn seek_to_mark(&mut self, mark: CursorMark<T>) {
+                self.try_seek_to_mark(mark)
+                    .expect("Cannot seek to an invalidated mark");
+            }
+
             /// Set the cursor to the start of the list (i.e. the first node).
             ///
             /// # Complexity
@@ -546,7 +980,11 @@ macro_rules! impl_cursor {
             pub fn move_to_start(&mut self) {
                 #[cfg(feature = "length")]
                 {
-                    self.index = 0;
+                    self.index = Some(0);
+                }
+                #[cfg(not(feature = "length"))]
+                {
+                    self.index_hint = Some(0);
                 }
                 self.current = self.list.front_node();
             }
@@ -577,7 +1015,11 @@ macro_rules! impl_cursor {
             pub fn move_to_end(&mut self) {
                 #[cfg(feature = "length")]
                 {
-                    self.index = self.list.len();
+                    self.index = Some(self.list.len());
+                }
+                #[cfg(not(feature = "length"))]
+                {
+                    self.index_hint = None;
                 }
                 self.current = self.list.ghost_node();
             }
@@ -632,15 +1074,101 @@ macro_rules! impl_cursor {
                 // is never a ghost node, and non-ghost nodes must hold a valid element.
                 Some(unsafe { &self.prev_node().as_ref().element })
             }
+
+            /// Return an immutable reference of the `n`-th element after the
+            /// current position without moving the cursor, or return `None` if it
+            /// passes through the ghost node.
+            ///
+            /// `peek_nth(0)` is the same as [`current`](Self::current).
+            ///
+            /// # Complexity
+            ///
+            /// This operation should compute in *O*(*n*) time.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use cyclic_list::List;
+            /// use std::iter::FromIterator;
+            ///
+            /// let list = List::from_iter([1, 2, 3]);
+            /// let cursor = list.cursor(1);
+            /// assert_eq!(cursor.peek_nth(0), Some(&2));
+            /// assert_eq!(cursor.peek_nth(1), Some(&3));
+            /// assert_eq!(cursor.peek_nth(2), None);
+            /// assert_eq!(cursor.peek_nth(3), None);
+            /// ```
+            pub fn peek_nth(&self, n: usize) -> Option<&'a T> {
+                #[cfg(feature = "length")]
+                if matches!(self.index, Some(index) if n >= self.list.len() - index) {
+                    return None;
+                }
+                let ghost = self.list.ghost_node();
+                let mut node = self.current;
+                for _ in 0..n {
+                    if node == ghost {
+                        return None;
+                    }
+                    // SAFETY: `node.next` is always valid since it is a cyclic list.
+                    node = unsafe { node.as_ref().next };
+                }
+                if node == ghost {
+                    return None;
+                }
+                // SAFETY: it is safe because non-ghost nodes must hold a
+                // valid element.
+                unsafe { Some(&node.as_ref().element) }
+            }
+
+            /// Return an immutable reference of the `n`-th element before the
+            /// current position without moving the cursor, or return `None` if it
+            /// passes through the ghost node.
+            ///
+            /// `peek_nth_back(0)` is the same as [`previous`](Self::previous).
+            ///
+            /// # Complexity
+            ///
+            /// This operation should compute in *O*(*n*) time.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use cyclic_list::List;
+            /// use std::iter::FromIterator;
+            ///
+            /// let list = List::from_iter([1, 2, 3]);
+            /// let cursor = list.cursor(2);
+            /// assert_eq!(cursor.peek_nth_back(0), Some(&2));
+            /// assert_eq!(cursor.peek_nth_back(1), Some(&1));
+            /// assert_eq!(cursor.peek_nth_back(2), None);
+            /// ```
+            pub fn peek_nth_back(&self, n: usize) -> Option<&'a T> {
+                #[cfg(feature = "length")]
+                if matches!(self.index, Some(index) if n >= index) {
+                    return None;
+                }
+                let ghost = self.list.ghost_node();
+                let mut node = self.current;
+                for _ in 0..=n {
+                    // SAFETY: `node.prev` is always valid since it is a cyclic list.
+                    node = unsafe { node.as_ref().prev };
+                    if node == ghost {
+                        return None;
+                    }
+                }
+                // SAFETY: it is safe because non-ghost nodes must hold a
+                // valid element.
+                unsafe { Some(&node.as_ref().element) }
+            }
         }
 
-        impl<'a, T: fmt::Debug + 'a> fmt::Debug for $CURSOR<'a, T> {
+        impl<'a, T: fmt::Debug + 'a, A: RawAlloc> fmt::Debug for $CURSOR<'a, T, A> {
             fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
                 let mut f = f.debug_struct(stringify!($CURSOR));
                 f.field("list", &self.list)
                     .field("current", &self.current());
                 #[cfg(feature = "length")]
-                f.field("index", &self.index);
+                f.field("index", &self.index());
                 f.finish()
             }
         }
@@ -650,52 +1178,307 @@ macro_rules! impl_cursor {
 impl_cursor!(CursorMut);
 impl_cursor!(Cursor);
 
-impl<'a, T: 'a> Cursor<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> Cursor<'a, T, A> {
     pub(crate) fn new(
-        list: &'a List<T>,
+        list: &'a List<T, A>,
         current: NonNull<Node<T>>,
         #[cfg(feature = "length")] index: usize,
     ) -> Self {
         Self {
             #[cfg(feature = "length")]
-            index,
+            index: Some(index),
+            #[cfg(not(feature = "length"))]
+            index_hint: (current == list.front_node()).then_some(0),
             current,
             list,
         }
     }
 
-    fn same_list_with(&self, other: &Self) -> bool {
-        std::ptr::eq(self.list, other.list)
-    }
-}
-
-impl<'a, T: 'a> CursorMut<'a, T> {
-    pub(crate) fn new(
-        list: &'a mut List<T>,
-        current: NonNull<Node<T>>,
-        #[cfg(feature = "length")] index: usize,
-    ) -> Self {
+    /// Create a cursor at `current`, whose index is computed only when needed.
+    pub(crate) fn at_node(list: &'a List<T, A>, current: NonNull<Node<T>>) -> Self {
         Self {
             #[cfg(feature = "length")]
-            index,
+            index: None,
+            #[cfg(not(feature = "length"))]
+            index_hint: (current == list.front_node()).then_some(0),
             current,
             list,
         }
     }
 
-    /// Insert a new item before the given node `next`.
-    ///
-    /// It is unsafe because it does not check whether `next` is
-    /// belong to the current list that the cursor points to.
+    fn same_list_with(&self, other: &Self) -> bool {
+        std::ptr::eq(self.list, other.list)
+    }
+}
+
+/// Public methods of `Cursor` only.
+impl<'a, T: 'a, A: RawAlloc> Cursor<'a, T, A> {
+    /// Return the signed number of steps to move from this cursor to `other`,
+    /// (positive for moving forward, and negative for moving backward) without
+    /// passing through the ghost node, or return `None` if the cursors belong
+    /// to different lists.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time with the `length` feature
+    /// (see [`index`](Self::index)), or in *O*(*d*) time otherwise, where *d*
+    /// is the distance of the cursors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let list = List::from_iter([1, 2, 3, 4]);
+    /// let cursor1 = list.cursor(1);
+    /// let cursor2 = list.cursor_end();
+    ///
+    /// assert_eq!(cursor1.distance_to(&cursor2), Some(3));
+    /// assert_eq!(cursor2.distance_to(&cursor1), Some(-3));
+    /// assert_eq!(cursor1.distance_to(&cursor1), Some(0));
+    ///
+    /// // Cursors of different lists have no distance.
+    /// let another_list = list.clone();
+    /// assert_eq!(cursor1.distance_to(&another_list.cursor(1)), None);
+    /// ```
+    pub fn distance_to(&self, other: &Self) -> Option<isize> {
+        if !self.same_list_with(other) {
+            return None;
+        }
+        #[cfg(feature = "length")]
+        {
+            Some(other.index() as isize - self.index() as isize)
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            // Walk both directions alternately, so that it stops as soon as
+            // `other` is met from either side.
+            let ghost = self.list.ghost_node();
+            let (mut forward, mut backward) = (self.current, self.current);
+            let mut steps = 0;
+            loop {
+                if forward == other.current {
+                    return Some(steps);
+                }
+                if backward == other.current {
+                    return Some(-steps);
+                }
+                // SAFETY: `forward` and `backward` are always valid nodes of the
+                // list, and so are their neighbours since it is a cyclic list.
+                unsafe {
+                    if forward != ghost {
+                        forward = forward.as_ref().next;
+                    }
+                    if backward.as_ref().prev != ghost {
+                        backward = backward.as_ref().prev;
+                    }
+                }
+                steps += 1;
+            }
+        }
+    }
+
+    /// Provides a double-ended iterator over the elements from this cursor
+    /// (inclusive) to `other` (exclusive).
+    ///
+    /// Unlike the cursor iterators, the iterator is non-cyclic and fused, and
+    /// iterating from the back does not change the direction of iteration.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time with the `length` feature
+    /// (see [`index`](Self::index)), or in *O*(*d*) time otherwise, where *d*
+    /// is the distance of the cursors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cursors belong to different lists, or `other` is located
+    /// before this cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let list = List::from_iter([1, 2, 3, 4, 5]);
+    /// let start = list.cursor(1);
+    /// let end = list.cursor(4);
+    ///
+    /// let mut iter = start.iter_to(&end);
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next_back(), Some(&4));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next_back(), None);
+    ///
+    /// assert_eq!(Vec::from_iter(start.iter_to(&list.cursor_end())), vec![&2, &3, &4, &5]);
+    /// ```
+    pub fn iter_to(&self, other: &Self) -> Iter<'a, T> {
+        let distance = self
+            .distance_to(other)
+            .expect("Cannot iterate between cursors of different lists");
+        assert!(
+            distance >= 0,
+            "Cannot iterate to a cursor before the current one"
+        );
+        // SAFETY: `self.current..other.current` is a valid range of the list,
+        // and its length is `distance`.
+        unsafe {
+            Iter::from_range(
+                self.current,
+                other.current,
+                #[cfg(feature = "length")]
+                (distance as usize),
+            )
+        }
+    }
+
+    /// Provides a borrowed view of the elements from this cursor (inclusive)
+    /// to `other` (exclusive).
+    ///
+    /// See [`ListView`] for details.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time with the `length` feature
+    /// (see [`index`](Self::index)), or in *O*(*d*) time otherwise, where *d*
+    /// is the distance of the cursors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cursors belong to different lists, or `other` is located
+    /// before this cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let list = List::from_iter([1, 2, 3, 4, 5]);
+    /// let view = list.cursor(1).view_to(&list.cursor(4));
+    ///
+    /// assert_eq!(view, List::from_iter([2, 3, 4]));
+    /// assert!(list.cursor(2).view_to(&list.cursor(2)).is_empty());
+    /// ```
+    pub fn view_to(&self, other: &Self) -> ListView<'a, T> {
+        let distance = self
+            .distance_to(other)
+            .expect("Cannot view between cursors of different lists");
+        assert!(
+            distance >= 0,
+            "Cannot view to a cursor before the current one"
+        );
+        // SAFETY: `self.current..other.current` is a valid range of the list,
+        // and its length is `distance`.
+        unsafe {
+            ListView::from_range(
+                self.current,
+                other.current,
+                #[cfg(feature = "length")]
+                (distance as usize),
+            )
+        }
+    }
+}
+
+impl<'a, T: 'a, A: RawAlloc> CursorMut<'a, T, A> {
+    pub(crate) fn new(
+        list: &'a mut List<T, A>,
+        current: NonNull<Node<T>>,
+        #[cfg(feature = "length")] index: usize,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "length")]
+            index: Some(index),
+            #[cfg(not(feature = "length"))]
+            index_hint: (current == list.front_node()).then_some(0),
+            current,
+            list,
+            #[cfg(feature = "length")]
+            _index_sync: None,
+        }
+    }
+
+    /// Create a cursor at `current`, whose index is computed only when needed.
+    pub(crate) fn at_node(list: &'a mut List<T, A>, current: NonNull<Node<T>>) -> Self {
+        Self {
+            #[cfg(feature = "length")]
+            index: None,
+            #[cfg(not(feature = "length"))]
+            index_hint: (current == list.front_node()).then_some(0),
+            current,
+            list,
+            #[cfg(feature = "length")]
+            _index_sync: None,
+        }
+    }
+
+    /// Insert a new item before the given node `next`.
+    ///
+    /// It is unsafe because it does not check whether `next` is
+    /// belong to the current list that the cursor points to.
     unsafe fn insert_before(&mut self, next: NonNull<Node<T>>, item: T) -> NonNull<Node<T>> {
-        let node = Node::new_detached(item);
+        let node = self.list.new_node(item);
         self.list.attach_node(next, node);
         node
     }
+
+    /// Like [`split`](CursorMut::split), but return the detached nodes.
+    pub(crate) fn split_nodes(&mut self) -> Option<DetachedNodes<T>> {
+        if self.is_ghost_node() {
+            return None;
+        }
+        #[cfg(feature = "length")]
+        let len = self.list.len - self.resolve_index();
+        // After splitting, the current node is pointing to the ghost node.
+        let current = std::mem::replace(&mut self.current, self.list.ghost_node());
+        // SAFETY: since current is a non-ghost node, the range from current to
+        // the ghost node is a valid range in the list, and thus it is safe.
+        unsafe {
+            Some(self.list.detach_nodes(
+                current,
+                self.list.back_node(),
+                #[cfg(feature = "length")]
+                len,
+            ))
+        }
+    }
+
+    /// Like [`split_before`](CursorMut::split_before), but return the detached
+    /// nodes.
+    pub(crate) fn split_before_nodes(&mut self) -> Option<DetachedNodes<T>> {
+        if self.is_front_node() {
+            return None;
+        }
+        // After splitting, the current node becomes a front node, so its
+        // index becomes 0.
+        #[cfg(feature = "length")]
+        let len = self.resolve_index();
+        #[cfg(feature = "length")]
+        {
+            self.index = Some(0);
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            self.index_hint = Some(0);
+        }
+        // SAFETY: since current is a non-front node, the range from the front node
+        // to the current node is a valid range in the list, and thus it is safe.
+        unsafe {
+            Some(self.list.detach_nodes(
+                self.list.front_node(),
+                self.prev_node(),
+                #[cfg(feature = "length")]
+                len,
+            ))
+        }
+    }
 }
 
 /// Methods that does not change the linking structure of the list.
-impl<'a, T: 'a> CursorMut<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> CursorMut<'a, T, A> {
     /// Return an mutable reference of current node of the cursor,
     /// or return `None` if it is located at the first node.
     ///
@@ -755,24 +1538,169 @@ impl<'a, T: 'a> CursorMut<'a, T> {
         Some(unsafe { &mut self.prev_node().as_mut().element })
     }
 
+    /// Replace the element of the current node with `item`, and return the
+    /// old element, or return `None` if the cursor is located at the ghost node
+    /// (in which case `item` is dropped and the list is unchanged).
+    ///
+    /// Unlike removing and re-inserting, the node is not unlinked from the list.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3]);
+    ///
+    /// let mut cursor = list.cursor_mut(1);
+    /// assert_eq!(cursor.replace(5), Some(2));
+    /// assert_eq!(cursor.current(), Some(&5));
+    ///
+    /// // Cannot replace the ghost node.
+    /// cursor.move_to_end();
+    /// assert_eq!(cursor.replace(6), None);
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![1, 5, 3]);
+    /// ```
+    pub fn replace(&mut self, item: T) -> Option<T> {
+        self.current_mut()
+            .map(|current| std::mem::replace(current, item))
+    }
+
+    /// Take the element of the current node, leaving `T::default()` in its
+    /// place, or return `None` if the cursor is located at the ghost node.
+    ///
+    /// Like [`replace`], the node is not unlinked from the list.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([
+    ///     String::from("a"),
+    ///     String::from("b"),
+    /// ]);
+    ///
+    /// let mut cursor = list.cursor_mut(1);
+    /// assert_eq!(cursor.take(), Some(String::from("b")));
+    /// assert_eq!(cursor.current(), Some(&String::new()));
+    ///
+    /// // Cannot take from the ghost node.
+    /// cursor.move_to_end();
+    /// assert_eq!(cursor.take(), None);
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec!["a", ""]);
+    /// ```
+    ///
+    /// [`replace`]: CursorMut::replace
+    pub fn take(&mut self) -> Option<T>
+    where
+        T: Default,
+    {
+        self.current_mut().map(std::mem::take)
+    }
+
     /// Re-borrow the mutable cursor as a short-lived immutable one.
-    pub fn as_cursor(&self) -> Cursor<'_, T> {
-        Cursor::new(
-            self.list,
-            self.current,
+    pub fn as_cursor(&self) -> Cursor<'_, T, A> {
+        Cursor {
             #[cfg(feature = "length")]
-            self.index,
-        )
+            index: self.index,
+            #[cfg(not(feature = "length"))]
+            index_hint: self.index_hint,
+            current: self.current,
+            list: self.list,
+        }
     }
 
     /// Convert the mutable cursor to an immutable one.
-    pub fn into_cursor(self) -> Cursor<'a, T> {
-        Cursor::new(
-            self.list,
-            self.current,
+    pub fn into_cursor(self) -> Cursor<'a, T, A> {
+        Cursor {
+            #[cfg(feature = "length")]
+            index: self.index,
+            #[cfg(not(feature = "length"))]
+            index_hint: self.index_hint,
+            current: self.current,
+            list: self.list,
+        }
+    }
+
+    /// Re-borrow the mutable cursor as a short-lived mutable one at the same
+    /// position, which can be passed by value to helper functions.
+    ///
+    /// Unlike reborrowing a `&mut` reference, this cursor does not keep its
+    /// position: since the sub-cursor may remove the current element, this
+    /// cursor is moved to the ghost node before control is given away, and
+    /// its index is updated when the sub-cursor is dropped. To continue where
+    /// the sub-cursor stopped, take a [`mark`] of it and [`seek_to_mark`]
+    /// afterwards.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use cyclic_list::list::cursor::{CursorMark, CursorMut};
+    /// use std::iter::FromIterator;
+    ///
+    /// // A helper that removes the current element and its next one.
+    /// fn remove_pair(mut cursor: CursorMut<'_, i32>) -> CursorMark<i32> {
+    ///     cursor.remove();
+    ///     cursor.remove();
+    ///     cursor.mark()
+    /// }
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4, 5]);
+    /// let mut cursor = list.cursor_mut(1);
+    ///
+    /// let mark = remove_pair(cursor.reborrow());
+    /// assert_eq!(cursor.current(), None);
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 3);
+    ///
+    /// cursor.seek_to_mark(mark);
+    /// assert_eq!(cursor.current(), Some(&4));
+    /// cursor.insert(0);
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![1, 0, 4, 5]);
+    /// ```
+    ///
+    /// [`mark`]: CursorMut::mark
+    /// [`seek_to_mark`]: CursorMut::seek_to_mark
+    pub fn reborrow(&mut self) -> CursorMut<'_, T, A> {
+        let current = self.current;
+        #[cfg(feature = "length")]
+        let index = self.index;
+        #[cfg(not(feature = "length"))]
+        let index_hint = self.index_hint;
+        self.move_to_end();
+        let list = NonNull::from(&mut *self.list);
+        CursorMut {
+            #[cfg(feature = "length")]
+            index,
+            #[cfg(not(feature = "length"))]
+            index_hint,
+            current,
+            // SAFETY: `self.list` is mutably borrowed as long as the sub-cursor.
+            list: unsafe { &mut *list.as_ptr() },
             #[cfg(feature = "length")]
-            self.index,
-        )
+            _index_sync: Some(IndexSync {
+                index: NonNull::from(&mut self.index),
+                list,
+            }),
+        }
     }
 
     /// Temporarily view the list via an immutable reference.
@@ -799,13 +1727,13 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     /// cursor.insert(4);
     /// assert_eq!(Vec::from_iter(list), vec![4, 1, 2, 3]);
     /// ```
-    pub fn view(&self) -> &List<T> {
+    pub fn view(&self) -> &List<T, A> {
         self.list
     }
 }
 
 /// Methods that might change the linking structure of the list.
-impl<'a, T: 'a> CursorMut<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> CursorMut<'a, T, A> {
     /// Add an element first in the list.
     ///
     /// It is the same as [`List::push_front`], except it avoids
@@ -837,7 +1765,11 @@ impl<'a, T: 'a> CursorMut<'a, T> {
         self.list.push_front(item);
         #[cfg(feature = "length")]
         {
-            self.index += 1;
+            self.index = self.index.map(|index| index + 1);
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            self.index_hint = self.index_hint.map(|index| index + 1);
         }
     }
 
@@ -882,7 +1814,11 @@ impl<'a, T: 'a> CursorMut<'a, T> {
         }
         #[cfg(feature = "length")]
         if !is_front {
-            self.index -= 1;
+            self.index = self.index.map(|index| index - 1);
+        }
+        #[cfg(not(feature = "length"))]
+        if !is_front {
+            self.index_hint = self.index_hint.map(|index| index - 1);
         }
         item
     }
@@ -916,6 +1852,15 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     /// assert_eq!(Vec::from_iter(list), vec![0, 1, 2, 3, 4]);
     /// ```
     pub fn push_back(&mut self, item: T) {
+        // The index of the ghost node grows with the list.
+        #[cfg(feature = "length")]
+        if self.is_ghost_node() {
+            self.index = self.index.map(|index| index + 1);
+        }
+        #[cfg(not(feature = "length"))]
+        if self.is_ghost_node() {
+            self.index_hint = self.index_hint.map(|index| index + 1);
+        }
         self.list.push_back(item)
     }
 
@@ -949,6 +1894,23 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     /// assert_eq!(Vec::from_iter(list), vec![0, 1, 2]);
     /// ```
     pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // The index of the ghost node shrinks with the list.
+        #[cfg(feature = "length")]
+        if self.is_ghost_node() {
+            self.index = self.index.map(|index| index - 1);
+        }
+        #[cfg(not(feature = "length"))]
+        if self.is_ghost_node() {
+            self.index_hint = self.index_hint.map(|index| index - 1);
+        }
+        // If the current node is popped, the cursor moves to the ghost node,
+        // whose index is the same as the popped node.
+        if self.next_node() == self.list.ghost_node() {
+            self.current = self.list.ghost_node();
+        }
         self.list.pop_back()
     }
 
@@ -988,17 +1950,22 @@ impl<'a, T: 'a> CursorMut<'a, T> {
         unsafe { self.insert_before(self.current, item) };
         #[cfg(feature = "length")]
         {
-            self.index += 1;
+            self.index = self.index.map(|index| index + 1);
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            self.index_hint = self.index_hint.map(|index| index + 1);
         }
     }
 
-    /// Remove the element at the cursor and return it, or return `None`
-    /// if the cursor is at the ghost node. After removal, the cursor
-    /// is moved to the next node unless no removing is happened.
+    /// Add an element before the cursor position, and return a handle to it.
+    ///
+    /// It is the same as [`insert`](CursorMut::insert), except it returns
+    /// a handle. See [`NodeHandle`] for details.
     ///
     /// # Complexity
     ///
-    /// This operation should compute in *O*(*1*) time.
+    /// This operation should compute in *O*(1) time.
     ///
     /// # Examples
     ///
@@ -1006,19 +1973,155 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     /// use cyclic_list::List;
     /// use std::iter::FromIterator;
     ///
-    /// let mut list = List::from_iter(0..10);
-    /// let mut cursor = list.cursor_mut(5);
-    ///
-    /// assert_eq!(cursor.remove(), Some(5)); // becomes [0, 1, 2, 3, 4, 6, 7, 8, 9]
-    /// #[cfg(feature = "length")]
-    /// assert_eq!(cursor.index(), 5);
-    /// assert_eq!(cursor.current(), Some(&6));
-    ///
-    /// cursor.move_to_start();
-    /// assert_eq!(cursor.remove(), Some(0)); // becomes [1, 2, 3, 4, 6, 7, 8, 9]
-    /// #[cfg(feature = "length")]
-    /// assert_eq!(cursor.index(), 0);
-    /// assert_eq!(cursor.current(), Some(&1));
+    /// let mut list = List::from_iter([1, 2, 3]);
+    /// let mut cursor = list.cursor_mut(1);
+    ///
+    /// let handle = cursor.insert_with_handle(4); // becomes [1, 4, 2, 3]
+    /// cursor.move_prev_cyclic();
+    /// assert_eq!(cursor.handle(), Some(handle));
+    /// ```
+    pub fn insert_with_handle(&mut self, item: T) -> NodeHandle<T> {
+        // SAFETY: `self.current` is a valid node in the list, so it is safe.
+        let node = unsafe { self.insert_before(self.current, item) };
+        #[cfg(feature = "length")]
+        {
+            self.index = self.index.map(|index| index + 1);
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            self.index_hint = self.index_hint.map(|index| index + 1);
+        }
+        NodeHandle::new(node)
+    }
+
+    /// Add all the elements from an iterator before the cursor position,
+    /// keeping their order.
+    ///
+    /// The new nodes are linked with each other before inserted into the list,
+    /// so the list is relinked only once. After insertion, the cursor stays put
+    /// but its `index` is increased by the number of the inserted elements.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*m*) time, where *m* is the number
+    /// of the inserted elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 5]);
+    /// let mut cursor = list.cursor_mut(1);
+    ///
+    /// cursor.insert_iter(2..5); // becomes [1, 2, 3, 4, 5]
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 4);
+    /// assert_eq!(cursor.current(), Some(&5));
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if let Some(detached) = DetachedNodes::from_iter_in(iter.into_iter(), &self.list.alloc) {
+            #[cfg(feature = "length")]
+            {
+                self.index = self.index.map(|index| index + detached.len);
+            }
+            #[cfg(not(feature = "length"))]
+            {
+                self.index_hint = None;
+            }
+            // SAFETY: `self.current.prev` and `self.current` are valid nodes in the list,
+            // and they are adjacent, so it is safe.
+            unsafe { self.list.attach_nodes(self.current, detached) };
+        }
+    }
+
+    /// Add all the elements from an iterator after the cursor position,
+    /// keeping their order.
+    ///
+    /// The new nodes are linked with each other before inserted into the list,
+    /// so the list is relinked only once. After insertion, the cursor stays put,
+    /// and its `index` is unchanged unless the cursor is at the ghost node,
+    /// where the elements are inserted at the front of the list.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*m*) time, where *m* is the number
+    /// of the inserted elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 5]);
+    /// let mut cursor = list.cursor_mut(0);
+    ///
+    /// cursor.insert_iter_after(2..5); // becomes [1, 2, 3, 4, 5]
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 0);
+    /// assert_eq!(cursor.current(), Some(&1));
+    ///
+    /// cursor.move_to_end();
+    /// cursor.insert_iter_after([-1, 0]); // becomes [-1, 0, 1, 2, 3, 4, 5]
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 7);
+    /// assert_eq!(cursor.current(), None);
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![-1, 0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert_iter_after<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if let Some(detached) = DetachedNodes::from_iter_in(iter.into_iter(), &self.list.alloc) {
+            #[cfg(feature = "length")]
+            if self.is_ghost_node() {
+                self.index = self.index.map(|index| index + detached.len);
+            }
+            #[cfg(not(feature = "length"))]
+            if self.is_ghost_node() {
+                self.index_hint = None;
+            }
+            // SAFETY: `self.current` and `self.current.next` are valid nodes in the
+            // list, and they are adjacent, so it is safe.
+            unsafe { self.list.attach_nodes(self.next_node(), detached) };
+        }
+    }
+
+    /// Remove the element at the cursor and return it, or return `None`
+    /// if the cursor is at the ghost node. After removal, the cursor
+    /// is moved to the next node unless no removing is happened.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*1*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(0..10);
+    /// let mut cursor = list.cursor_mut(5);
+    ///
+    /// assert_eq!(cursor.remove(), Some(5)); // becomes [0, 1, 2, 3, 4, 6, 7, 8, 9]
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 5);
+    /// assert_eq!(cursor.current(), Some(&6));
+    ///
+    /// cursor.move_to_start();
+    /// assert_eq!(cursor.remove(), Some(0)); // becomes [1, 2, 3, 4, 6, 7, 8, 9]
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 0);
+    /// assert_eq!(cursor.current(), Some(&1));
     ///
     /// cursor.move_to_end();
     /// assert_eq!(cursor.remove(), None); // removing at the ghost node returns `None`
@@ -1035,7 +2138,8 @@ impl<'a, T: 'a> CursorMut<'a, T> {
         // SAFETY: `self.current` is a valid non-ghost node in the list, so it is safe.
         let node = unsafe { self.list.detach_node(self.current) };
         self.current = self.next_node();
-        Some(node.element)
+        // SAFETY: `node` is detached from the list, and no longer used.
+        Some(unsafe { self.list.free_node(node) })
     }
 
     /// Remove the element before the cursor and return it, or return `None` if
@@ -1078,6 +2182,205 @@ impl<'a, T: 'a> CursorMut<'a, T> {
         self.move_prev().ok().and_then(|_| self.remove())
     }
 
+    /// Move the current element to the front of the list, by relinking its node
+    /// instead of moving the element. The cursor follows the element, so its
+    /// `index` becomes `0`.
+    ///
+    /// If the cursor is pointing at the ghost node, nothing happens.
+    ///
+    /// This is the core operation of an LRU cache, which moves a recently used
+    /// entry to the front.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_mut(2);
+    ///
+    /// cursor.move_current_to_front();
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 0);
+    /// assert_eq!(cursor.current(), Some(&3));
+    /// assert_eq!(cursor.peek_nth(1), Some(&1));
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![3, 1, 2, 4]);
+    /// ```
+    pub fn move_current_to_front(&mut self) {
+        if self.is_ghost_node() {
+            return;
+        }
+        // SAFETY: `self.current` is a valid non-ghost node in the list, and it is
+        // relinked before the first node of the rest of the list.
+        unsafe {
+            let node = self.list.detach_node(self.current);
+            let front = self.list.front_node();
+            self.current = node;
+            self.list.attach_node(front, self.current);
+        }
+        #[cfg(feature = "length")]
+        {
+            self.index = Some(0);
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            self.index_hint = Some(0);
+        }
+    }
+
+    /// Move the current element to the back of the list, by relinking its node
+    /// instead of moving the element. The cursor follows the element, so its
+    /// `index` becomes `len - 1`.
+    ///
+    /// If the cursor is pointing at the ghost node, nothing happens.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_mut(1);
+    ///
+    /// cursor.move_current_to_back();
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 3);
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// assert_eq!(cursor.previous(), Some(&4));
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![1, 3, 4, 2]);
+    /// ```
+    pub fn move_current_to_back(&mut self) {
+        if self.is_ghost_node() {
+            return;
+        }
+        // SAFETY: `self.current` is a valid non-ghost node in the list, and it is
+        // relinked before the ghost node.
+        unsafe {
+            let node = self.list.detach_node(self.current);
+            self.current = node;
+            self.list.attach_node(self.list.ghost_node(), self.current);
+        }
+        #[cfg(feature = "length")]
+        {
+            self.index = Some(self.list.len() - 1);
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            self.index_hint = None;
+        }
+    }
+
+    /// Rotate the list in-place such that the current element becomes the first
+    /// one, while the cyclic order of the elements is unchanged. The cursor stays
+    /// put, so its `index` becomes `0`.
+    ///
+    /// If the cursor is pointing at the ghost node, nothing happens.
+    ///
+    /// It is the same as [`List::rotate_left`] with the index of the cursor,
+    /// except it needs no seeking. Since the list is cyclic, only the ghost node
+    /// is relinked.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(['a', 'b', 'c', 'd', 'e', 'f']);
+    /// let mut cursor = list.cursor_mut(2);
+    ///
+    /// cursor.rotate_to_here();
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 0);
+    /// assert_eq!(cursor.current(), Some(&'c'));
+    /// assert_eq!(cursor.previous(), None);
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec!['c', 'd', 'e', 'f', 'a', 'b']);
+    /// ```
+    pub fn rotate_to_here(&mut self) {
+        if self.is_ghost_node() {
+            return;
+        }
+        // SAFETY: `self.current` is a valid node in the list.
+        unsafe { self.list.rebase_ghost(self.current) };
+        #[cfg(feature = "length")]
+        {
+            self.index = Some(0);
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            self.index_hint = Some(0);
+        }
+    }
+
+    /// Detach the `n` elements from the current one (inclusive) and return them
+    /// as a new list, or return `None` if there are less than `n` elements from
+    /// the current one to the back, where the list is unchanged.
+    ///
+    /// After extraction, the cursor points to the element after the extracted
+    /// ones, and its `index` is unchanged.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time for seeking the end of the
+    /// range, and *O*(1) time for detaching.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(0..10);
+    /// let mut cursor = list.cursor_mut(3);
+    ///
+    /// let extracted = cursor.extract_n(4).unwrap();
+    /// assert_eq!(Vec::from_iter(extracted), vec![3, 4, 5, 6]);
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 3);
+    /// assert_eq!(cursor.current(), Some(&7));
+    ///
+    /// // Only 3 elements remain from the cursor.
+    /// assert!(cursor.extract_n(4).is_none());
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![0, 1, 2, 7, 8, 9]);
+    /// ```
+    pub fn extract_n(&mut self, n: usize) -> Option<List<T, A>>
+    where
+        A: Clone,
+    {
+        let mut end = self.as_cursor();
+        end.seek_forward(n).ok()?;
+        let end = end.current;
+        let start = std::mem::replace(&mut self.current, end);
+        // SAFETY: `start..end` is a valid range of `n` nodes in the list,
+        // and thus it is safe.
+        unsafe {
+            Some(self.list.detach_range(
+                start,
+                end,
+                #[cfg(feature = "length")]
+                n,
+            ))
+        }
+    }
+
     /// Split the list into two after the current element (inclusive). This will
     /// return a new list consisting of everything after the cursor (inclusive),
     /// with the original list retaining everything before (exclusive).
@@ -1107,23 +2410,80 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     /// assert_eq!(Vec::from_iter(list2), vec![5, 6, 7, 8, 9]);
     /// assert_eq!(Vec::from_iter(list), vec![0, 1, 2, 3, 4]);
     /// ```
-    pub fn split(&mut self) -> Option<List<T>> {
-        if self.is_ghost_node() {
+    pub fn split(&mut self) -> Option<List<T, A>>
+    where
+        A: Clone,
+    {
+        let detached = self.split_nodes()?;
+        Some(List::from_detached_in(detached, self.list.alloc.clone()))
+    }
+
+    /// Split the list into two after the current element (exclusive). This will
+    /// return a new list consisting of everything after the cursor (exclusive),
+    /// with the original list retaining everything before (inclusive).
+    ///
+    /// The cursor stays on its element, which becomes the back of the original
+    /// list. If the cursor is pointing at the ghost node, the whole list is
+    /// split off, leaving the original list empty.
+    ///
+    /// If there is no element after the cursor, `None` will be returned.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*1*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(0..10);
+    /// let mut cursor = list.cursor_mut(5);
+    ///
+    /// // Split the list after cursor position (index = 5), and leave
+    /// // all the nodes before cursor (inclusive).
+    /// let list2 = cursor.split_after().unwrap();
+    /// assert_eq!(cursor.current(), Some(&5));
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 5);
+    /// assert!(cursor.split_after().is_none());
+    ///
+    /// assert_eq!(Vec::from_iter(list2), vec![6, 7, 8, 9]);
+    /// assert_eq!(Vec::from_iter(list), vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn split_after(&mut self) -> Option<List<T, A>>
+    where
+        A: Clone,
+    {
+        let next = self.next_node();
+        if next == self.list.ghost_node() {
             return None;
         }
         #[cfg(feature = "length")]
-        let len = self.list.len - self.index;
-        // After splitting, the current node is pointing to the ghost node.
-        let current = std::mem::replace(&mut self.current, self.list.ghost_node());
-        // SAFETY: since current is a non-ghost node, the range from current to
-        // the ghost node is a valid range in the list, and thus it is safe.
+        let len = if self.is_ghost_node() {
+            // After splitting, the list becomes empty, so the index of the
+            // ghost node becomes 0.
+            let len = self.resolve_index();
+            self.index = Some(0);
+            len
+        } else {
+            self.list.len - self.resolve_index() - 1
+        };
+        #[cfg(not(feature = "length"))]
+        if self.is_ghost_node() {
+            self.index_hint = Some(0);
+        }
+        // SAFETY: since the next node is a non-ghost node, the range from it to
+        // the back node is a valid range in the list, and thus it is safe.
         unsafe {
-            Some(List::from_detached(self.list.detach_nodes(
-                current,
+            let detached = self.list.detach_nodes(
+                next,
                 self.list.back_node(),
                 #[cfg(feature = "length")]
                 len,
-            )))
+            );
+            Some(List::from_detached_in(detached, self.list.alloc.clone()))
         }
     }
 
@@ -1156,24 +2516,12 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     /// assert_eq!(Vec::from_iter(list2), vec![0, 1, 2, 3, 4]);
     /// assert_eq!(Vec::from_iter(list), vec![5, 6, 7, 8, 9]);
     /// ```
-    pub fn split_before(&mut self) -> Option<List<T>> {
-        if self.is_front_node() {
-            return None;
-        }
-        // After splitting, the current node becomes a front node, so its
-        // index becomes 0.
-        #[cfg(feature = "length")]
-        let len = std::mem::replace(&mut self.index, 0);
-        // SAFETY: since current is a non-front node, the range from the front node
-        // to the current node is a valid range in the list, and thus it is safe.
-        unsafe {
-            Some(List::from_detached(self.list.detach_nodes(
-                self.list.front_node(),
-                self.prev_node(),
-                #[cfg(feature = "length")]
-                len,
-            )))
-        }
+    pub fn split_before(&mut self) -> Option<List<T, A>>
+    where
+        A: Clone,
+    {
+        let detached = self.split_before_nodes()?;
+        Some(List::from_detached_in(detached, self.list.alloc.clone()))
     }
 
     /// Splice another list between the current node and its previous node.
@@ -1182,6 +2530,10 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     ///
     /// This operation should compute in *O*(*1*) time.
     ///
+    /// # Panics
+    ///
+    /// Panics if the allocator of `other` is not equal to that of the list.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1200,17 +2552,68 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     ///
     /// assert_eq!(Vec::from_iter(list), Vec::from_iter(0..10));
     /// ```
-    pub fn splice(&mut self, other: List<T>) {
+    pub fn splice(&mut self, other: List<T, A>) {
+        self.list.check_same_alloc(&other);
         if let Some(detached) = other.into_detached() {
             #[cfg(feature = "length")]
             {
-                self.index += detached.len;
+                self.index = self.index.map(|index| index + detached.len);
+            }
+            // The number of the spliced elements is unknown without the `length` feature.
+            #[cfg(not(feature = "length"))]
+            {
+                self.index_hint = None;
             }
             // SAFETY: `self.current.prev` and `self.current` are valid nodes in the list,
             // and they are adjacent, so it is safe.
             unsafe { self.list.attach_nodes(self.current, detached) };
         }
     }
+
+    /// Splice another list between the current node and its previous node,
+    /// and move the cursor to the first spliced element.
+    ///
+    /// It is like [`splice`](CursorMut::splice), but the spliced elements
+    /// can be edited right away without seeking backward. The index of the
+    /// cursor is unchanged. If `other` is empty, the cursor stays put.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*1*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocator of `other` is not equal to that of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([0, 1, 7, 8, 9]);
+    /// let list2 = List::from_iter([2, 3, 4, 5, 6]);
+    /// let mut cursor = list.cursor_mut(2);
+    ///
+    /// // Splice another list at the cursor position, and move to its front.
+    /// cursor.splice_and_seek_front(list2);
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 2);
+    ///
+    /// *cursor.current_mut().unwrap() *= 10;
+    /// assert_eq!(Vec::from_iter(list), vec![0, 1, 20, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn splice_and_seek_front(&mut self, other: List<T, A>) {
+        self.list.check_same_alloc(&other);
+        if let Some(detached) = other.into_detached() {
+            let front = detached.front;
+            // SAFETY: `self.current.prev` and `self.current` are valid nodes in the list,
+            // and they are adjacent, so it is safe.
+            unsafe { self.list.attach_nodes(self.current, detached) };
+            self.current = front;
+        }
+    }
 }
 
 /// `CursorIter` provides an cursor-like iterator that are cyclic
@@ -1241,8 +2644,8 @@ impl<'a, T: 'a> CursorMut<'a, T> {
 ///
 /// [`Iter`]: crate::list::iterator::Iter
 /// [`IterMut`]: crate::list::iterator::IterMut
-pub struct CursorIter<'a, T: 'a> {
-    pub(crate) cursor: Cursor<'a, T>,
+pub struct CursorIter<'a, T: 'a, A: RawAlloc = Global> {
+    pub(crate) cursor: Cursor<'a, T, A>,
 }
 
 /// `CursorIterMut` provides an cursor-like mutable iterator
@@ -1274,8 +2677,8 @@ pub struct CursorIter<'a, T: 'a> {
 ///
 /// [`Iter`]: crate::list::iterator::Iter
 /// [`IterMut`]: crate::list::iterator::IterMut
-pub struct CursorIterMut<'a, T: 'a> {
-    pub(crate) cursor: CursorMut<'a, T>,
+pub struct CursorIterMut<'a, T: 'a, A: RawAlloc = Global> {
+    pub(crate) cursor: CursorMut<'a, T, A>,
 }
 
 /// `CursorBackIter` is largely the same asa [`CursorIter`],
@@ -1300,8 +2703,8 @@ pub struct CursorIterMut<'a, T: 'a> {
 /// let mut cursor = cursor_iter.into_cursor();
 /// assert_eq!(cursor.previous(), Some(&2));
 /// ```
-pub struct CursorBackIter<'a, T: 'a> {
-    pub(crate) cursor: Cursor<'a, T>,
+pub struct CursorBackIter<'a, T: 'a, A: RawAlloc = Global> {
+    pub(crate) cursor: Cursor<'a, T, A>,
 }
 
 /// `CursorBackIterMut` is largely the same asa [`CursorIterMut`],
@@ -1327,142 +2730,652 @@ pub struct CursorBackIter<'a, T: 'a> {
 /// let mut cursor = cursor_iter.into_cursor_mut();
 /// assert_eq!(cursor.previous(), Some(&5));
 /// ```
-pub struct CursorBackIterMut<'a, T: 'a> {
-    pub(crate) cursor: CursorMut<'a, T>,
+pub struct CursorBackIterMut<'a, T: 'a, A: RawAlloc = Global> {
+    pub(crate) cursor: CursorMut<'a, T, A>,
+}
+
+impl<'a, T: 'a, A: RawAlloc> CursorIter<'a, T, A> {
+    /// Convert the cursor iterator to a cursor.
+    pub fn into_cursor(self) -> Cursor<'a, T, A> {
+        self.cursor
+    }
+    /// Make a back iterator which reverses the iterating direction.
+    pub fn rev(self) -> CursorBackIter<'a, T, A> {
+        CursorBackIter {
+            cursor: self.cursor,
+        }
+    }
+    /// Peek the next item being iterated without consume it.
+    pub fn peek(&self) -> Option<&'a T> {
+        self.cursor.current()
+    }
+    /// Make an iterator that yields the elements for `cycles` full cycles
+    /// from the current position, skipping the ghost node, and then fuses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let list = List::from_iter([1, 2, 3]);
+    /// let cycles = list.cursor(1).into_iter().take_cycles(2);
+    /// assert_eq!(Vec::from_iter(cycles), vec![&2, &3, &1, &2, &3, &1]);
+    ///
+    /// // Nothing to cycle in an empty list.
+    /// let empty = List::<i32>::new();
+    /// assert_eq!(empty.cursor_start().into_iter().take_cycles(5).next(), None);
+    /// ```
+    pub fn take_cycles(self, cycles: usize) -> TakeCycles<Self> {
+        TakeCycles::new(self, cycles)
+    }
+
+    /// Return the number of the elements left to iterate before reaching
+    /// the ghost node.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[cfg(feature = "length")]
+    pub fn remaining(&self) -> usize {
+        self.cursor.list.len() - self.cursor.index()
+    }
+}
+
+impl<'a, T: 'a, A: RawAlloc> CursorIterMut<'a, T, A> {
+    /// Convert the mutable cursor iterator to an immutable cursor
+    pub fn into_cursor(self) -> Cursor<'a, T, A> {
+        self.cursor.into_cursor()
+    }
+    /// Convert the mutable cursor iterator to a mutable cursor.
+    pub fn into_cursor_mut(self) -> CursorMut<'a, T, A> {
+        self.cursor
+    }
+    /// Make a mutable cursor back iterator which reverses the
+    /// iterating direction.
+    pub fn rev(self) -> CursorBackIterMut<'a, T, A> {
+        CursorBackIterMut {
+            cursor: self.cursor,
+        }
+    }
+    /// Peek the next item being iterated (mutably) without consume it.
+    pub fn peek(&mut self) -> Option<&'a mut T> {
+        self.cursor.current_mut()
+    }
+    /// Peek the next item being iterated without consume it.
+    pub fn peek_next(&self) -> Option<&T> {
+        self.cursor.current()
+    }
+    /// Peek the next item being iterated (mutably) without consume it.
+    pub fn peek_next_mut(&mut self) -> Option<&mut T> {
+        self.cursor.current_mut()
+    }
+    /// Peek the item that is iterated last time, i.e. the item before
+    /// the next one.
+    ///
+    /// There is no mutable counterpart, since the item may have been yielded
+    /// by [`next`](Iterator::next) as a mutable reference that is still alive.
+    pub fn peek_prev(&self) -> Option<&T> {
+        self.cursor.previous()
+    }
+
+    /// Make an iterator that yields the elements (mutably) for `cycles` full
+    /// cycles from the current position, skipping the ghost node, and then fuses.
+    ///
+    /// See [`CursorIter::take_cycles`] for details.
+    pub fn take_cycles(self, cycles: usize) -> TakeCycles<Self> {
+        TakeCycles::new(self, cycles)
+    }
+
+    /// Return the number of the elements left to iterate before reaching
+    /// the ghost node.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[cfg(feature = "length")]
+    pub fn remaining(&self) -> usize {
+        self.cursor.list.len() - self.cursor.index()
+    }
+}
+
+impl<'a, T: 'a, A: RawAlloc> CursorBackIter<'a, T, A> {
+    /// Convert the cursor back iterator to a cursor.
+    pub fn into_cursor(self) -> Cursor<'a, T, A> {
+        self.cursor
+    }
+    /// Make a normal cursor iterator which recovers the
+    /// original iterating direction.
+    pub fn rev(self) -> CursorIter<'a, T, A> {
+        CursorIter {
+            cursor: self.cursor,
+        }
+    }
+    /// Peek the next item being iterated without consume it.
+    ///
+    /// Note that the iterating direction is opposite to the
+    /// normal cursor iterator.
+    pub fn peek(&self) -> Option<&'a T> {
+        self.cursor.previous()
+    }
+    /// Make an iterator that yields the elements for `cycles` full cycles
+    /// from the current position, skipping the ghost node, and then fuses.
+    ///
+    /// Note that the iterating direction is opposite to the
+    /// normal cursor iterator. See [`CursorIter::take_cycles`] for details.
+    pub fn take_cycles(self, cycles: usize) -> TakeCycles<Self> {
+        TakeCycles::new(self, cycles)
+    }
+
+    /// Return the number of the elements left to iterate before reaching
+    /// the ghost node.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[cfg(feature = "length")]
+    pub fn remaining(&self) -> usize {
+        self.cursor.index()
+    }
+}
+
+impl<'a, T: 'a, A: RawAlloc> CursorBackIterMut<'a, T, A> {
+    /// Convert the mutable cursor back iterator to an immutable cursor.
+    pub fn into_cursor(self) -> Cursor<'a, T, A> {
+        self.cursor.into_cursor()
+    }
+    /// Convert the mutable cursor back iterator to a mutable cursor.
+    pub fn into_cursor_mut(self) -> CursorMut<'a, T, A> {
+        self.cursor
+    }
+    /// Make a normal mutable cursor iterator which recovers the
+    /// original iterating direction.
+    pub fn rev(self) -> CursorIterMut<'a, T, A> {
+        CursorIterMut {
+            cursor: self.cursor,
+        }
+    }
+    /// Peek the next item being iterated (mutably) without consume it.
+    ///
+    /// Note that the iterating direction is opposite to the
+    /// normal cursor iterator.
+    pub fn peek(&mut self) -> Option<&'a mut T> {
+        self.cursor.previous_mut()
+    }
+    /// Peek the next item being iterated without consume it.
+    ///
+    /// Note that the iterating direction is opposite to the
+    /// normal cursor iterator.
+    pub fn peek_next(&self) -> Option<&T> {
+        self.cursor.previous()
+    }
+    /// Peek the next item being iterated (mutably) without consume it.
+    ///
+    /// Note that the iterating direction is opposite to the
+    /// normal cursor iterator.
+    pub fn peek_next_mut(&mut self) -> Option<&mut T> {
+        self.cursor.previous_mut()
+    }
+    /// Peek the item that is iterated last time, i.e. the item after
+    /// the next one in the list.
+    ///
+    /// See [`CursorIterMut::peek_prev`].
+    pub fn peek_prev(&self) -> Option<&T> {
+        self.cursor.current()
+    }
+
+    /// Make an iterator that yields the elements (mutably) for `cycles` full
+    /// cycles from the current position, skipping the ghost node, and then fuses.
+    ///
+    /// Note that the iterating direction is opposite to the
+    /// normal cursor iterator. See [`CursorIter::take_cycles`] for details.
+    pub fn take_cycles(self, cycles: usize) -> TakeCycles<Self> {
+        TakeCycles::new(self, cycles)
+    }
+
+    /// Return the number of the elements left to iterate before reaching
+    /// the ghost node.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[cfg(feature = "length")]
+    pub fn remaining(&self) -> usize {
+        self.cursor.index()
+    }
+}
+
+/// A pair of cursors over a mutable [`List`], which can move independently
+/// and mutate the elements they point to, but not the linking structure of the list.
+///
+/// This `struct` is created by [`List::cursor_pair_mut`].
+/// See its documentation for more.
+///
+/// It is useful for algorithms walking the list with two pointers, such as
+/// partitioning. Since both cursors may point to the same element, only one
+/// of them can be mutably accessed at a time, or both via [`both_mut`] when
+/// they point to different elements.
+///
+/// [`both_mut`]: CursorPairMut::both_mut
+pub struct CursorPairMut<'a, T: 'a, A: RawAlloc = Global> {
+    first: Cursor<'a, T, A>,
+    second: Cursor<'a, T, A>,
+    _marker: PhantomData<&'a mut List<T, A>>,
+}
+
+impl<'a, T: 'a, A: RawAlloc> CursorPairMut<'a, T, A> {
+    pub(crate) fn new(first: Cursor<'a, T, A>, second: Cursor<'a, T, A>) -> Self {
+        Self {
+            first,
+            second,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Return a short-lived immutable copy of the first cursor.
+    pub fn first(&self) -> Cursor<'_, T, A> {
+        self.first.clone()
+    }
+
+    /// Return a short-lived immutable copy of the second cursor.
+    pub fn second(&self) -> Cursor<'_, T, A> {
+        self.second.clone()
+    }
+
+    /// Move the first cursor by `offset`, like [`Cursor::seek_by`].
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    pub fn move_first(&mut self, offset: isize) -> Result<(), usize> {
+        self.first.seek_by(offset)
+    }
+
+    /// Move the second cursor by `offset`, like [`Cursor::seek_by`].
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    pub fn move_second(&mut self, offset: isize) -> Result<(), usize> {
+        self.second.seek_by(offset)
+    }
+
+    /// Return a mutable reference to the element of the first cursor,
+    /// or `None` if it is at the ghost node.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: the list is mutably borrowed by the pair, and the returned
+        // reference borrows the pair mutably.
+        unsafe { Self::element_mut(&self.first) }
+    }
+
+    /// Return a mutable reference to the element of the second cursor,
+    /// or `None` if it is at the ghost node.
+    pub fn second_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: the same as `first_mut`.
+        unsafe { Self::element_mut(&self.second) }
+    }
+
+    /// Return mutable references to the elements of both cursors, or `None`
+    /// if either cursor is at the ghost node, or both point to the same element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3]);
+    /// let mut pair = list.cursor_pair_mut(0, 2);
+    /// if let Some((a, b)) = pair.both_mut() {
+    ///     *a += *b;
+    ///     *b = 0;
+    /// }
+    ///
+    /// pair.move_second(-2).unwrap();
+    /// assert!(pair.both_mut().is_none());
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![4, 2, 0]);
+    /// ```
+    pub fn both_mut(&mut self) -> Option<(&mut T, &mut T)> {
+        if self.first.current == self.second.current {
+            return None;
+        }
+        // SAFETY: the two cursors point to different nodes,
+        // so the references do not alias.
+        unsafe {
+            Some((
+                Self::element_mut(&self.first)?,
+                Self::element_mut(&self.second)?,
+            ))
+        }
+    }
+
+    /// Swap the elements of both cursors, and return `true` if swapped, or
+    /// `false` if either cursor is at the ghost node.
+    ///
+    /// The cursors stay put, so they point to the swapped elements.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// // Reverse the list with two pointers.
+    /// let mut list = List::from_iter(1..=5);
+    /// let mut pair = list.cursor_pair_mut(0, 4);
+    /// while pair.first().distance_to(&pair.second()) > Some(0) {
+    ///     pair.swap();
+    ///     pair.move_first(1).unwrap();
+    ///     pair.move_second(-1).unwrap();
+    /// }
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![5, 4, 3, 2, 1]);
+    /// ```
+    pub fn swap(&mut self) -> bool {
+        if self.first.is_ghost_node() || self.second.is_ghost_node() {
+            return false;
+        }
+        if let Some((a, b)) = self.both_mut() {
+            std::mem::swap(a, b);
+        }
+        true
+    }
+
+    /// Convert the pair into two immutable cursors.
+    pub fn into_cursors(self) -> (Cursor<'a, T, A>, Cursor<'a, T, A>) {
+        (self.first, self.second)
+    }
+
+    /// It is unsafe because the returned reference must not alias with
+    /// any other references to the element.
+    unsafe fn element_mut<'b>(cursor: &Cursor<'a, T, A>) -> Option<&'b mut T> {
+        if cursor.is_ghost_node() {
+            return None;
+        }
+        Some(&mut (*cursor.current.as_ptr()).element)
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a, A: RawAlloc> fmt::Debug for CursorPairMut<'a, T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CursorPairMut")
+            .field(&self.first)
+            .field(&self.second)
+            .finish()
+    }
+}
+
+/// A position in a sorted [`List`], which may either hold a matching element
+/// or be a vacant place where one could be inserted.
+///
+/// This `enum` is created by [`List::entry_by`].
+/// See its documentation for more.
+pub enum Entry<'a, T: 'a, A: RawAlloc = Global> {
+    /// The cursor points to a matching element.
+    Occupied(OccupiedEntry<'a, T, A>),
+    /// No element matches, and the cursor points to the position where a
+    /// matching element could be inserted while maintaining sorted order.
+    Vacant(VacantEntry<'a, T, A>),
 }
 
-impl<'a, T: 'a> CursorIter<'a, T> {
-    /// Convert the cursor iterator to a cursor.
-    pub fn into_cursor(self) -> Cursor<'a, T> {
-        self.cursor
+/// An entry of a sorted [`List`] pointing to a matching element.
+/// It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, T: 'a, A: RawAlloc = Global> {
+    cursor: CursorMut<'a, T, A>,
+}
+
+/// An entry of a sorted [`List`] pointing to the position where a matching
+/// element could be inserted. It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, T: 'a, A: RawAlloc = Global> {
+    cursor: CursorMut<'a, T, A>,
+}
+
+impl<'a, T: 'a, A: RawAlloc> Entry<'a, T, A> {
+    /// Insert `item` if the entry is vacant, and return a mutable reference
+    /// to the element of the entry.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([(1, 'a'), (3, 'c')]);
+    ///
+    /// list.entry_by(|x| x.0.cmp(&2)).or_insert((2, 'b')).1 = 'B';
+    /// list.entry_by(|x| x.0.cmp(&3)).or_insert((3, 'x')).1 = 'C';
+    ///
+    /// assert_eq!(list.into_vec(), vec![(1, 'a'), (2, 'B'), (3, 'C')]);
+    /// ```
+    pub fn or_insert(self, item: T) -> &'a mut T {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(item),
+        }
     }
-    /// Make a back iterator which reverses the iterating direction.
-    pub fn rev(self) -> CursorBackIter<'a, T> {
-        CursorBackIter {
-            cursor: self.cursor,
+
+    /// Insert the result of `f` if the entry is vacant, and return a mutable
+    /// reference to the element of the entry.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time, plus a call to `f`.
+    pub fn or_insert_with<F>(self, f: F) -> &'a mut T
+    where
+        F: FnOnce() -> T,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(f()),
         }
     }
-    /// Peek the next item being iterated without consume it.
-    pub fn peek(&self) -> Option<&'a T> {
-        self.cursor.current()
+
+    /// Call `f` on the element if the entry is occupied, and return the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([(1, 10), (3, 30)]);
+    ///
+    /// for key in [3, 2, 3] {
+    ///     list.entry_by(|x| x.0.cmp(&key))
+    ///         .and_modify(|x| x.1 += 1)
+    ///         .or_insert((key, 20));
+    /// }
+    ///
+    /// assert_eq!(list.into_vec(), vec![(1, 10), (2, 20), (3, 32)]);
+    /// ```
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut T),
+    {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    /// Convert the entry to a mutable cursor pointing to the first matching
+    /// element, or the position where a matching element could be inserted.
+    pub fn into_cursor_mut(self) -> CursorMut<'a, T, A> {
+        match self {
+            Entry::Occupied(entry) => entry.cursor,
+            Entry::Vacant(entry) => entry.cursor,
+        }
     }
 }
 
-impl<'a, T: 'a> CursorIterMut<'a, T> {
-    /// Convert the mutable cursor iterator to an immutable cursor
-    pub fn into_cursor(self) -> Cursor<'a, T> {
-        self.cursor.into_cursor()
+impl<'a, T: 'a, A: RawAlloc> OccupiedEntry<'a, T, A> {
+    pub(crate) fn new(cursor: CursorMut<'a, T, A>) -> Self {
+        debug_assert!(!cursor.is_ghost_node());
+        Self { cursor }
     }
-    /// Convert the mutable cursor iterator to a mutable cursor.
-    pub fn into_cursor_mut(self) -> CursorMut<'a, T> {
+
+    /// Return a reference to the matching element.
+    pub fn get(&self) -> &T {
         self.cursor
+            .current()
+            .expect("An occupied entry is never ghost")
     }
-    /// Make a mutable cursor back iterator which reverses the
-    /// iterating direction.
-    pub fn rev(self) -> CursorBackIterMut<'a, T> {
-        CursorBackIterMut {
-            cursor: self.cursor,
-        }
-    }
-    /// Peek the next item being iterated (mutably) without consume it.
-    pub fn peek(&mut self) -> Option<&'a mut T> {
-        self.cursor.current_mut()
+
+    /// Return a mutable reference to the matching element.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.cursor
+            .current_mut()
+            .expect("An occupied entry is never ghost")
     }
-}
 
-impl<'a, T: 'a> CursorBackIter<'a, T> {
-    /// Convert the cursor back iterator to a cursor.
-    pub fn into_cursor(self) -> Cursor<'a, T> {
+    /// Convert the entry into a mutable reference to the matching element,
+    /// with the lifetime of the list.
+    pub fn into_mut(mut self) -> &'a mut T {
         self.cursor
+            .current_mut()
+            .expect("An occupied entry is never ghost")
     }
-    /// Make a normal cursor iterator which recovers the
-    /// original iterating direction.
-    pub fn rev(self) -> CursorIter<'a, T> {
-        CursorIter {
-            cursor: self.cursor,
-        }
+
+    /// Replace the matching element with `item`, and return the old one.
+    pub fn insert(&mut self, item: T) -> T {
+        std::mem::replace(self.get_mut(), item)
     }
-    /// Peek the next item being iterated without consume it.
+
+    /// Remove the matching element from the list and return it.
     ///
-    /// Note that the iterating direction is opposite to the
-    /// normal cursor iterator.
-    pub fn peek(&self) -> Option<&'a T> {
-        self.cursor.previous()
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use cyclic_list::list::cursor::Entry;
+    ///
+    /// let mut list = List::from([1, 2, 3]);
+    ///
+    /// if let Entry::Occupied(entry) = list.entry_by(|x| x.cmp(&2)) {
+    ///     assert_eq!(entry.remove(), 2);
+    /// }
+    /// assert_eq!(list.into_vec(), vec![1, 3]);
+    /// ```
+    pub fn remove(mut self) -> T {
+        self.cursor
+            .remove()
+            .expect("An occupied entry is never ghost")
+    }
+
+    /// Convert the entry to a mutable cursor pointing to the matching element.
+    pub fn into_cursor_mut(self) -> CursorMut<'a, T, A> {
+        self.cursor
     }
 }
 
-impl<'a, T: 'a> CursorBackIterMut<'a, T> {
-    /// Convert the mutable cursor back iterator to an immutable cursor.
-    pub fn into_cursor(self) -> Cursor<'a, T> {
-        self.cursor.into_cursor()
+impl<'a, T: 'a, A: RawAlloc> VacantEntry<'a, T, A> {
+    pub(crate) fn new(cursor: CursorMut<'a, T, A>) -> Self {
+        Self { cursor }
     }
-    /// Convert the mutable cursor back iterator to a mutable cursor.
-    pub fn into_cursor_mut(self) -> CursorMut<'a, T> {
+
+    /// Insert `item` at the position of the entry, and return a mutable
+    /// reference to it.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn insert(mut self, item: T) -> &'a mut T {
+        self.cursor.insert(item);
         self.cursor
+            .previous_mut()
+            .expect("The inserted element must be before the cursor")
     }
-    /// Make a normal mutable cursor iterator which recovers the
-    /// original iterating direction.
-    pub fn rev(self) -> CursorIterMut<'a, T> {
-        CursorIterMut {
-            cursor: self.cursor,
+
+    /// Convert the entry to a mutable cursor pointing to the position where
+    /// a matching element could be inserted (possibly the ghost node).
+    pub fn into_cursor_mut(self) -> CursorMut<'a, T, A> {
+        self.cursor
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a, A: RawAlloc> fmt::Debug for Entry<'a, T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Entry::Occupied(entry) => f.debug_tuple("Entry").field(entry).finish(),
+            Entry::Vacant(entry) => f.debug_tuple("Entry").field(entry).finish(),
         }
     }
-    /// Peek the next item being iterated (mutably) without consume it.
-    ///
-    /// Note that the iterating direction is opposite to the
-    /// normal cursor iterator.
-    pub fn peek(&mut self) -> Option<&'a mut T> {
-        self.cursor.previous_mut()
+}
+
+impl<'a, T: fmt::Debug + 'a, A: RawAlloc> fmt::Debug for OccupiedEntry<'a, T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OccupiedEntry").field(&self.cursor).finish()
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a, A: RawAlloc> fmt::Debug for VacantEntry<'a, T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VacantEntry").field(&self.cursor).finish()
     }
 }
 
-impl<'a, T: 'a> From<CursorIter<'a, T>> for Cursor<'a, T> {
-    fn from(cursor_iter: CursorIter<'a, T>) -> Self {
+impl<'a, T: 'a, A: RawAlloc> From<CursorIter<'a, T, A>> for Cursor<'a, T, A> {
+    fn from(cursor_iter: CursorIter<'a, T, A>) -> Self {
         cursor_iter.into_cursor()
     }
 }
 
-impl<'a, T: 'a> From<CursorIterMut<'a, T>> for CursorMut<'a, T> {
-    fn from(cursor_iter: CursorIterMut<'a, T>) -> Self {
+impl<'a, T: 'a, A: RawAlloc> From<CursorIterMut<'a, T, A>> for CursorMut<'a, T, A> {
+    fn from(cursor_iter: CursorIterMut<'a, T, A>) -> Self {
         cursor_iter.into_cursor_mut()
     }
 }
 
-impl<'a, T: 'a> From<CursorMut<'a, T>> for Cursor<'a, T> {
-    fn from(cursor: CursorMut<'a, T>) -> Self {
+impl<'a, T: 'a, A: RawAlloc> From<CursorMut<'a, T, A>> for Cursor<'a, T, A> {
+    fn from(cursor: CursorMut<'a, T, A>) -> Self {
         cursor.into_cursor()
     }
 }
 
-impl<'a, T: 'a> From<CursorIterMut<'a, T>> for CursorIter<'a, T> {
-    fn from(cursor_iter: CursorIterMut<'a, T>) -> Self {
+impl<'a, T: 'a, A: RawAlloc> From<CursorIterMut<'a, T, A>> for CursorIter<'a, T, A> {
+    fn from(cursor_iter: CursorIterMut<'a, T, A>) -> Self {
         cursor_iter.into_cursor().into_iter()
     }
 }
 
-unsafe impl<T: Sync> Send for Cursor<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Send for Cursor<'_, T, A> {}
+
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for Cursor<'_, T, A> {}
+
+unsafe impl<T: Send, A: RawAlloc + Send> Send for CursorMut<'_, T, A> {}
 
-unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for CursorMut<'_, T, A> {}
 
-unsafe impl<T: Send> Send for CursorMut<'_, T> {}
+unsafe impl<T: Send, A: RawAlloc + Send> Send for CursorPairMut<'_, T, A> {}
 
-unsafe impl<T: Sync> Sync for CursorMut<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for CursorPairMut<'_, T, A> {}
 
-unsafe impl<T: Sync> Send for CursorIter<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Send for CursorIter<'_, T, A> {}
 
-unsafe impl<T: Sync> Sync for CursorIter<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for CursorIter<'_, T, A> {}
 
-unsafe impl<T: Send> Send for CursorIterMut<'_, T> {}
+unsafe impl<T: Send, A: RawAlloc + Send> Send for CursorIterMut<'_, T, A> {}
 
-unsafe impl<T: Sync> Sync for CursorIterMut<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for CursorIterMut<'_, T, A> {}
 
-unsafe impl<T: Sync> Send for CursorBackIter<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Send for CursorBackIter<'_, T, A> {}
 
-unsafe impl<T: Sync> Sync for CursorBackIter<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for CursorBackIter<'_, T, A> {}
 
-unsafe impl<T: Send> Send for CursorBackIterMut<'_, T> {}
+unsafe impl<T: Send, A: RawAlloc + Send> Send for CursorBackIterMut<'_, T, A> {}
 
-unsafe impl<T: Sync> Sync for CursorBackIterMut<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for CursorBackIterMut<'_, T, A> {}
 
 #[cfg(test)]
 mod tests {
@@ -1503,6 +3416,8 @@ mod tests {
 
     #[test]
     fn cursor_write() {
+        // `Option::inspect` only lends a shared reference to the item.
+        #[allow(clippy::manual_inspect)]
         fn test_cursor_write<T, F, I1, I2>(input: I1, f: F, expected: I2)
         where
             T: Debug + Eq + Clone,
@@ -1541,13 +3456,44 @@ mod tests {
         test_cursor_write(0..5, |i| *i *= 2, [0, 2, 4, 6, 8]);
         test_cursor_write(
             [String::from("123"), String::from("abc")],
-            |s| s.push_str("#"),
+            |s| s.push('#'),
             [String::from("123#"), String::from("abc#")],
         );
         test_cursor_write(Some(0), |_| {}, Some(0));
         test_cursor_write::<i32, _, _, _>(None, |i| *i *= 2, None);
     }
 
+    #[test]
+    fn cursor_extend() {
+        for len in 0..4 {
+            for at in 0..=len {
+                let mut list = List::from_iter(0..len);
+                let mut cursor = list.cursor_mut(at);
+                cursor.extend(10..13);
+                #[cfg(feature = "length")]
+                assert_eq!(cursor.index(), at + 3);
+                assert_eq!(cursor.current(), (at..len).next().as_ref());
+                assert_eq!(cursor.previous(), Some(&12));
+
+                let expected = Vec::from_iter((0..at).chain(10..13).chain(at..len));
+                assert_eq!(list.to_vec(), expected);
+                assert!(list.iter().rev().eq(expected.iter().rev()));
+                #[cfg(feature = "length")]
+                assert_eq!(list.len(), len + 3);
+            }
+        }
+
+        // The items are attached at once, so the list is unchanged if the
+        // iterator panics.
+        let mut list = List::from_iter(0..3);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let iter = (10..13).inspect(|&i| assert_ne!(i, 12, "iterator panics"));
+            list.cursor_mut(1).extend(iter);
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.to_vec(), vec![0, 1, 2]);
+    }
+
     #[test]
     fn cursor_insert_and_remove() {
         fn test_cursor_insert_and_remove<T, I1, I2>(input: I1, at: usize, item: T, expected: I2)
@@ -1761,4 +3707,488 @@ mod tests {
         test_case(1, 0);
         test_case(0, 0);
     }
+
+    #[test]
+    fn cursor_split_after() {
+        fn test_cursor_split_after(len: usize, at: usize) {
+            let mut list = List::from_iter(0..len);
+            let mut cursor = list.cursor_mut(at);
+            let split = cursor.split_after();
+            #[cfg(feature = "length")]
+            assert_eq!(cursor.index(), if at == len { 0 } else { at });
+            if at == len {
+                assert!(cursor.current().is_none());
+            } else {
+                assert_eq!(cursor.current(), Some(&at));
+            }
+            let kept = if at == len { 0 } else { at + 1 };
+            assert_eq!(cursor.view(), &List::from_iter(0..kept));
+            match split {
+                Some(split) => {
+                    #[cfg(feature = "length")]
+                    assert_eq!(split.len(), len - kept);
+                    assert_eq!(Vec::from_iter(split), Vec::from_iter(kept..len));
+                }
+                None => assert_eq!(kept, len),
+            }
+        }
+        for len in 0..5 {
+            for at in 0..=len {
+                test_cursor_split_after(len, at);
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_distance_to() {
+        for len in 0..5 {
+            let list = List::from_iter(0..len);
+            for i in 0..=len {
+                for j in 0..=len {
+                    assert_eq!(
+                        list.cursor(i).distance_to(&list.cursor(j)),
+                        Some(j as isize - i as isize)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_seek_after_edits() {
+        let mut list = List::from_iter(0..10);
+        let mut cursor = list.cursor_mut(3);
+        let verify = |cursor: &mut CursorMut<'_, i32>, expected: &[i32]| {
+            for &target in &[6, 2, 5, 0, 3, 4, 4, expected.len()] {
+                cursor.seek_to(target);
+                assert_eq!(cursor.current(), expected.get(target));
+            }
+            assert!(cursor.try_seek_to(expected.len() + 1).is_err());
+            assert!(cursor.current().is_none());
+        };
+        verify(&mut cursor, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        cursor.seek_to(4);
+        cursor.insert(-1);
+        cursor.push_front(-2);
+        assert_eq!(cursor.pop_front(), Some(-2));
+        verify(&mut cursor, &[0, 1, 2, 3, -1, 4, 5, 6, 7, 8, 9]);
+        cursor.seek_to(2);
+        cursor.splice(List::from_iter([-3, -4]));
+        verify(&mut cursor, &[0, 1, -3, -4, 2, 3, -1, 4, 5, 6, 7, 8, 9]);
+        cursor.push_back(-5);
+        cursor.move_prev_cyclic();
+        assert_eq!(cursor.remove(), Some(-5));
+        cursor.seek_to(6);
+        let split = cursor.split_before();
+        assert_eq!(split, Some(List::from_iter([0, 1, -3, -4, 2, 3])));
+        verify(&mut cursor, &[-1, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn cursor_mark() {
+        let mut list = List::from_iter(0..5);
+        let mut cursor = list.cursor_mut(2);
+        let mark = cursor.mark();
+        cursor.move_to_end();
+        let ghost = cursor.mark();
+
+        // Edits after the marked element.
+        cursor.insert(5);
+        cursor.seek_to(3);
+        assert_eq!(cursor.remove(), Some(3));
+        cursor.seek_to_mark(mark);
+        assert_eq!(cursor.current(), Some(&2));
+        cursor.seek_to_mark(ghost);
+        assert!(cursor.current().is_none());
+        #[cfg(feature = "length")]
+        assert_eq!(cursor.index(), 5);
+
+        // The marked element is removed.
+        cursor.seek_to_mark(mark);
+        assert_eq!(cursor.remove(), Some(2));
+        cursor.move_to_start();
+        assert!(cursor.try_seek_to_mark(mark).is_err());
+        assert_eq!(cursor.current(), Some(&0));
+
+        // The cursor is marked after moving backward across the ghost node.
+        cursor.move_prev_cyclic();
+        cursor.move_prev_cyclic();
+        let mark = cursor.mark();
+        cursor.move_to_start();
+        cursor.seek_to_mark(mark);
+        assert_eq!(cursor.current(), Some(&5));
+        assert_eq!(cursor.view(), &List::from_iter([0, 1, 4, 5]));
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "paranoid"))]
+    #[should_panic(expected = "Cannot seek to a stale mark")]
+    fn cursor_stale_mark() {
+        let mut list = List::from_iter(0..5);
+        let mut cursor = list.cursor_mut(2);
+        // Forge a mark as if the element were removed and its memory reused.
+        let mut mark = cursor.mark();
+        mark.generation += 1;
+        cursor.move_to_start();
+        let _ = cursor.try_seek_to_mark(mark);
+    }
+
+    #[test]
+    fn cursor_peek_nth() {
+        for len in 0..5 {
+            let list = List::from_iter(0..len);
+            for at in 0..=len {
+                let cursor = list.cursor(at);
+                for n in 0..=len + 1 {
+                    assert_eq!(cursor.peek_nth(n), (at + n < len).then_some(&(at + n)));
+                    assert_eq!(
+                        cursor.peek_nth_back(n),
+                        (n < at).then(|| at - n - 1).as_ref()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_iter_size_hint() {
+        #[allow(unused_mut)]
+        let mut list = List::from_iter(0..5);
+        for at in 0..=5 {
+            let forward = list.cursor(at).into_iter();
+            let backward = list.cursor(at).into_iter().rev();
+            #[cfg(feature = "length")]
+            {
+                assert_eq!(forward.size_hint(), (5 - at, Some(5 - at)));
+                assert_eq!(backward.size_hint(), (at, Some(at)));
+                assert_eq!(list.cursor_mut(at).into_iter().remaining(), 5 - at);
+                assert_eq!(list.cursor_mut(at).into_iter().rev().remaining(), at);
+            }
+            #[cfg(not(feature = "length"))]
+            {
+                assert_eq!(forward.size_hint(), (usize::from(at < 5), None));
+                assert_eq!(backward.size_hint(), (usize::from(at > 0), None));
+            }
+            let vec = Vec::from_iter(list.cursor(at).into_iter().copied());
+            assert_eq!(vec, Vec::from_iter(at..5));
+        }
+    }
+
+    #[test]
+    fn cursor_take_cycles() {
+        for len in 0..4 {
+            let mut list = List::from_iter(0..len);
+            for at in 0..=len {
+                for cycles in 0..3 {
+                    let expected = Vec::from_iter((0..cycles * len).map(|i| (at + i) % len));
+                    let forward = list.cursor(at).into_iter().take_cycles(cycles);
+                    assert_eq!(Vec::from_iter(forward.copied()), expected);
+                    let forward = list.cursor_mut(at).into_iter().take_cycles(cycles);
+                    assert_eq!(Vec::from_iter(forward.map(|i| *i)), expected);
+
+                    let expected = Vec::from_iter(
+                        (0..cycles * len).map(|i| (at + 2 * len - 1 - i % len) % len),
+                    );
+                    let backward = list.cursor(at).into_iter().rev().take_cycles(cycles);
+                    assert_eq!(Vec::from_iter(backward.copied()), expected);
+                    let mut backward = list.cursor_mut(at).into_iter().rev().take_cycles(cycles);
+                    assert_eq!(Vec::from_iter(backward.by_ref().map(|i| *i)), expected);
+                    assert!(backward.next().is_none());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_take_cycles_empty() {
+        // An empty list stops at the first cycle, instead of running
+        // through all the cycles.
+        let mut list = List::<i32>::new();
+        let mut cycles = list.cursor_start().into_iter().take_cycles(usize::MAX);
+        assert_eq!(cycles.next(), None);
+        assert_eq!(cycles.next(), None);
+        let mut cycles = list
+            .cursor_end_mut()
+            .into_iter()
+            .rev()
+            .take_cycles(usize::MAX);
+        assert_eq!(cycles.next(), None);
+        assert!(cycles.next().is_none());
+    }
+
+    #[test]
+    fn cursor_iter_to() {
+        for len in 0..5 {
+            let list = List::from_iter(0..len);
+            for i in 0..=len {
+                for j in i..=len {
+                    let (start, end) = (list.cursor(i), list.cursor(j));
+                    assert_eq!(
+                        Vec::from_iter(start.iter_to(&end).copied()),
+                        Vec::from_iter(i..j)
+                    );
+                    assert_eq!(
+                        Vec::from_iter(start.iter_to(&end).rev().copied()),
+                        Vec::from_iter((i..j).rev())
+                    );
+                    #[cfg(feature = "length")]
+                    assert_eq!(start.iter_to(&end).len(), j - i);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot iterate to a cursor before the current one")]
+    fn cursor_iter_to_backward() {
+        let list = List::from_iter(0..5);
+        let _ = list.cursor(3).iter_to(&list.cursor(2));
+    }
+
+    #[test]
+    fn cursor_iter_mut_peek() {
+        let mut list = List::from_iter([1, 2, 3]);
+        let mut iter = list.cursor_start_mut().into_iter();
+        assert_eq!(iter.peek_prev(), None);
+        assert_eq!(iter.peek_next(), Some(&1));
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.peek_prev(), Some(&1));
+        *iter.peek_next_mut().unwrap() *= 10;
+        assert_eq!(iter.peek_next(), Some(&20));
+
+        let mut iter = iter.rev();
+        assert_eq!(iter.peek_next(), Some(&1));
+        assert_eq!(iter.peek_prev(), Some(&20));
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.peek_next(), None);
+        assert_eq!(iter.peek_prev(), Some(&1));
+        assert!(iter.peek_next_mut().is_none());
+        assert_eq!(Vec::from_iter(list), vec![1, 20, 3]);
+    }
+
+    #[test]
+    fn cursor_reborrow() {
+        fn skip_and_double(mut cursor: CursorMut<'_, i32>, n: usize) {
+            for _ in 0..n {
+                cursor.move_next().unwrap();
+            }
+            if let Some(current) = cursor.current_mut() {
+                *current *= 2;
+            }
+        }
+
+        let mut list = List::from_iter(1..=5);
+        let mut cursor = list.cursor_mut(1);
+        skip_and_double(cursor.reborrow(), 2);
+        assert!(cursor.current().is_none());
+        #[cfg(feature = "length")]
+        assert_eq!(cursor.index(), 5);
+
+        let mut sub = cursor.reborrow();
+        sub.move_to_start();
+        sub.remove();
+        let mark = sub.mark();
+        cursor.seek_to_mark(mark);
+        assert_eq!(cursor.current(), Some(&2));
+        cursor.insert(0);
+        assert_eq!(Vec::from_iter(list), vec![0, 2, 3, 8, 5]);
+    }
+
+    #[test]
+    fn cursor_reborrow_resync_index() {
+        let mut list = List::from_iter(0..6);
+        let mut cursor = list.cursor_mut(2);
+        {
+            let mut sub = cursor.reborrow();
+            sub.remove();
+            sub.remove();
+        }
+        #[cfg(feature = "length")]
+        assert_eq!(cursor.index(), 4);
+        assert!(cursor.current().is_none());
+        cursor.seek_to(3);
+        assert_eq!(cursor.current(), Some(&5));
+        cursor.seek_to(1);
+        assert_eq!(cursor.current(), Some(&1));
+
+        cursor.reborrow().insert_iter([6, 7, 8]);
+        #[cfg(feature = "length")]
+        assert_eq!(cursor.index(), 7);
+        cursor.seek_to(6);
+        assert_eq!(cursor.current(), Some(&5));
+        assert!(cursor.move_next().is_ok());
+        assert!(cursor.current().is_none());
+        assert_eq!(Vec::from_iter(list), vec![0, 6, 7, 8, 1, 4, 5]);
+    }
+
+    #[test]
+    fn cursor_pair_mut() {
+        // Hoare partition: move the even elements before the odd ones.
+        let mut list = List::from_iter([1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut pair = list.cursor_pair_mut(0, 8);
+        loop {
+            while pair.first().current().is_some_and(|x| x % 2 == 0) {
+                pair.move_first(1).unwrap();
+            }
+            pair.move_second(-1).unwrap();
+            while pair.second().current().is_some_and(|x| x % 2 != 0) {
+                if pair.move_second(-1).is_err() {
+                    break;
+                }
+            }
+            if pair.first().distance_to(&pair.second()) <= Some(0) {
+                break;
+            }
+            assert!(pair.swap());
+        }
+        let (first, second) = pair.into_cursors();
+        #[cfg(feature = "length")]
+        assert_eq!((first.index(), second.index()), (4, 3));
+        assert_eq!(first.current(), Some(&5));
+        assert_eq!(second.current(), Some(&4));
+        assert_eq!(Vec::from_iter(list), vec![8, 2, 6, 4, 5, 3, 7, 1]);
+
+        let mut list = List::from_iter([1, 2]);
+        let mut pair = list.cursor_pair_mut(2, 1);
+        assert!(pair.first_mut().is_none());
+        assert!(pair.both_mut().is_none());
+        assert!(!pair.swap());
+        *pair.second_mut().unwrap() = 3;
+        pair.move_first(-2).unwrap();
+        assert!(pair.swap());
+        assert_eq!(Vec::from_iter(list), vec![3, 1]);
+    }
+
+    #[test]
+    fn cursor_move_current_to_ends() {
+        let mut list = List::from_iter(0..5);
+        let handle = list.cursor(3).handle().unwrap();
+        let mut cursor = list.cursor_mut(3);
+        cursor.move_current_to_front();
+        cursor.move_current_to_front();
+        cursor.move_to_end();
+        cursor.move_current_to_back();
+        assert_eq!(cursor.current(), None);
+        cursor.seek_to(4);
+        cursor.move_current_to_back();
+        #[cfg(feature = "length")]
+        assert_eq!(cursor.index(), 4);
+        cursor.seek_to(2);
+        cursor.move_current_to_back();
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.previous(), Some(&4));
+        cursor.move_prev().unwrap();
+        cursor.move_current_to_front();
+        assert_eq!(cursor.peek_nth(1), Some(&3));
+        assert_eq!(Vec::from_iter(&list), vec![&4, &3, &0, &2, &1]);
+        // The moved elements keep their nodes.
+        assert!(list.contains_handle(&handle));
+        assert_eq!(unsafe { list.cursor_at(&handle) }.current(), Some(&3));
+
+        let mut list = List::from_iter([1]);
+        let mut cursor = list.cursor_start_mut();
+        cursor.move_current_to_back();
+        cursor.move_current_to_front();
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(Vec::from_iter(list), vec![1]);
+    }
+
+    #[test]
+    fn cursor_rotate_to_here() {
+        let mut list = List::from_iter(0..5);
+        let mut cursor = list.cursor_end_mut();
+        cursor.rotate_to_here();
+        assert_eq!(cursor.current(), None);
+        cursor.move_to_start();
+        cursor.rotate_to_here();
+        assert_eq!(cursor.current(), Some(&0));
+        cursor.seek_to(4);
+        cursor.rotate_to_here();
+        cursor.seek_to(3);
+        assert_eq!(cursor.current(), Some(&2));
+        cursor.rotate_to_here();
+        assert_eq!(cursor.peek_nth(2), Some(&4));
+        cursor.insert(5);
+        assert_eq!(Vec::from_iter(&list), vec![&5, &2, &3, &4, &0, &1]);
+        assert_eq!(list.back(), Some(&1));
+    }
+
+    #[test]
+    fn cursor_extract_n() {
+        for len in 0..5 {
+            for at in 0..=len {
+                for n in 0..=len + 1 {
+                    let mut list = List::from_iter(0..len);
+                    let mut cursor = list.cursor_mut(at);
+                    let extracted = cursor.extract_n(n);
+                    if at + n > len {
+                        assert!(extracted.is_none());
+                        assert!(list.iter().copied().eq(0..len));
+                        continue;
+                    }
+                    #[cfg(feature = "length")]
+                    assert_eq!(cursor.index(), at);
+                    assert_eq!(cursor.current(), Some(&(at + n)).filter(|&&x| x < len));
+                    let extracted = extracted.unwrap();
+                    assert!(extracted.iter().copied().eq(at..at + n));
+                    assert!(extracted.iter().rev().copied().eq((at..at + n).rev()));
+                    assert!(list.iter().copied().eq((0..at).chain(at + n..len)));
+                    #[cfg(feature = "length")]
+                    assert_eq!((list.len(), extracted.len()), (len - n, n));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_splice_and_seek_front() {
+        for len in 0..4 {
+            for at in 0..=len {
+                for other_len in 0..3 {
+                    let mut list = List::from_iter(0..len);
+                    let mut cursor = list.cursor_mut(at);
+                    cursor.splice_and_seek_front(List::from_iter(10..10 + other_len));
+                    #[cfg(feature = "length")]
+                    assert_eq!(cursor.index(), at);
+                    let expected = Vec::from_iter((0..at).chain(10..10 + other_len).chain(at..len));
+                    assert_eq!(cursor.current(), expected.get(at));
+                    assert!(list.iter().eq(expected.iter()));
+                    assert!(list.iter().rev().eq(expected.iter().rev()));
+                    #[cfg(feature = "length")]
+                    assert_eq!(list.len(), len + other_len);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_replace() {
+        let mut list = List::new();
+        assert_eq!(list.cursor_start_mut().replace(1), None);
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        let mut cursor = list.cursor_start_mut();
+        assert_eq!(cursor.replace(2), Some(1));
+        cursor.move_next_cyclic();
+        assert_eq!(cursor.replace(3), None);
+        cursor.move_next_cyclic();
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(Vec::from_iter(list), vec![2]);
+    }
+
+    #[test]
+    fn cursor_take() {
+        let mut list = List::<Vec<i32>>::new();
+        assert_eq!(list.cursor_end_mut().take(), None);
+        assert!(list.is_empty());
+
+        list.push_back(vec![1]);
+        let mut cursor = list.cursor_start_mut();
+        assert_eq!(cursor.take(), Some(vec![1]));
+        assert_eq!(cursor.take(), Some(vec![]));
+        cursor.move_prev_cyclic();
+        assert_eq!(cursor.take(), None);
+        assert_eq!(Vec::from_iter(list), vec![Vec::<i32>::new()]);
+    }
 }
@@ -0,0 +1,159 @@
+//! Support for [`arbitrary`], enabled by `feature = "arbitrary"`.
+//!
+//! Besides implementing [`Arbitrary`] for [`List`], this module provides
+//! [`ListOp`], a fuzzer-friendly operation on a list, so that fuzz targets
+//! can exercise the linking code with random sequences of operations, and
+//! compare the results against a [`VecDeque`] model.
+//!
+//! # Examples
+//!
+//! ```
+//! #![cfg(feature = "arbitrary")]
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use cyclic_list::list::fuzz::ListOp;
+//! use cyclic_list::List;
+//! use std::collections::VecDeque;
+//!
+//! let data = [7_u8; 64];
+//! let mut u = Unstructured::new(&data);
+//! let ops = Vec::<ListOp<u8>>::arbitrary(&mut u).unwrap();
+//!
+//! let (mut list, mut model) = (List::new(), VecDeque::new());
+//! for op in ops {
+//!     op.clone().apply(&mut list);
+//!     op.apply_to_deque(&mut model);
+//!     assert!(list.iter().eq(&model));
+//! }
+//! ```
+
+use crate::List;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use std::collections::VecDeque;
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for List<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// An operation on a [`List`], generated by [`Arbitrary`] for fuzzing.
+///
+/// The positions are arbitrary numbers, which are wrapped into the valid
+/// range of the list when the operation is applied, so that every operation
+/// can be applied to any list without panicking.
+#[derive(Arbitrary, Clone, Debug, PartialEq, Eq)]
+pub enum ListOp<T> {
+    /// [`List::push_front`].
+    PushFront(T),
+    /// [`List::push_back`].
+    PushBack(T),
+    /// [`List::pop_front`].
+    PopFront,
+    /// [`List::pop_back`].
+    PopBack,
+    /// [`List::insert`] at the wrapped position.
+    Insert(usize, T),
+    /// [`List::remove`] at the wrapped position, if the list is not empty.
+    Remove(usize),
+    /// [`List::split_off`] at the wrapped position, dropping the split part.
+    SplitOff(usize),
+    /// [`List::splice_at`] at the wrapped position.
+    Splice(usize, List<T>),
+    /// [`List::append`].
+    Append(List<T>),
+    /// [`List::rotate_left`] by the wrapped position.
+    RotateLeft(usize),
+    /// [`List::reverse`].
+    Reverse,
+}
+
+impl<T> ListOp<T> {
+    /// Apply the operation to `list`.
+    pub fn apply(self, list: &mut List<T>) {
+        #[cfg(feature = "length")]
+        let len = list.len();
+        #[cfg(not(feature = "length"))]
+        let len = list.iter().count();
+        match self {
+            ListOp::PushFront(item) => list.push_front(item),
+            ListOp::PushBack(item) => list.push_back(item),
+            ListOp::PopFront => drop(list.pop_front()),
+            ListOp::PopBack => drop(list.pop_back()),
+            ListOp::Insert(at, item) => list.insert(at % (len + 1), item),
+            ListOp::Remove(at) if len > 0 => drop(list.remove(at % len)),
+            ListOp::Remove(_) => {}
+            ListOp::SplitOff(at) => drop(list.split_off(at % (len + 1))),
+            ListOp::Splice(at, other) => list.splice_at(at % (len + 1), other),
+            ListOp::Append(mut other) => list.append(&mut other),
+            ListOp::RotateLeft(mid) => list.rotate_left(mid % (len + 1)),
+            ListOp::Reverse => list.reverse(),
+        }
+    }
+
+    /// Apply the operation to a [`VecDeque`] model of a list, with the
+    /// same effect as [`apply`](ListOp::apply) on the list.
+    pub fn apply_to_deque(self, deque: &mut VecDeque<T>) {
+        let len = deque.len();
+        match self {
+            ListOp::PushFront(item) => deque.push_front(item),
+            ListOp::PushBack(item) => deque.push_back(item),
+            ListOp::PopFront => drop(deque.pop_front()),
+            ListOp::PopBack => drop(deque.pop_back()),
+            ListOp::Insert(at, item) => deque.insert(at % (len + 1), item),
+            ListOp::Remove(at) if len > 0 => drop(deque.remove(at % len)),
+            ListOp::Remove(_) => {}
+            ListOp::SplitOff(at) => deque.truncate(at % (len + 1)),
+            ListOp::Splice(at, other) => {
+                let mut back = deque.split_off(at % (len + 1));
+                deque.extend(other);
+                deque.append(&mut back);
+            }
+            ListOp::Append(other) => deque.extend(other),
+            ListOp::RotateLeft(mid) => deque.rotate_left(mid % (len + 1)),
+            ListOp::Reverse => deque.make_contiguous().reverse(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListOp;
+    use crate::List;
+    use arbitrary::{Arbitrary, Unstructured};
+    use std::collections::VecDeque;
+    use std::iter::FromIterator;
+
+    #[test]
+    fn fuzz_ops() {
+        // A simple deterministic byte stream in place of a fuzzer.
+        let mut state = 0x2545_f491_u32;
+        let data = Vec::from_iter((0..4096).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        }));
+        let mut u = Unstructured::new(&data);
+        let (mut list, mut model) = (List::new(), VecDeque::new());
+        while let Ok(op) = ListOp::<u8>::arbitrary(&mut u) {
+            op.clone().apply(&mut list);
+            op.apply_to_deque(&mut model);
+            assert!(list.iter().eq(&model));
+            assert!(list.iter().rev().eq(model.iter().rev()));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), model.len());
+            if u.is_empty() {
+                break;
+            }
+        }
+    }
+}
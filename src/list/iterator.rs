@@ -1,10 +1,13 @@
+use crate::list::alloc::{Global, RawAlloc};
 use crate::list::cursor::{
     Cursor, CursorBackIter, CursorBackIterMut, CursorIter, CursorIterMut, CursorMut,
 };
-use crate::list::{List, Node};
+use crate::list::view::ListView;
+use crate::list::{DetachedNodes, List, Node};
 use std::fmt;
 use std::iter::{FromIterator, FusedIterator};
 use std::marker::PhantomData;
+use std::ops::Deref;
 use std::ptr::NonNull;
 
 /// An iterator over the elements of a `List`.
@@ -30,7 +33,6 @@ use std::ptr::NonNull;
 /// list.push_back(4);
 /// println!("{:?}", iter.next());
 /// ```
-#[derive(Clone)]
 pub struct Iter<'a, T: 'a> {
     start: NonNull<Node<T>>,
     end: NonNull<Node<T>>,
@@ -39,8 +41,21 @@ pub struct Iter<'a, T: 'a> {
     _marker: PhantomData<&'a List<T>>,
 }
 
+// Not derived, since cloning an iterator does not require `T: Clone`.
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start,
+            end: self.end,
+            #[cfg(feature = "length")]
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<'a, T: 'a> Iter<'a, T> {
-    pub(crate) fn new(list: &'a List<T>) -> Self {
+    pub(crate) fn new<A: RawAlloc>(list: &'a List<T, A>) -> Self {
         let start = list.front_node();
         let end = list.ghost_node();
         let _marker = PhantomData;
@@ -56,6 +71,26 @@ impl<'a, T: 'a> Iter<'a, T> {
     }
 }
 
+impl<'a, T: 'a> Iter<'a, T> {
+    /// Create an iterator over the range `start..end` with `len` elements.
+    ///
+    /// It is unsafe because `start..end` must be a valid range of a list
+    /// that is borrowed for `'a`.
+    pub(crate) unsafe fn from_range(
+        start: NonNull<Node<T>>,
+        end: NonNull<Node<T>>,
+        #[cfg(feature = "length")] len: usize,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            #[cfg(feature = "length")]
+            len,
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<'a, T: fmt::Debug + 'a> fmt::Debug for Iter<'a, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut f = f.debug_tuple("Iter");
@@ -128,6 +163,52 @@ impl<'a, T: 'a> ExactSizeIterator for Iter<'a, T> {}
 
 impl<'a, T: 'a> FusedIterator for Iter<'a, T> {}
 
+impl<'a, T: 'a> Iter<'a, T> {
+    /// Return a view of the elements that have not been yielded yet,
+    /// analogous to `slice::Iter::as_slice`.
+    ///
+    /// The view borrows the list for the same lifetime `'a` as the iterator,
+    /// so it can outlive the iterator and be iterated again.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let list = List::from_iter([1, 2, 3, 4, 5]);
+    /// let mut iter = list.iter();
+    /// iter.next();
+    /// iter.next_back();
+    ///
+    /// let remaining = iter.remaining();
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(remaining.len(), 3);
+    /// assert_eq!(remaining.front(), Some(&2));
+    /// assert_eq!(remaining.back(), Some(&4));
+    /// assert_eq!(Vec::from_iter(remaining.iter()), vec![&2, &3, &4]);
+    ///
+    /// // The iterator is not affected by the view.
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(Vec::from_iter(remaining), vec![&2, &3, &4]);
+    /// ```
+    pub fn remaining(&self) -> ListView<'a, T> {
+        // SAFETY: `start..end` is always a valid range of a list borrowed for `'a`.
+        unsafe {
+            ListView::from_range(
+                self.start,
+                self.end,
+                #[cfg(feature = "length")]
+                self.len,
+            )
+        }
+    }
+}
+
 /// A mutable iterator over the elements of a `List`.
 ///
 /// `start..end` denotes a subrange of the list.
@@ -149,16 +230,22 @@ impl<'a, T: 'a> FusedIterator for Iter<'a, T> {}
 /// println!("{:?}", list.back());
 /// println!("{:?}", iter.next());
 /// ```
-pub struct IterMut<'a, T: 'a> {
+pub struct IterMut<'a, T: 'a, A: RawAlloc = Global> {
     start: NonNull<Node<T>>,
     end: NonNull<Node<T>>,
     #[cfg(feature = "length")]
     len: usize,
-    _marker: PhantomData<&'a mut List<T>>,
+    /// The index of `start`.
+    #[cfg(feature = "length")]
+    index: usize,
+    /// The list being iterated, which is used to convert the iterator into
+    /// a cursor.
+    list: NonNull<List<T, A>>,
+    _marker: PhantomData<&'a mut List<T, A>>,
 }
 
-impl<'a, T: 'a> IterMut<'a, T> {
-    pub(crate) fn new(list: &'a mut List<T>) -> Self {
+impl<'a, T: 'a, A: RawAlloc> IterMut<'a, T, A> {
+    pub(crate) fn new(list: &'a mut List<T, A>) -> Self {
         let start = list.front_node();
         let end = list.ghost_node();
         let _marker = PhantomData;
@@ -169,12 +256,89 @@ impl<'a, T: 'a> IterMut<'a, T> {
             end,
             #[cfg(feature = "length")]
             len,
+            #[cfg(feature = "length")]
+            index: 0,
+            list: NonNull::from(list),
             _marker,
         }
     }
+
+    /// Create an iterator over the range `start..end` of `list` with `len`
+    /// elements, where `index` is the index of `start`.
+    ///
+    /// It is unsafe because `start..end` must be a valid range of `list`.
+    pub(crate) unsafe fn from_range(
+        list: &'a mut List<T, A>,
+        start: NonNull<Node<T>>,
+        end: NonNull<Node<T>>,
+        #[cfg(feature = "length")] len: usize,
+        #[cfg(feature = "length")] index: usize,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            #[cfg(feature = "length")]
+            len,
+            #[cfg(feature = "length")]
+            index,
+            list: NonNull::from(list),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Convert the iterator into a cursor with editing operations, which is
+    /// located at the next element to be iterated (or the ghost node if all
+    /// the elements from the front are iterated).
+    ///
+    /// If the iterator is exhausted after iterating from the back, the cursor
+    /// is located at the element that is iterated from the back last time.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Safety
+    ///
+    /// The references yielded by the iterator must not be used after the
+    /// cursor is created, since they would alias with the cursor, and the
+    /// elements might be removed by the cursor.
+    ///
+    /// If the iterator is created from a [`ListViewMut`](crate::ListViewMut), the cursor must not
+    /// change the linking structure of the list, since the view relies on it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4]);
+    /// let mut iter = list.iter_mut();
+    ///
+    /// // Locate the first even element by iterating.
+    /// while iter.next().map_or(false, |item| *item % 2 != 0) {}
+    ///
+    /// // Insert an element after it.
+    /// // SAFETY: the yielded references are no longer used.
+    /// let mut cursor = unsafe { iter.into_cursor_mut() };
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 2);
+    /// cursor.insert(0);
+    /// assert_eq!(Vec::from_iter(list), vec![1, 2, 0, 3, 4]);
+    /// ```
+    pub unsafe fn into_cursor_mut(self) -> CursorMut<'a, T, A> {
+        CursorMut::new(
+            // The list is mutably borrowed for `'a`, and the iterator is consumed,
+            // so the borrow is handed out to the cursor.
+            &mut *self.list.as_ptr(),
+            self.start,
+            #[cfg(feature = "length")]
+            self.index,
+        )
+    }
 }
 
-impl<'a, T: fmt::Debug + 'a> fmt::Debug for IterMut<'a, T> {
+impl<'a, T: fmt::Debug + 'a, A: RawAlloc> fmt::Debug for IterMut<'a, T, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut f = f.debug_tuple("IterMut");
         // SAFETY: `start..end` is always a valid range of a list,
@@ -189,7 +353,7 @@ impl<'a, T: fmt::Debug + 'a> fmt::Debug for IterMut<'a, T> {
     }
 }
 
-impl<'a, T: 'a> Iterator for IterMut<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> Iterator for IterMut<'a, T, A> {
     type Item = &'a mut T;
 
     /// Return `*start` and reset the iterating range to `(start.next)..end`,
@@ -205,6 +369,7 @@ impl<'a, T: 'a> Iterator for IterMut<'a, T> {
         #[cfg(feature = "length")]
         {
             self.len -= 1;
+            self.index += 1;
         }
         Some(&mut current.element)
     }
@@ -223,11 +388,11 @@ impl<'a, T: 'a> Iterator for IterMut<'a, T> {
 }
 
 #[cfg(feature = "length")]
-impl<'a, T: 'a> ExactSizeIterator for IterMut<'a, T> {}
+impl<'a, T: 'a, A: RawAlloc> ExactSizeIterator for IterMut<'a, T, A> {}
 
-impl<'a, T: 'a> FusedIterator for IterMut<'a, T> {}
+impl<'a, T: 'a, A: RawAlloc> FusedIterator for IterMut<'a, T, A> {}
 
-impl<'a, T: 'a> DoubleEndedIterator for IterMut<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> DoubleEndedIterator for IterMut<'a, T, A> {
     /// Reset the iterating range to `start..(end.prev)` and return `*end`,
     /// or return `None` if `start..end` is already empty.
     fn next_back(&mut self) -> Option<Self::Item> {
@@ -253,11 +418,11 @@ impl<'a, T: 'a> DoubleEndedIterator for IterMut<'a, T> {
 /// (provided by the `IntoIterator` trait). See its documentation for more.
 ///
 /// [`into_iter`]: List::into_iter
-pub struct IntoIter<T> {
-    list: List<T>,
+pub struct IntoIter<T, A: RawAlloc = Global> {
+    list: List<T, A>,
 }
 
-impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+impl<T: fmt::Debug, A: RawAlloc> fmt::Debug for IntoIter<T, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("IntoIter")
             .field("list", &self.list)
@@ -265,7 +430,7 @@ impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
     }
 }
 
-impl<T> Iterator for IntoIter<T> {
+impl<T, A: RawAlloc> Iterator for IntoIter<T, A> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -286,27 +451,570 @@ impl<T> Iterator for IntoIter<T> {
     }
 }
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
+impl<T, A: RawAlloc> DoubleEndedIterator for IntoIter<T, A> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.list.pop_back()
     }
 }
 
 #[cfg(feature = "length")]
-impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T, A: RawAlloc> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: RawAlloc> FusedIterator for IntoIter<T, A> {}
+
+/// An iterator over a `List` in (non-overlapping) chunks of `size` elements,
+/// starting at the front of the list. Each chunk is an [`Iter`] over its
+/// elements.
+///
+/// When the list length is not evenly divided by the chunk size, the last
+/// chunk will be shorter.
+///
+/// This `struct` is created by the [`chunks`] method on [`List`].
+/// See its documentation for more.
+///
+/// [`chunks`]: List::chunks
+pub struct Chunks<'a, T: 'a> {
+    rest: Iter<'a, T>,
+    size: usize,
+}
+
+impl<'a, T: 'a> Chunks<'a, T> {
+    pub(crate) fn new<A: RawAlloc>(list: &'a List<T, A>, size: usize) -> Self {
+        let rest = Iter::new(list);
+        Self { rest, size }
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a> fmt::Debug for Chunks<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chunks")
+            .field("rest", &self.rest)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<'a, T: 'a> Iterator for Chunks<'a, T> {
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.start == self.rest.end {
+            return None;
+        }
+        let start = self.rest.start;
+        #[cfg_attr(not(feature = "length"), allow(unused_variables))]
+        let len = self.rest.by_ref().take(self.size).count();
+        // SAFETY: `start..(rest.start)` is a valid range of the list.
+        unsafe {
+            Some(Iter::from_range(
+                start,
+                self.rest.start,
+                #[cfg(feature = "length")]
+                len,
+            ))
+        }
+    }
+
+    #[cfg(feature = "length")]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.len.div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "length")]
+impl<'a, T: 'a> ExactSizeIterator for Chunks<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for Chunks<'a, T> {}
+
+/// An owning iterator over a `List` in (non-overlapping) chunks of `size`
+/// elements, starting at the front of the list. Each chunk is a `List`
+/// that reuses the nodes of the original list.
+///
+/// When the list length is not evenly divided by the chunk size, the last
+/// chunk will be shorter.
+///
+/// This `struct` is created by the [`into_chunks`] method on [`List`].
+/// See its documentation for more.
+///
+/// [`into_chunks`]: List::into_chunks
+pub struct IntoChunks<T, A: RawAlloc = Global> {
+    list: List<T, A>,
+    size: usize,
+}
+
+impl<T, A: RawAlloc> IntoChunks<T, A> {
+    pub(crate) fn new(list: List<T, A>, size: usize) -> Self {
+        Self { list, size }
+    }
+}
+
+impl<T: fmt::Debug, A: RawAlloc> fmt::Debug for IntoChunks<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoChunks")
+            .field("list", &self.list)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<T, A: RawAlloc + Clone> Iterator for IntoChunks<T, A> {
+    type Item = List<T, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut cursor = self.list.cursor_start_mut();
+        // If the list is shorter than `size`, the cursor stays at the ghost
+        // node, and the whole list is split off.
+        let _ = cursor.seek_forward(self.size);
+        cursor.split_before()
+    }
+
+    #[cfg(feature = "length")]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len.div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "length")]
+impl<T, A: RawAlloc + Clone> ExactSizeIterator for IntoChunks<T, A> {}
+
+impl<T, A: RawAlloc + Clone> FusedIterator for IntoChunks<T, A> {}
+
+/// An iterator over overlapping windows of `size` elements of a `List`,
+/// starting at the front of the list. Each window is an [`Iter`] over its
+/// elements.
+///
+/// If the list is shorter than `size`, the iterator returns no windows.
+///
+/// This `struct` is created by the [`windows`] method on [`List`].
+/// See its documentation for more.
+///
+/// [`windows`]: List::windows
+pub struct Windows<'a, T: 'a> {
+    /// The first node of the next window.
+    start: NonNull<Node<T>>,
+    /// The node after the last node of the next window, or `None` if there
+    /// are no more windows.
+    end: Option<NonNull<Node<T>>>,
+    ghost: NonNull<Node<T>>,
+    #[cfg(feature = "length")]
+    size: usize,
+    #[cfg(feature = "length")]
+    len: usize,
+    _marker: PhantomData<&'a List<T>>,
+}
+
+impl<'a, T: 'a> Windows<'a, T> {
+    pub(crate) fn new<A: RawAlloc>(list: &'a List<T, A>, size: usize) -> Self {
+        let mut rest = Iter::new(list);
+        let end = match rest.by_ref().take(size).count() {
+            taken if taken == size => Some(rest.start),
+            _ => None,
+        };
+        Self {
+            start: list.front_node(),
+            end,
+            ghost: list.ghost_node(),
+            #[cfg(feature = "length")]
+            size,
+            #[cfg(feature = "length")]
+            len: (list.len() + 1).saturating_sub(size),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a> fmt::Debug for Windows<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_tuple("Windows");
+        if self.end.is_some() {
+            // SAFETY: `start..ghost` is always a valid range of a list,
+            // so it is safe.
+            let mut ptr = self.start;
+            while ptr != self.ghost {
+                let current = unsafe { ptr.as_ref() };
+                f.field(&current.element);
+                ptr = current.next;
+            }
+        }
+        f.finish()
+    }
+}
+
+impl<'a, T: 'a> Iterator for Windows<'a, T> {
+    type Item = Iter<'a, T>;
+
+    /// Return the window `start..end` and reset the next window to
+    /// `(start.next)..(end.next)`, or return `None` if there are no more
+    /// windows.
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.end?;
+        // SAFETY: `start..end` is always a valid range of a list,
+        // and `end` is not the ghost node when moving it, so it is safe.
+        let window = unsafe {
+            Iter::from_range(
+                self.start,
+                end,
+                #[cfg(feature = "length")]
+                self.size,
+            )
+        };
+        if end == self.ghost {
+            self.end = None;
+        } else {
+            self.start = unsafe { self.start.as_ref().next };
+            self.end = Some(unsafe { end.as_ref().next });
+        }
+        #[cfg(feature = "length")]
+        {
+            self.len -= 1;
+        }
+        Some(window)
+    }
+
+    #[cfg(feature = "length")]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+#[cfg(feature = "length")]
+impl<'a, T: 'a> ExactSizeIterator for Windows<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for Windows<'a, T> {}
+
+/// An iterator over overlapping pairs of adjacent elements of a `List`,
+/// starting at the front of the list.
+///
+/// This `struct` is created by the [`windows2`] method on [`List`].
+/// See its documentation for more.
+///
+/// [`windows2`]: List::windows2
+pub struct Windows2<'a, T: 'a> {
+    prev: Option<&'a T>,
+    rest: Iter<'a, T>,
+}
+
+impl<'a, T: 'a> Windows2<'a, T> {
+    pub(crate) fn new<A: RawAlloc>(list: &'a List<T, A>) -> Self {
+        let mut rest = Iter::new(list);
+        let prev = rest.next();
+        Self { prev, rest }
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a> fmt::Debug for Windows2<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Windows2")
+            .field("prev", &self.prev)
+            .field("rest", &self.rest)
+            .finish()
+    }
+}
+
+impl<'a, T: 'a> Iterator for Windows2<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.rest.next()?;
+        let prev = self.prev.replace(next)?;
+        Some((prev, next))
+    }
+
+    #[cfg(feature = "length")]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rest.size_hint()
+    }
+}
+
+#[cfg(feature = "length")]
+impl<'a, T: 'a> ExactSizeIterator for Windows2<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for Windows2<'a, T> {}
+
+/// An iterator over the elements of a `List`, together with the cursors
+/// pointing to them.
+///
+/// This `struct` is created by the [`iter_with_cursors`] method on [`List`].
+/// See its documentation for more.
+///
+/// [`iter_with_cursors`]: List::iter_with_cursors
+pub struct IterWithCursors<'a, T: 'a, A: RawAlloc = Global> {
+    cursor: Cursor<'a, T, A>,
+}
+
+impl<'a, T: 'a, A: RawAlloc> IterWithCursors<'a, T, A> {
+    pub(crate) fn new(list: &'a List<T, A>) -> Self {
+        Self {
+            cursor: list.cursor_start(),
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a, A: RawAlloc> fmt::Debug for IterWithCursors<'a, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterWithCursors")
+            .field(&self.cursor)
+            .finish()
+    }
+}
+
+impl<'a, T: 'a, A: RawAlloc> Iterator for IterWithCursors<'a, T, A> {
+    type Item = (Cursor<'a, T, A>, &'a T);
+
+    /// Return the current element with a copy of the cursor, and move the
+    /// cursor to the next element, or return `None` at the ghost node.
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.cursor.current()?;
+        let cursor = self.cursor.clone();
+        self.cursor.move_next_cyclic();
+        Some((cursor, item))
+    }
+
+    #[cfg(feature = "length")]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.cursor.list.len() - self.cursor.index();
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "length")]
+impl<'a, T: 'a, A: RawAlloc> ExactSizeIterator for IterWithCursors<'a, T, A> {}
+
+impl<'a, T: 'a, A: RawAlloc> FusedIterator for IterWithCursors<'a, T, A> {}
+
+/// An iterator over a range of a `List` that may wrap around from the back
+/// to the front of the list, skipping the ghost node.
+///
+/// The range is split at the ghost node into a `head` ending at the back of
+/// the list and a `tail` starting at the front of the list, either of which
+/// may be empty.
+///
+/// This `struct` is created by the [`iter_cyclic_from`] method on [`List`],
+/// and is the item type of [`CyclicWindows`].
+///
+/// [`iter_cyclic_from`]: List::iter_cyclic_from
+pub struct CyclicIter<'a, T: 'a> {
+    head: Iter<'a, T>,
+    tail: Iter<'a, T>,
+}
+
+// Not derived, since cloning an iterator does not require `T: Clone`.
+impl<T> Clone for CyclicIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            tail: self.tail.clone(),
+        }
+    }
+}
+
+impl<'a, T: 'a> CyclicIter<'a, T> {
+    pub(crate) fn new(head: Iter<'a, T>, tail: Iter<'a, T>) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a> fmt::Debug for CyclicIter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_tuple("CyclicIter");
+        for element in self.clone() {
+            f.field(element);
+        }
+        f.finish()
+    }
+}
+
+impl<'a, T: 'a> Iterator for CyclicIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.head.next().or_else(|| self.tail.next())
+    }
+
+    #[cfg(feature = "length")]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.head.len + self.tail.len;
+        (len, Some(len))
+    }
+
+    fn last(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        self.next_back()
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for CyclicIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.tail.next_back().or_else(|| self.head.next_back())
+    }
+}
+
+#[cfg(feature = "length")]
+impl<'a, T: 'a> ExactSizeIterator for CyclicIter<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for CyclicIter<'a, T> {}
+
+/// An iterator over overlapping windows of `size` elements of a `List`,
+/// including the windows wrapping around from the back to the front of
+/// the list. Each window is a [`CyclicIter`].
+///
+/// There is a window starting at each element of the list. If the list is
+/// shorter than `size`, the iterator returns no windows.
+///
+/// This `struct` is created by the [`cyclic_windows`] method on [`List`].
+/// See its documentation for more.
+///
+/// [`cyclic_windows`]: List::cyclic_windows
+pub struct CyclicWindows<'a, T: 'a> {
+    /// The first node of the next window, or the ghost node if there are
+    /// no more windows.
+    start: NonNull<Node<T>>,
+    /// The last node of the next window.
+    last: NonNull<Node<T>>,
+    /// Whether the next window wraps around the ghost node.
+    wrapped: bool,
+    ghost: NonNull<Node<T>>,
+    #[cfg(feature = "length")]
+    size: usize,
+    #[cfg(feature = "length")]
+    index: usize,
+    #[cfg(feature = "length")]
+    len: usize,
+    _marker: PhantomData<&'a List<T>>,
+}
+
+impl<'a, T: 'a> CyclicWindows<'a, T> {
+    pub(crate) fn new<A: RawAlloc>(list: &'a List<T, A>, size: usize) -> Self {
+        let mut rest = Iter::new(list);
+        let ghost = list.ghost_node();
+        let start = match rest.by_ref().take(size).count() {
+            taken if taken == size => list.front_node(),
+            _ => ghost,
+        };
+        Self {
+            start,
+            // SAFETY: `rest.start` is a valid node of the list.
+            last: unsafe { rest.start.as_ref().prev },
+            wrapped: false,
+            ghost,
+            #[cfg(feature = "length")]
+            size,
+            #[cfg(feature = "length")]
+            index: if start == ghost { list.len() } else { 0 },
+            #[cfg(feature = "length")]
+            len: list.len(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a> fmt::Debug for CyclicWindows<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_tuple("CyclicWindows");
+        // SAFETY: `start..ghost` is always a valid range of a list,
+        // so it is safe.
+        let mut ptr = self.start;
+        while ptr != self.ghost {
+            let current = unsafe { ptr.as_ref() };
+            f.field(&current.element);
+            ptr = current.next;
+        }
+        f.finish()
+    }
+}
+
+impl<'a, T: 'a> Iterator for CyclicWindows<'a, T> {
+    type Item = CyclicIter<'a, T>;
+
+    /// Return the window `start..=last` (which may wrap around the ghost node)
+    /// and reset the next window to `(start.next)..=(last.next)`, skipping the
+    /// ghost node, or return `None` if there are no more windows.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.ghost {
+            return None;
+        }
+        // SAFETY: `start` and `last` are valid non-ghost nodes of the list,
+        // and the window is split at the ghost node into at most two valid
+        // ranges of the list, so it is safe.
+        unsafe {
+            let end = self.last.as_ref().next;
+            #[cfg(feature = "length")]
+            let head_len = if self.wrapped {
+                self.len - self.index
+            } else {
+                self.size
+            };
+            let window = if self.wrapped {
+                CyclicIter::new(
+                    Iter::from_range(
+                        self.start,
+                        self.ghost,
+                        #[cfg(feature = "length")]
+                        head_len,
+                    ),
+                    Iter::from_range(
+                        self.ghost.as_ref().next,
+                        end,
+                        #[cfg(feature = "length")]
+                        (self.size - head_len),
+                    ),
+                )
+            } else {
+                CyclicIter::new(
+                    Iter::from_range(
+                        self.start,
+                        end,
+                        #[cfg(feature = "length")]
+                        head_len,
+                    ),
+                    Iter::from_range(
+                        self.ghost,
+                        self.ghost,
+                        #[cfg(feature = "length")]
+                        0,
+                    ),
+                )
+            };
+            self.start = self.start.as_ref().next;
+            self.last = end;
+            if self.last == self.ghost {
+                self.last = self.ghost.as_ref().next;
+                self.wrapped = true;
+            }
+            #[cfg(feature = "length")]
+            {
+                self.index += 1;
+            }
+            Some(window)
+        }
+    }
+
+    #[cfg(feature = "length")]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.index;
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "length")]
+impl<'a, T: 'a> ExactSizeIterator for CyclicWindows<'a, T> {}
 
-impl<T> FusedIterator for IntoIter<T> {}
+impl<'a, T: 'a> FusedIterator for CyclicWindows<'a, T> {}
 
-impl<T> IntoIterator for List<T> {
+impl<T, A: RawAlloc> IntoIterator for List<T, A> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter { list: self }
     }
 }
 
-impl<'a, T> IntoIterator for &'a List<T> {
+impl<'a, T, A: RawAlloc> IntoIterator for &'a List<T, A> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
 
@@ -315,15 +1023,17 @@ impl<'a, T> IntoIterator for &'a List<T> {
     }
 }
 
-impl<'a, T> IntoIterator for &'a mut List<T> {
+impl<'a, T, A: RawAlloc> IntoIterator for &'a mut List<T, A> {
     type Item = &'a mut T;
-    type IntoIter = IterMut<'a, T>;
+    type IntoIter = IterMut<'a, T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter_mut()
     }
 }
 
+/// Creates a list with the [`Global`] allocator.
+/// See [`List::from_iter_in`] for other allocators.
 impl<T> FromIterator<T> for List<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut list = List::new();
@@ -332,19 +1042,35 @@ impl<T> FromIterator<T> for List<T> {
     }
 }
 
-impl<T> Extend<T> for List<T> {
+// The nodes are linked into a detached chain before attaching them to the
+// list at once, which saves the bookkeeping of pushing them one by one.
+impl<T, A: RawAlloc> Extend<T> for List<T, A> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        iter.into_iter().for_each(|item| self.push_back(item));
+        if let Some(detached) = DetachedNodes::from_iter_in(iter.into_iter(), &self.alloc) {
+            // SAFETY: `self.ghost_node()` is a valid node in the list.
+            unsafe { self.attach_nodes(self.ghost_node(), detached) }
+        }
     }
 }
 
-impl<'a, T: 'a + Copy> Extend<&'a T> for List<T> {
+// Unlike `Vec`, this is not limited to `T: Copy`, since there is no bulk copy
+// of the elements to specialize for.
+impl<'a, T: 'a + Clone, A: RawAlloc> Extend<&'a T> for List<T, A> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
-        self.extend(iter.into_iter().copied())
+        self.extend(iter.into_iter().cloned())
+    }
+}
+
+/// Inserts the items before the current node in order, so the cursor stays
+/// at the same node, like [`CursorMut::insert_iter`]. The nodes are linked
+/// into a detached chain first, and attached to the list at once.
+impl<T, A: RawAlloc> Extend<T> for CursorMut<'_, T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.insert_iter(iter);
     }
 }
 
-impl<'a, T: 'a> Iterator for CursorIter<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> Iterator for CursorIter<'a, T, A> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -352,9 +1078,21 @@ impl<'a, T: 'a> Iterator for CursorIter<'a, T> {
         self.cursor.move_next_cyclic();
         current
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        #[cfg(feature = "length")]
+        {
+            let remaining = self.remaining();
+            (remaining, Some(remaining))
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            (usize::from(!self.cursor.is_ghost_node()), None)
+        }
+    }
 }
 
-impl<'a, T: 'a> Iterator for CursorIterMut<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> Iterator for CursorIterMut<'a, T, A> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -362,30 +1100,148 @@ impl<'a, T: 'a> Iterator for CursorIterMut<'a, T> {
         self.cursor.move_next_cyclic();
         current
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        #[cfg(feature = "length")]
+        {
+            let remaining = self.remaining();
+            (remaining, Some(remaining))
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            (usize::from(!self.cursor.is_ghost_node()), None)
+        }
+    }
 }
 
-impl<'a, T: 'a> Iterator for CursorBackIter<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> Iterator for CursorBackIter<'a, T, A> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.cursor.move_prev_cyclic();
         self.cursor.current()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        #[cfg(feature = "length")]
+        {
+            let remaining = self.remaining();
+            (remaining, Some(remaining))
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            (usize::from(!self.cursor.is_front_node()), None)
+        }
+    }
 }
 
-impl<'a, T: 'a> Iterator for CursorBackIterMut<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> Iterator for CursorBackIterMut<'a, T, A> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.cursor.move_prev_cyclic();
         self.cursor.current_mut()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        #[cfg(feature = "length")]
+        {
+            let remaining = self.remaining();
+            (remaining, Some(remaining))
+        }
+        #[cfg(not(feature = "length"))]
+        {
+            (usize::from(!self.cursor.is_front_node()), None)
+        }
+    }
+}
+
+/// An iterator that yields the elements of a cursor iterator for given full
+/// cycles (skipping the ghost node), and then fuses.
+///
+/// This `struct` is created by the `take_cycles` method on cursor iterators.
+/// See [`CursorIter::take_cycles`] for details.
+pub struct TakeCycles<I> {
+    iter: I,
+    /// The address of the first element (or `None` if starting at the ghost
+    /// node), which is recorded when the iteration starts.
+    start: Option<Option<usize>>,
+    cycles: usize,
+    /// Whether no element has been yielded in the current cycle.
+    empty_cycle: bool,
+}
+
+impl<I> TakeCycles<I> {
+    pub(crate) fn new(iter: I, cycles: usize) -> Self {
+        Self {
+            iter,
+            start: None,
+            cycles,
+            empty_cycle: true,
+        }
+    }
+}
+
+impl<I, R> Iterator for TakeCycles<I>
+where
+    I: Iterator<Item = R>,
+    R: Deref,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.cycles > 0 {
+            let item = self.iter.next();
+            let address = item
+                .as_deref()
+                .map(|element| (element as *const R::Target).cast::<()>() as usize);
+            match self.start {
+                None => self.start = Some(address),
+                // Returning to the start position completes a cycle.
+                Some(start) if start == address => {
+                    // A cycle without any element means the list is empty,
+                    // so the remaining cycles are empty as well.
+                    if self.empty_cycle {
+                        self.cycles = 0;
+                        return None;
+                    }
+                    self.empty_cycle = true;
+                    self.cycles -= 1;
+                    if self.cycles == 0 {
+                        return None;
+                    }
+                }
+                _ => {}
+            }
+            if item.is_some() {
+                self.empty_cycle = false;
+                return item;
+            }
+        }
+        None
+    }
+}
+
+impl<I, R> FusedIterator for TakeCycles<I>
+where
+    I: Iterator<Item = R>,
+    R: Deref,
+{
+}
+
+impl<I: fmt::Debug> fmt::Debug for TakeCycles<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeCycles")
+            .field("iter", &self.iter)
+            .field("cycles", &self.cycles)
+            .finish()
+    }
 }
 
 /// Convert the cursor to an iterator, which is cyclic and not fused.
-impl<'a, T: 'a> IntoIterator for Cursor<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> IntoIterator for Cursor<'a, T, A> {
     type Item = &'a T;
-    type IntoIter = CursorIter<'a, T>;
+    type IntoIter = CursorIter<'a, T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         CursorIter { cursor: self }
@@ -394,9 +1250,9 @@ impl<'a, T: 'a> IntoIterator for Cursor<'a, T> {
 
 /// Convert the cursor to an mutable iterator, which is cyclic
 /// and not fused.
-impl<'a, T: 'a> IntoIterator for CursorMut<'a, T> {
+impl<'a, T: 'a, A: RawAlloc> IntoIterator for CursorMut<'a, T, A> {
     type Item = &'a mut T;
-    type IntoIter = CursorIterMut<'a, T>;
+    type IntoIter = CursorIterMut<'a, T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         CursorIterMut { cursor: self }
@@ -407,9 +1263,9 @@ unsafe impl<T: Sync> Send for Iter<'_, T> {}
 
 unsafe impl<T: Sync> Sync for Iter<'_, T> {}
 
-unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Send, A: RawAlloc + Send> Send for IterMut<'_, T, A> {}
 
-unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for IterMut<'_, T, A> {}
 
 #[cfg(test)]
 mod tests {
@@ -494,4 +1350,189 @@ mod tests {
         test_case(0..1, 0);
         test_case(0..0, 0);
     }
+
+    #[test]
+    fn test_chunks() {
+        fn test_chunks(len: usize, size: usize) {
+            let vec = Vec::from_iter(0..len);
+            let list = List::from_iter(0..len);
+            let chunks = list.chunks(size);
+            #[cfg(feature = "length")]
+            assert_eq!(chunks.len(), vec.chunks(size).len());
+            for (chunk, expected) in chunks.zip(vec.chunks(size)) {
+                #[cfg(feature = "length")]
+                assert_eq!(chunk.len(), expected.len());
+                assert!(chunk.clone().eq(expected));
+                assert!(chunk.rev().eq(expected.iter().rev()));
+            }
+            assert!(list
+                .chunks(size)
+                .map(|c| Vec::from_iter(c.copied()))
+                .eq(vec.chunks(size)));
+
+            let into_chunks = list.into_chunks(size);
+            #[cfg(feature = "length")]
+            assert_eq!(into_chunks.len(), vec.chunks(size).len());
+            for (chunk, expected) in into_chunks.zip(vec.chunks(size)) {
+                #[cfg(feature = "length")]
+                assert_eq!(chunk.len(), expected.len());
+                assert!(chunk.iter().rev().eq(expected.iter().rev()));
+            }
+        }
+        for (len, size) in [
+            (0, 1),
+            (1, 1),
+            (5, 1),
+            (5, 2),
+            (6, 2),
+            (6, 3),
+            (3, 5),
+            (10, 10),
+        ] {
+            test_chunks(len, size);
+        }
+    }
+
+    #[test]
+    fn test_windows() {
+        fn test_windows(len: usize, size: usize) {
+            let vec = Vec::from_iter(0..len);
+            let list = List::from_iter(0..len);
+            let windows = list.windows(size);
+            #[cfg(feature = "length")]
+            assert_eq!(windows.len(), vec.windows(size).len());
+            for (window, expected) in windows.zip(vec.windows(size)) {
+                #[cfg(feature = "length")]
+                assert_eq!(window.len(), expected.len());
+                assert!(window.clone().eq(expected));
+                assert!(window.rev().eq(expected.iter().rev()));
+            }
+            assert!(list
+                .windows(size)
+                .map(|w| Vec::from_iter(w.copied()))
+                .eq(vec.windows(size)));
+
+            let windows2 = list.windows2();
+            #[cfg(feature = "length")]
+            assert_eq!(windows2.len(), vec.windows(2).len());
+            assert!(windows2.map(|(a, b)| [*a, *b]).eq(vec.windows(2)));
+        }
+        for (len, size) in [
+            (0, 1),
+            (1, 1),
+            (1, 2),
+            (5, 1),
+            (5, 2),
+            (5, 5),
+            (5, 6),
+            (6, 3),
+        ] {
+            test_windows(len, size);
+        }
+    }
+
+    #[test]
+    fn test_cyclic_windows() {
+        fn test_cyclic_windows(len: usize, size: usize) {
+            let list = List::from_iter(0..len);
+            let expected = Vec::from_iter(
+                (0..len)
+                    .filter(|_| size <= len)
+                    .map(|i| Vec::from_iter((i..i + size).map(|j| j % len))),
+            );
+            let windows = list.cyclic_windows(size);
+            #[cfg(feature = "length")]
+            assert_eq!(windows.len(), expected.len());
+            for (window, expected) in windows.zip(&expected) {
+                #[cfg(feature = "length")]
+                assert_eq!(window.size_hint(), (size, Some(size)));
+                assert!(window.clone().eq(expected));
+                assert!(window.rev().eq(expected.iter().rev()));
+            }
+            assert_eq!(list.cyclic_windows(size).count(), expected.len());
+        }
+        for (len, size) in [
+            (0, 1),
+            (1, 1),
+            (1, 2),
+            (2, 2),
+            (5, 1),
+            (5, 2),
+            (5, 4),
+            (5, 5),
+            (5, 6),
+        ] {
+            test_cyclic_windows(len, size);
+        }
+    }
+
+    #[test]
+    fn test_iter_remaining() {
+        let list = List::from_iter(0..6);
+        let mut iter = list.iter();
+        for step in 0..=6 {
+            let remaining = iter.remaining();
+            let expected = if step % 2 == 0 {
+                step / 2..6 - step / 2
+            } else {
+                step / 2 + 1..6 - step / 2
+            };
+            #[cfg(feature = "length")]
+            assert_eq!(remaining.len(), expected.len());
+            assert_eq!(remaining.is_empty(), expected.is_empty());
+            assert_eq!(remaining.front(), expected.clone().next().as_ref());
+            assert_eq!(remaining.back(), expected.clone().next_back().as_ref());
+            assert!(remaining.iter().eq(iter.clone()));
+            assert!(remaining.iter().rev().copied().eq(expected.rev()));
+            if step % 2 == 0 {
+                iter.next();
+            } else {
+                iter.next_back();
+            }
+        }
+    }
+
+    #[test]
+    fn test_extend_ref() {
+        let words = ["a".to_string(), "b".to_string()];
+        let mut list = List::from_iter(["x".to_string()]);
+        list.extend(&words);
+        list.extend(words.iter().rev());
+        assert_eq!(list.into_vec(), vec!["x", "a", "b", "b", "a"]);
+
+        let list = List::from_cloned(&words);
+        assert_eq!(list.into_vec(), words);
+        let list = List::from_cloned([1, 2, 3].iter());
+        assert!(list.iter().rev().eq(&[3, 2, 1]));
+
+        // Collecting references infers a list of references.
+        let refs: List<_> = words.iter().collect();
+        assert_eq!(refs.front(), Some(&&words[0]));
+    }
+
+    #[test]
+    fn test_extend_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut list = List::from_iter([Rc::clone(&rc)]);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.extend((0..5).map(|i| {
+                assert!(i < 3, "iterator panics");
+                Rc::clone(&rc)
+            }))
+        }));
+        assert!(result.is_err());
+        // The list is left unchanged, and the extended elements are dropped.
+        assert_eq!(list.iter().count(), 1);
+        #[cfg(feature = "length")]
+        assert_eq!(list.len(), 1);
+        assert_eq!(Rc::strong_count(&rc), 2);
+
+        list.extend_front((0..3).map(|_| Rc::clone(&rc)));
+        list.extend((0..3).map(|_| Rc::clone(&rc)));
+        assert_eq!(list.iter().rev().count(), 7);
+        assert_eq!(Rc::strong_count(&rc), 8);
+    }
 }
@@ -1,16 +1,32 @@
-use std::fmt::{Debug, Formatter};
+use std::alloc::Layout;
+use std::collections::{LinkedList, VecDeque};
+use std::convert::Infallible;
+use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
-use std::mem::MaybeUninit;
+use std::ops::{Bound, RangeBounds};
 use std::ptr::NonNull;
+#[cfg(any(debug_assertions, feature = "paranoid"))]
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-use crate::list::cursor::{Cursor, CursorMut};
+use crate::list::alloc::{Global, RawAlloc};
+use crate::list::cursor::{Cursor, CursorMut, CursorPairMut, NodeHandle};
+use crate::list::iterator::{
+    Chunks, CyclicIter, CyclicWindows, IntoChunks, IterWithCursors, Windows, Windows2,
+};
+use crate::list::view::{ListView, ListViewMut};
 use crate::{IntoIter, Iter, IterMut};
-use std::iter::FromIterator;
+use std::iter::{Cycle, FromIterator};
 
+pub mod alloc;
 pub mod cursor;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
 pub mod iterator;
+pub mod view;
 
 mod algorithms;
+#[cfg(feature = "rkyv")]
+mod archive;
 
 /// The `List` is a doubly-linked list with owned nodes, implemented as a cyclic list.
 ///
@@ -31,11 +47,13 @@ mod algorithms;
 /// - `front..=back`: a closed range of list nodes, both inclusive;
 /// - `start..end`: a half-open range of list nodes, left inclusive and right
 ///   exclusive (probably the ghost node).
-pub struct List<T> {
-    ghost: Box<Node<Erased>>,
+pub struct List<T, A: RawAlloc = Global> {
+    ghost: NonNull<Node<Erased>>,
     #[cfg(feature = "length")]
     /// the length of the list
     pub(crate) len: usize,
+    /// the allocator of the nodes, including the ghost node
+    alloc: A,
     _marker: PhantomData<Box<Node<T>>>,
 }
 
@@ -43,10 +61,14 @@ pub struct List<T> {
 pub(crate) struct Node<T> {
     pub(crate) next: NonNull<Node<T>>,
     pub(crate) prev: NonNull<Node<T>>,
+    /// The generation of the node, which is unique among all the allocated nodes,
+    /// to detect stale positions referring to a removed node, whose memory might
+    /// be reused by another node.
+    #[cfg(any(debug_assertions, feature = "paranoid"))]
+    pub(crate) generation: usize,
     pub(crate) element: T,
 }
 
-#[derive(Default)]
 struct Erased;
 
 /// Nodes fragment detached from a list, used in list splitting or
@@ -63,9 +85,9 @@ pub(crate) struct DetachedNodes<T> {
 }
 
 // private methods
-impl<T> List<T> {
+impl<T, A: RawAlloc> List<T, A> {
     pub(crate) fn ghost_node(&self) -> NonNull<Node<T>> {
-        NonNull::from(self.ghost.as_ref()).cast()
+        self.ghost.cast()
     }
     pub(crate) fn front_node(&self) -> NonNull<Node<T>> {
         // SAFETY: `ghost.next` is always valid (either `ghost` itself, or the first element
@@ -78,22 +100,47 @@ impl<T> List<T> {
         NonNull::from(unsafe { self.ghost_node().as_ref().prev.as_ref() }).cast()
     }
 
-    /// Detach a single node `node` from the list, and return it as a box.
+    /// Detach a single node `node` from the list, and return it.
     ///
     /// It is unsafe because it does not check whether `node` belongs to the list.
     ///
     /// If the `node` does not belong to the list, this function call will make
     /// the list ill-formed.
-    pub(crate) unsafe fn detach_node(&mut self, node: NonNull<Node<T>>) -> Box<Node<T>> {
+    pub(crate) unsafe fn detach_node(&mut self, node: NonNull<Node<T>>) -> NonNull<Node<T>> {
+        self.check_owned_element(node);
+        check_linked(node);
         #[cfg(feature = "length")]
         {
             self.len -= 1;
         }
-        let node = Box::from_raw(node.as_ptr());
-        connect(node.prev, node.next);
+        connect(node.as_ref().prev, node.as_ref().next);
         node
     }
 
+    /// Detach a single node `node` from the list, free it, and return its element.
+    ///
+    /// It is unsafe for the same reason as [`List::detach_node`].
+    pub(crate) unsafe fn remove_node(&mut self, node: NonNull<Node<T>>) -> T {
+        let node = self.detach_node(node);
+        self.free_node(node)
+    }
+
+    /// Allocate a detached node with given element by the allocator of the list.
+    pub(crate) fn new_node(&self, element: T) -> NonNull<Node<T>> {
+        Node::new_detached_in(element, &self.alloc)
+    }
+
+    /// Move the element out of a detached node, and free the node by the
+    /// allocator of the list.
+    ///
+    /// It is unsafe because `node` must be a detached node holding a valid
+    /// element, and allocated by an allocator of type `A`.
+    pub(crate) unsafe fn free_node(&self, node: NonNull<Node<T>>) -> T {
+        let element = std::ptr::read(std::ptr::addr_of!((*node.as_ptr()).element));
+        Node::dealloc_in(node, &self.alloc);
+        element
+    }
+
     /// Attach a single node `node` to the list, before `next`.
     ///
     /// It is unsafe because it does not check whether `next` belongs
@@ -102,6 +149,8 @@ impl<T> List<T> {
     /// If `next` does not belong to the list, this function call
     /// will make the list ill-formed.
     pub(crate) unsafe fn attach_node(&mut self, next: NonNull<Node<T>>, node: NonNull<Node<T>>) {
+        self.check_owned(next);
+        check_linked(next);
         connect(next.as_ref().prev, node);
         connect(node, next);
         #[cfg(feature = "length")]
@@ -125,6 +174,14 @@ impl<T> List<T> {
         back: NonNull<Node<T>>,
         #[cfg(feature = "length")] len: usize,
     ) -> DetachedNodes<T> {
+        self.check_owned_range(
+            front,
+            back,
+            #[cfg(feature = "length")]
+            len,
+        );
+        check_linked(front);
+        check_linked(back);
         #[cfg(feature = "length")]
         {
             self.len -= len;
@@ -151,6 +208,9 @@ impl<T> List<T> {
         next: NonNull<Node<T>>,
         detached: DetachedNodes<T>,
     ) {
+        self.check_owned(next);
+        check_linked(next);
+        detached.check_range();
         connect(next.as_ref().prev, detached.front);
         connect(detached.back, next);
         #[cfg(feature = "length")]
@@ -181,18 +241,374 @@ impl<T> List<T> {
     ///
     /// It is safe because the detached nodes is guaranteed to be a valid range
     /// when construction.
-    pub(crate) fn from_detached(detached: DetachedNodes<T>) -> Self {
-        let mut list = List::new();
+    pub(crate) fn from_detached_in(detached: DetachedNodes<T>, alloc: A) -> Self {
+        let mut list = List::new_in(alloc);
         unsafe {
             list.attach_nodes(list.ghost_node(), detached);
         }
         list
     }
 
+    /// Drop the elements of detached nodes and free the nodes, which are
+    /// allocated by the allocator of this list.
+    pub(crate) fn drop_detached(&self, detached: DetachedNodes<T>) {
+        /// Owns the detached nodes `next..=back`, and frees the rest of them
+        /// even if dropping an element panics.
+        struct Guard<'a, T, A: RawAlloc> {
+            next: Option<NonNull<Node<T>>>,
+            back: NonNull<Node<T>>,
+            list: &'a List<T, A>,
+        }
+
+        impl<T, A: RawAlloc> Guard<'_, T, A> {
+            /// Drop the element of the next node and free the node, or return
+            /// `false` if all the nodes are freed.
+            fn drop_next(&mut self) -> bool {
+                match self.next {
+                    // SAFETY: `node..=back` are valid detached nodes linked
+                    // by `next`, which are allocated by the list's allocator.
+                    Some(node) => unsafe {
+                        // `back.next` may be uninitialized and must not be read.
+                        self.next = if node == self.back {
+                            None
+                        } else {
+                            Some(node.as_ref().next)
+                        };
+                        drop(self.list.free_node(node));
+                        true
+                    },
+                    None => false,
+                }
+            }
+        }
+
+        impl<T, A: RawAlloc> Drop for Guard<'_, T, A> {
+            fn drop(&mut self) {
+                while self.drop_next() {}
+            }
+        }
+
+        let mut guard = Guard {
+            next: Some(detached.front),
+            back: detached.back,
+            list: self,
+        };
+        while guard.drop_next() {}
+        std::mem::forget(guard);
+    }
+
+    /// Panics if the nodes of `other` cannot be moved into this list, since
+    /// they are allocated by an allocator not equal to that of this list.
+    pub(crate) fn check_same_alloc(&self, other: &Self) {
+        assert!(
+            self.alloc == other.alloc,
+            "Cannot move nodes between lists with different allocators"
+        );
+    }
+
     /// Like [`List::detach_all_nodes`], but consume the list.
     pub(crate) fn into_detached(mut self) -> Option<DetachedNodes<T>> {
         self.detach_all_nodes()
     }
+
+    /// Locate the range `start..end` (or `start..` if `end` is `None`), and
+    /// return the first node in the range and the node right after the range
+    /// (possibly the ghost node). They are the same node if the range is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end`, or `end > len`.
+    pub(crate) fn range_nodes(
+        &self,
+        start: usize,
+        end: Option<usize>,
+    ) -> (NonNull<Node<T>>, NonNull<Node<T>>) {
+        let start_cursor = self.cursor(start);
+        let start_node = start_cursor.current;
+        let end_node = match end {
+            Some(end) => {
+                let mut end_cursor = start_cursor;
+                end_cursor
+                    .try_seek_to(end)
+                    .expect("Range end is out of range of the list");
+                end_cursor.current
+            }
+            None => self.ghost_node(),
+        };
+        (start_node, end_node)
+    }
+
+    /// Detach the nodes in the range `start..end` and return them as a new list,
+    /// where `len` is the length of the range.
+    ///
+    /// It is unsafe because it does not check whether `start..end` is a valid range
+    /// of the list, or whether its length is `len` (with `feature = "length"`).
+    pub(crate) unsafe fn detach_range(
+        &mut self,
+        start: NonNull<Node<T>>,
+        end: NonNull<Node<T>>,
+        #[cfg(feature = "length")] len: usize,
+    ) -> List<T, A>
+    where
+        A: Clone,
+    {
+        if start == end {
+            return List::new_in(self.alloc.clone());
+        }
+        let detached = self.detach_nodes(
+            start,
+            end.as_ref().prev,
+            #[cfg(feature = "length")]
+            len,
+        );
+        List::from_detached_in(detached, self.alloc.clone())
+    }
+
+    /// Detach the nodes in the range `start..end` (or `start..` if `end` is
+    /// `None`) and return them as a new list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end`, or `end > len`.
+    pub(crate) fn split_range(&mut self, start: usize, end: Option<usize>) -> List<T, A>
+    where
+        A: Clone,
+    {
+        let (start_node, end_node) = self.range_nodes(start, end);
+        // SAFETY: `start_node..end_node` is a valid range in the list, and its
+        // length is `end - start`.
+        unsafe {
+            self.detach_range(
+                start_node,
+                end_node,
+                #[cfg(feature = "length")]
+                (end.unwrap_or(self.len) - start),
+            )
+        }
+    }
+
+    /// Move the ghost node to the position before `front`, so that `front`
+    /// becomes the first node of the list, while the cyclic order of the
+    /// elements is unchanged.
+    ///
+    /// It is unsafe because it does not check whether `front` belongs to
+    /// the list.
+    ///
+    /// If `front` does not belong to the list, this function call will make
+    /// the list ill-formed.
+    pub(crate) unsafe fn rebase_ghost(&mut self, front: NonNull<Node<T>>) {
+        let ghost = self.ghost_node();
+        if front == ghost || front == self.front_node() {
+            return;
+        }
+        self.check_owned(front);
+        // Close the ring without the ghost node, then reopen it before `front`.
+        connect(self.back_node(), self.front_node());
+        connect(front.as_ref().prev, ghost);
+        connect(ghost, front);
+    }
+
+    /// Return the node referred to by `handle`, checking it with
+    /// `debug_assertions` or `feature = "paranoid"`.
+    ///
+    /// The check walks through the list instead of reading the node of the
+    /// handle, which might have been freed.
+    ///
+    /// It is unsafe because the node must belong to the list.
+    unsafe fn handle_node(&self, handle: &NodeHandle<T>) -> NonNull<Node<T>> {
+        #[cfg(any(debug_assertions, feature = "paranoid"))]
+        assert!(
+            self.contains_handle(handle),
+            "Cannot locate a handle whose element is not in the list"
+        );
+        handle.node()
+    }
+
+    /// Return the index of `node`, by walking to the ghost node from both
+    /// directions alternately.
+    ///
+    /// It is unsafe because `node` must belong to the list.
+    #[cfg(feature = "length")]
+    unsafe fn index_of_node(&self, node: NonNull<Node<T>>) -> usize {
+        let ghost = self.ghost_node();
+        let (mut forward, mut backward) = (node, node);
+        let mut steps = 0;
+        loop {
+            if forward == ghost {
+                return self.len - steps;
+            }
+            if backward == ghost {
+                return steps - 1;
+            }
+            forward = forward.as_ref().next;
+            backward = backward.as_ref().prev;
+            steps += 1;
+        }
+    }
+
+    /// Returns an iterator over all the non-ghost nodes of the list.
+    pub(crate) fn nodes(&self) -> impl Iterator<Item = NonNull<Node<T>>> + '_ {
+        let ghost = self.ghost_node();
+        // SAFETY: each node before the ghost node is a valid non-ghost node
+        // in the list.
+        std::iter::successors(Some(self.front_node()), move |node| {
+            Some(unsafe { node.as_ref().next })
+        })
+        .take_while(move |&node| node != ghost)
+    }
+
+    /// Relink all the non-ghost nodes of the list in the order of `nodes`.
+    ///
+    /// It is unsafe because it does not check whether `nodes` is a permutation
+    /// of all the non-ghost nodes in the list.
+    ///
+    /// If `nodes` is not such a permutation, this function call will make the
+    /// list ill-formed.
+    pub(crate) unsafe fn relink_nodes<I>(&mut self, nodes: I)
+    where
+        I: IntoIterator<Item = NonNull<Node<T>>>,
+    {
+        let ghost = self.ghost_node();
+        let prev = nodes.into_iter().fold(ghost, |prev, node| {
+            connect(prev, node);
+            node
+        });
+        connect(prev, ghost);
+    }
+
+    /// Resolve a range of indices into `(start, end)`, where `end` is `None` if
+    /// it is unbounded and the length of the list is unknown (without
+    /// `feature = "length"`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end`, or `end > len` (with `feature = "length"`).
+    pub(crate) fn resolve_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, Option<usize>) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start
+                .checked_add(1)
+                .expect("Cannot start a range after `usize::MAX`"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Some(
+                end.checked_add(1)
+                    .expect("Cannot end a range after `usize::MAX`"),
+            ),
+            Bound::Excluded(&end) => Some(end),
+            #[cfg(feature = "length")]
+            Bound::Unbounded => Some(self.len),
+            #[cfg(not(feature = "length"))]
+            Bound::Unbounded => None,
+        };
+        if let Some(end) = end {
+            assert!(
+                start <= end,
+                "Range starts at {} but ends at {}",
+                start,
+                end
+            );
+            #[cfg(feature = "length")]
+            assert!(
+                end <= self.len,
+                "Range end {} is out of range for list of length {}",
+                end,
+                self.len
+            );
+        }
+        (start, end)
+    }
+}
+
+/// Runtime validations of the linking structure.
+///
+/// Adjacency checks (i.e. `node.prev.next == node` and `node.next.prev == node`)
+/// are *O*(1) and run with `debug_assertions` or `feature = "paranoid"`.
+///
+/// Ownership checks (i.e. whether a node belongs to the list) walk through the
+/// list, so they take *O*(*n*) time and only run with `feature = "paranoid"`.
+impl<T, A: RawAlloc> List<T, A> {
+    /// Returns `true` if `node` is a node of the list, including the ghost node.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    #[cfg(feature = "paranoid")]
+    pub(crate) fn owns_node(&self, node: NonNull<Node<T>>) -> bool {
+        let ghost = self.ghost_node();
+        let mut current = ghost;
+        loop {
+            if current == node {
+                return true;
+            }
+            // SAFETY: the nodes reachable from the ghost node are always valid.
+            current = unsafe { current.as_ref().next };
+            if current == ghost {
+                return false;
+            }
+        }
+    }
+
+    /// Panics if `node` does not belong to the list, with `feature = "paranoid"`.
+    #[inline]
+    #[cfg_attr(not(feature = "paranoid"), allow(unused_variables))]
+    pub(crate) fn check_owned(&self, node: NonNull<Node<T>>) {
+        #[cfg(feature = "paranoid")]
+        assert!(
+            self.owns_node(node),
+            "Node {:p} does not belong to the list",
+            node
+        );
+    }
+
+    /// Panics if `node` is the ghost node or it does not belong to the list,
+    /// with `feature = "paranoid"`.
+    #[inline]
+    #[cfg_attr(not(feature = "paranoid"), allow(unused_variables))]
+    pub(crate) fn check_owned_element(&self, node: NonNull<Node<T>>) {
+        #[cfg(feature = "paranoid")]
+        {
+            assert!(node != self.ghost_node(), "Cannot detach the ghost node");
+            self.check_owned(node);
+        }
+    }
+
+    /// Panics if `front..=back` is not a valid range of the list (or its length is
+    /// not `len`), with `feature = "paranoid"`.
+    #[inline]
+    #[cfg_attr(not(feature = "paranoid"), allow(unused_variables))]
+    pub(crate) fn check_owned_range(
+        &self,
+        front: NonNull<Node<T>>,
+        back: NonNull<Node<T>>,
+        #[cfg(feature = "length")] len: usize,
+    ) {
+        #[cfg(feature = "paranoid")]
+        {
+            self.check_owned_element(front);
+            let ghost = self.ghost_node();
+            let mut current = front;
+            #[cfg(feature = "length")]
+            let mut count = 1_usize;
+            while current != back {
+                // SAFETY: `current` is a non-ghost node of the list.
+                current = unsafe { current.as_ref().next };
+                assert!(
+                    current != ghost,
+                    "Node {:p} is not reachable from node {:p}",
+                    back,
+                    front
+                );
+                #[cfg(feature = "length")]
+                {
+                    count += 1;
+                }
+            }
+            #[cfg(feature = "length")]
+            assert_eq!(count, len, "Length mismatches the range of nodes");
+        }
+    }
 }
 
 impl<T> List<T> {
@@ -205,7 +621,136 @@ impl<T> List<T> {
     /// ```
     #[inline]
     pub fn new() -> Self {
-        let ghost = new_ghost();
+        Self::new_in(Global)
+    }
+
+    /// Creates a `List` of `n` clones of `value`.
+    ///
+    /// The value itself is moved into the last node, so only `n - 1` clones
+    /// are made, like [`vec!`]`[value; n]`.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::repeat("abc".to_string(), 3);
+    /// assert_eq!(list.into_vec(), vec!["abc", "abc", "abc"]);
+    ///
+    /// assert!(List::repeat(0, 0).is_empty());
+    /// ```
+    pub fn repeat(value: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut list = Self::new();
+        if n > 0 {
+            for _ in 1..n {
+                list.push_back(value.clone());
+            }
+            list.push_back(value);
+        }
+        list
+    }
+
+    /// Creates a `List` of `n` elements, each produced by calling `f`.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut next = 1;
+    /// let list = List::repeat_with(4, || {
+    ///     next *= 2;
+    ///     next
+    /// });
+    /// assert_eq!(list.into_vec(), vec![2, 4, 8, 16]);
+    /// ```
+    pub fn repeat_with<F>(n: usize, mut f: F) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        let mut list = Self::new();
+        for _ in 0..n {
+            list.push_back(f());
+        }
+        list
+    }
+
+    /// Creates a `List` of `n` elements, where each element is produced by
+    /// calling `f` with its index, like [`std::array::from_fn`].
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from_fn(5, |i| i * i);
+    /// assert_eq!(list.into_vec(), vec![0, 1, 4, 9, 16]);
+    /// ```
+    pub fn from_fn<F>(n: usize, f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self::from_iter((0..n).map(f))
+    }
+
+    /// Creates a `List` of clones of the elements referred to by `iter`.
+    ///
+    /// This is the same as `iter.into_iter().cloned().collect()`, without
+    /// naming the element type for inference.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let words = ["a".to_string(), "b".to_string()];
+    /// let list = List::from_cloned(&words);
+    /// assert_eq!(list.into_vec(), words);
+    /// ```
+    pub fn from_cloned<'a, I>(iter: I) -> Self
+    where
+        T: 'a + Clone,
+        I: IntoIterator<Item = &'a T>,
+    {
+        Self::from_iter(iter.into_iter().cloned())
+    }
+}
+
+impl<T, A: RawAlloc> List<T, A> {
+    /// Create an empty `List` whose nodes are allocated by `alloc`.
+    ///
+    /// See the [`alloc`] module for more.
+    ///
+    /// # Examples
+    /// ```
+    /// use cyclic_list::list::alloc::Global;
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::new_in(Global);
+    /// list.push_back(1);
+    /// assert_eq!(list.into_vec(), vec![1]);
+    /// ```
+    pub fn new_in(alloc: A) -> Self {
+        let ghost = new_ghost_in(&alloc);
         #[cfg(feature = "length")]
         let len = 0;
         let _marker = PhantomData;
@@ -213,10 +758,41 @@ impl<T> List<T> {
             ghost,
             #[cfg(feature = "length")]
             len,
+            alloc,
             _marker,
         }
     }
 
+    /// Creates a `List` from the items of `iter`, whose nodes are allocated
+    /// by `alloc`.
+    ///
+    /// The [`FromIterator`] implementation creates lists with the
+    /// [`Global`] allocator only.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    /// ```
+    /// use cyclic_list::list::alloc::Global;
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from_iter_in(1..4, Global);
+    /// assert_eq!(list.into_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn from_iter_in<I: IntoIterator<Item = T>>(iter: I, alloc: A) -> Self {
+        let mut list = Self::new_in(alloc);
+        list.extend(iter);
+        list
+    }
+
+    /// Returns a reference to the allocator of the list.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
     /// Returns `true` if the `List` is empty.
     ///
     /// # Complexity
@@ -297,27 +873,109 @@ impl<T> List<T> {
         while self.pop_front().is_some() {}
     }
 
-    /// Provides a reference to the front element, or `None` if the list is
-    /// empty.
+    /// Shortens the list, keeping the first `len` elements and dropping
+    /// the rest.
+    ///
+    /// If `len` is greater than the list's current length, this has no
+    /// effect.
+    ///
+    /// The dropped nodes are detached from the list at once before being
+    /// dropped.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
     ///
-    /// let mut list = List::new();
-    /// assert_eq!(list.front(), None);
+    /// let mut list = List::from_iter([1, 2, 3, 4, 5]);
     ///
-    /// list.push_front(1);
-    /// assert_eq!(list.front(), Some(&1));
+    /// list.truncate(2);
+    /// assert_eq!(list.to_vec(), vec![1, 2]);
+    ///
+    /// list.truncate(5);
+    /// assert_eq!(list.to_vec(), vec![1, 2]);
     /// ```
-    #[inline]
-    pub fn front(&self) -> Option<&T> {
-        self.cursor_start().current()
+    pub fn truncate(&mut self, len: usize) {
+        let mut cursor = self.cursor_start_mut();
+        if cursor.try_seek_to(len).is_ok() {
+            if let Some(detached) = cursor.split_nodes() {
+                cursor.list.drop_detached(detached);
+            }
+        }
     }
 
-    /// Provides a mutable reference to the front element, or `None` if the list
-    /// is empty.
+    /// Shortens the list, keeping the last `len` elements and dropping
+    /// the rest.
+    ///
+    /// If `len` is greater than the list's current length, this has no
+    /// effect.
+    ///
+    /// The dropped nodes are detached from the list at once before being
+    /// dropped.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4, 5]);
+    ///
+    /// list.truncate_front(2);
+    /// assert_eq!(list.to_vec(), vec![4, 5]);
+    ///
+    /// list.truncate_front(5);
+    /// assert_eq!(list.to_vec(), vec![4, 5]);
+    /// ```
+    pub fn truncate_front(&mut self, len: usize) {
+        #[cfg(feature = "length")]
+        let mut cursor = match self.len.checked_sub(len) {
+            Some(at) => self.cursor_mut(at),
+            None => return,
+        };
+        #[cfg(not(feature = "length"))]
+        let mut cursor = {
+            let mut cursor = self.cursor_end_mut();
+            if cursor.seek_backward(len).is_err() {
+                return;
+            }
+            cursor
+        };
+        if let Some(detached) = cursor.split_before_nodes() {
+            cursor.list.drop_detached(detached);
+        }
+    }
+
+    /// Provides a reference to the front element, or `None` if the list is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::new();
+    /// assert_eq!(list.front(), None);
+    ///
+    /// list.push_front(1);
+    /// assert_eq!(list.front(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.cursor_start().current()
+    }
+
+    /// Provides a mutable reference to the front element, or `None` if the list
+    /// is empty.
     ///
     /// # Examples
     ///
@@ -383,6 +1041,44 @@ impl<T> List<T> {
         self.cursor_end_mut().previous_mut()
     }
 
+    /// Provides mutable references to both the front and the back elements at
+    /// once, or `None` if the list has less than two elements.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3]);
+    /// if let Some((front, back)) = list.ends_mut() {
+    ///     std::mem::swap(front, back);
+    /// }
+    /// assert_eq!(list.to_vec(), vec![3, 2, 1]);
+    ///
+    /// let mut list = List::from_iter([1]);
+    /// assert!(list.ends_mut().is_none());
+    /// ```
+    pub fn ends_mut(&mut self) -> Option<(&mut T, &mut T)> {
+        let (front, back) = (self.front_node(), self.back_node());
+        if front == back {
+            // Either both are the ghost node, or the only element.
+            return None;
+        }
+        // SAFETY: `front` and `back` are different non-ghost nodes, so their
+        // elements are valid and do not alias.
+        unsafe {
+            Some((
+                &mut (*front.as_ptr()).element,
+                &mut (*back.as_ptr()).element,
+            ))
+        }
+    }
+
     /// Adds an element first in the list.
     ///
     /// # Complexity
@@ -479,6 +1175,94 @@ impl<T> List<T> {
         self.cursor_end_mut().backspace()
     }
 
+    /// Removes up to `n` elements from the front of the list, and returns them
+    /// as a new list in the same order.
+    ///
+    /// The nodes are detached at once, which is much cheaper than `n` calls of
+    /// [`pop_front`](List::pop_front).
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(min(*n*, *len*)) time for seeking,
+    /// and *O*(1) time for detaching.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(1..=5);
+    /// assert_eq!(list.pop_front_n(2).to_vec(), vec![1, 2]);
+    /// assert_eq!(list.pop_front_n(5).to_vec(), vec![3, 4, 5]);
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn pop_front_n(&mut self, n: usize) -> List<T, A>
+    where
+        A: Clone,
+    {
+        let (start, ghost) = (self.front_node(), self.ghost_node());
+        let (mut end, mut count) = (start, 0);
+        while count < n && end != ghost {
+            // SAFETY: `end` is a valid node in the list.
+            end = unsafe { end.as_ref().next };
+            count += 1;
+        }
+        // SAFETY: `start..end` is a valid range of `count` nodes in the list.
+        unsafe {
+            self.detach_range(
+                start,
+                end,
+                #[cfg(feature = "length")]
+                count,
+            )
+        }
+    }
+
+    /// Removes up to `n` elements from the back of the list, and returns them
+    /// as a new list in the same order.
+    ///
+    /// The nodes are detached at once, which is much cheaper than `n` calls of
+    /// [`pop_back`](List::pop_back).
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(min(*n*, *len*)) time for seeking,
+    /// and *O*(1) time for detaching.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(1..=5);
+    /// assert_eq!(list.pop_back_n(2).to_vec(), vec![4, 5]);
+    /// assert_eq!(list.pop_back_n(5).to_vec(), vec![1, 2, 3]);
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn pop_back_n(&mut self, n: usize) -> List<T, A>
+    where
+        A: Clone,
+    {
+        let ghost = self.ghost_node();
+        let (mut start, mut count) = (ghost, 0);
+        // SAFETY: `start` is a valid node in the list.
+        while count < n && unsafe { start.as_ref().prev } != ghost {
+            start = unsafe { start.as_ref().prev };
+            count += 1;
+        }
+        // SAFETY: `start..ghost` is a valid range of `count` nodes in the list.
+        unsafe {
+            self.detach_range(
+                start,
+                ghost,
+                #[cfg(feature = "length")]
+                count,
+            )
+        }
+    }
+
     /// Provides a cursor at the node with given index.
     ///
     /// By convention, the cursor is pointing to the "ghost" node if `at == len`.
@@ -497,7 +1281,7 @@ impl<T> List<T> {
     /// assert_eq!(list.cursor(1).current(), Some(&2));
     /// assert_eq!(list.cursor(3).current(), None);
     /// ```
-    pub fn cursor(&self, at: usize) -> Cursor<'_, T> {
+    pub fn cursor(&self, at: usize) -> Cursor<'_, T, A> {
         #[cfg(feature = "length")]
         assert!(
             at <= self.len,
@@ -524,7 +1308,7 @@ impl<T> List<T> {
     /// let cursor = list.cursor_start();
     /// assert_eq!(cursor.current(), Some(&1));
     /// ```
-    pub fn cursor_start(&self) -> Cursor<'_, T> {
+    pub fn cursor_start(&self) -> Cursor<'_, T, A> {
         Cursor::new(
             self,
             self.front_node(),
@@ -546,7 +1330,7 @@ impl<T> List<T> {
     /// assert_eq!(cursor.current(), None);
     /// assert_eq!(cursor.previous(), Some(&3));
     /// ```
-    pub fn cursor_end(&self) -> Cursor<'_, T> {
+    pub fn cursor_end(&self) -> Cursor<'_, T, A> {
         Cursor::new(
             self,
             self.ghost_node(),
@@ -578,7 +1362,7 @@ impl<T> List<T> {
     /// assert_eq!(cursor.current(), Some(&10));
     /// assert_eq!(list.cursor_mut(3).current_mut(), None);
     /// ```
-    pub fn cursor_mut(&mut self, at: usize) -> CursorMut<'_, T> {
+    pub fn cursor_mut(&mut self, at: usize) -> CursorMut<'_, T, A> {
         #[cfg(feature = "length")]
         assert!(
             at <= self.len,
@@ -592,6 +1376,41 @@ impl<T> List<T> {
         cursor
     }
 
+    /// Provides a pair of cursors at the nodes with given indices, which can
+    /// move independently and mutate the elements they point to.
+    ///
+    /// Unlike two [`CursorMut`]s, which cannot coexist, the pair cannot change
+    /// the linking structure of the list. See [`CursorPairMut`] for details.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `first > len` or `second > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4]);
+    /// let mut pair = list.cursor_pair_mut(1, 3);
+    /// assert_eq!(pair.first().current(), Some(&2));
+    /// assert_eq!(pair.second().current(), Some(&4));
+    ///
+    /// *pair.first_mut().unwrap() *= 10;
+    /// pair.swap();
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![1, 4, 3, 20]);
+    /// ```
+    pub fn cursor_pair_mut(&mut self, first: usize, second: usize) -> CursorPairMut<'_, T, A> {
+        let list: &Self = self;
+        CursorPairMut::new(list.cursor(first), list.cursor(second))
+    }
+
     /// Provides a cursor with editing operations at the first node.
     ///
     /// The cursor is pointing to the "ghost" node if the list is empty.
@@ -610,7 +1429,7 @@ impl<T> List<T> {
     /// }
     /// assert_eq!(cursor.current(), Some(&5));
     /// ```
-    pub fn cursor_start_mut(&mut self) -> CursorMut<'_, T> {
+    pub fn cursor_start_mut(&mut self) -> CursorMut<'_, T, A> {
         CursorMut::new(
             self,
             self.front_node(),
@@ -635,7 +1454,7 @@ impl<T> List<T> {
     /// }
     /// assert_eq!(cursor.previous(), Some(&15));
     /// ```
-    pub fn cursor_end_mut(&mut self) -> CursorMut<'_, T> {
+    pub fn cursor_end_mut(&mut self) -> CursorMut<'_, T, A> {
         CursorMut::new(
             self,
             self.ghost_node(),
@@ -644,6 +1463,173 @@ impl<T> List<T> {
         )
     }
 
+    /// Returns `true` if the element referred to by `handle` is in the list.
+    ///
+    /// Note that if the element is removed and another element happens to be
+    /// allocated at the same address, it returns `true` (except with
+    /// `debug_assertions` or `feature = "paranoid"`). See [`NodeHandle`] for
+    /// details.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3]);
+    /// let handle = list.cursor(1).handle().unwrap();
+    /// assert!(list.contains_handle(&handle));
+    ///
+    /// let mut cursor = list.cursor_mut(1);
+    /// assert_eq!(cursor.remove(), Some(2));
+    /// assert!(!list.contains_handle(&handle));
+    /// ```
+    pub fn contains_handle(&self, handle: &NodeHandle<T>) -> bool {
+        let ghost = self.ghost_node();
+        let mut current = self.front_node();
+        while current != ghost {
+            // SAFETY: the nodes reachable from the ghost node are always valid.
+            unsafe {
+                if handle.refers_to(current) {
+                    return true;
+                }
+                current = current.as_ref().next;
+            }
+        }
+        false
+    }
+
+    /// Searches for the first element that satisfies a predicate, and returns
+    /// a handle to it, or `None` if no element matches.
+    ///
+    /// Unlike a cursor, the handle does not borrow the list, so the list can
+    /// be edited before the element is located again by [`cursor_at`] or
+    /// [`cursor_mut_at`].
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4]);
+    /// let handle = list.find_handle(|x| x % 2 == 0).unwrap();
+    /// list.push_front(0);
+    /// assert_eq!(list.pop_back(), Some(4));
+    ///
+    /// // SAFETY: the element of the handle is in the list.
+    /// let mut cursor = unsafe { list.cursor_mut_at(&handle) };
+    /// assert_eq!(cursor.remove(), Some(2));
+    /// assert_eq!(Vec::from_iter(list), vec![0, 1, 3]);
+    /// ```
+    ///
+    /// [`cursor_at`]: List::cursor_at
+    /// [`cursor_mut_at`]: List::cursor_mut_at
+    pub fn find_handle<P>(&self, mut predicate: P) -> Option<NodeHandle<T>>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.nodes()
+            // SAFETY: the nodes of the list are valid non-ghost nodes.
+            .find(|node| predicate(unsafe { &node.as_ref().element }))
+            .map(NodeHandle::new)
+    }
+
+    /// Provides a cursor at the element referred to by `handle`, without
+    /// seeking by index from either end of the list.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time, except that the element
+    /// is checked to be in the list in *O*(*n*) time with `debug_assertions`
+    /// or `feature = "paranoid"`. With the `length` feature, the index *i* of
+    /// the element is computed only when needed, by walking
+    /// *O*(min(*i*, *n* - *i*)) nodes to the ghost node.
+    ///
+    /// # Safety
+    ///
+    /// The element referred to by `handle` must be in the list. See
+    /// [`contains_handle`] for a safe (but *O*(*n*)) check.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is not in the list, with `debug_assertions` or
+    /// `feature = "paranoid"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3]);
+    /// let handle = list.cursor(1).handle().unwrap();
+    /// list.push_front(0);
+    ///
+    /// assert!(list.contains_handle(&handle));
+    /// // SAFETY: the element of the handle is in the list.
+    /// let cursor = unsafe { list.cursor_at(&handle) };
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 2);
+    /// ```
+    ///
+    /// [`contains_handle`]: List::contains_handle
+    pub unsafe fn cursor_at(&self, handle: &NodeHandle<T>) -> Cursor<'_, T, A> {
+        let node = self.handle_node(handle);
+        Cursor::at_node(self, node)
+    }
+
+    /// Provides a cursor with editing operations at the element referred to
+    /// by `handle`, without seeking from either end of the list.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time, except that the element
+    /// is checked to be in the list in *O*(*n*) time with `debug_assertions`
+    /// or `feature = "paranoid"`. With the `length` feature, the index *i* of
+    /// the element is computed only when needed, by walking
+    /// *O*(min(*i*, *n* - *i*)) nodes to the ghost node.
+    ///
+    /// # Safety
+    ///
+    /// The element referred to by `handle` must be in the list. See
+    /// [`contains_handle`] for a safe (but *O*(*n*)) check.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is not in the list, with `debug_assertions` or
+    /// `feature = "paranoid"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3]);
+    /// let handle = list.cursor_start_mut().insert_with_handle(0);
+    ///
+    /// // SAFETY: the element of the handle is in the list.
+    /// let mut cursor = unsafe { list.cursor_mut_at(&handle) };
+    /// assert_eq!(cursor.remove(), Some(0));
+    /// assert_eq!(Vec::from_iter(list), vec![1, 2, 3]);
+    /// ```
+    ///
+    /// [`contains_handle`]: List::contains_handle
+    pub unsafe fn cursor_mut_at(&mut self, handle: &NodeHandle<T>) -> CursorMut<'_, T, A> {
+        let node = self.handle_node(handle);
+        CursorMut::at_node(self, node)
+    }
+
     /// Provides a forward iterator.
     ///
     /// # Examples
@@ -692,471 +1678,3450 @@ impl<T> List<T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, A> {
         IterMut::new(self)
     }
 
-    /// Moves all elements from `other` to the end of the list.
+    /// Provides a double-ended iterator over the specified range of the list.
     ///
-    /// This reuses all the nodes from `other` and moves them into `self`. After
-    /// this operation, `other` becomes empty.
+    /// Unlike `iter().skip(a).take(b - a)`, the start of the range is sought
+    /// only once, and the iterator has an exact `size_hint` (with the `length`
+    /// feature), so it can also be iterated from the back.
     ///
     /// # Complexity
     ///
-    /// This operation should compute in *O*(1) time and *O*(1) memory.
+    /// This operation should compute in *O*(*n*) time for seeking the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
     ///
-    /// let mut list1 = List::new();
-    /// list1.push_back('a');
-    ///
-    /// let mut list2 = List::new();
-    /// list2.push_back('b');
-    /// list2.push_back('c');
-    ///
-    /// list1.append(&mut list2);
-    ///
-    /// let mut iter = list1.iter();
-    /// assert_eq!(iter.next(), Some(&'a'));
-    /// assert_eq!(iter.next(), Some(&'b'));
-    /// assert_eq!(iter.next(), Some(&'c'));
-    /// assert!(iter.next().is_none());
+    /// let list = List::from_iter([1, 2, 3, 4, 5]);
+    /// let mut iter = list.iter_range(1..4);
     ///
-    /// assert!(list2.is_empty());
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.next_back(), Some(&4));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn append(&mut self, other: &mut Self) {
-        if let Some(detached) = other.detach_all_nodes() {
-            // `self.back_node()` and `self.ghost_node()` are valid
-            // nodes in the list and they are adjacent, so it is safe.
-            unsafe { self.attach_nodes(self.ghost_node(), detached) }
-        }
+    pub fn iter_range<R: RangeBounds<usize>>(&self, range: R) -> Iter<'_, T> {
+        self.view(range).iter()
     }
 
-    /// Moves all elements from `other` to the begin of the list.
-    /// This reuses all the nodes from `other` and moves them into `self`. After
-    /// this operation, `other` becomes empty.
+    /// Provides a forward iterator which starts at the element with the given
+    /// index, walks to the back, wraps around to the front, and finishes at the
+    /// element before `at`, so each element is visited exactly once.
+    ///
+    /// The iterator is double-ended, where iterating from the back starts at the
+    /// element before `at`.
     ///
     /// # Complexity
     ///
-    /// This operation should compute in *O*(1) time and *O*(1) memory.
+    /// This operation should compute in *O*(*n*) time for seeking the start.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
     ///
-    /// let mut list1 = List::new();
-    /// list1.push_back('a');
+    /// let list = List::from_iter([1, 2, 3, 4, 5]);
     ///
-    /// let mut list2 = List::new();
-    /// list2.push_back('b');
-    /// list2.push_back('c');
+    /// assert_eq!(Vec::from_iter(list.iter_cyclic_from(3)), vec![&4, &5, &1, &2, &3]);
+    /// assert_eq!(list.iter_cyclic_from(3).next_back(), Some(&3));
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(list.iter_cyclic_from(3).len(), 5);
+    /// assert_eq!(Vec::from_iter(list.iter_cyclic_from(0)), vec![&1, &2, &3, &4, &5]);
+    /// ```
+    pub fn iter_cyclic_from(&self, at: usize) -> CyclicIter<'_, T> {
+        let (start_node, end_node) = self.range_nodes(at, None);
+        // SAFETY: `start_node..ghost` and `front..start_node` are valid ranges of
+        // the list, with `len - at` and `at` elements respectively.
+        unsafe {
+            CyclicIter::new(
+                Iter::from_range(
+                    start_node,
+                    end_node,
+                    #[cfg(feature = "length")]
+                    (self.len - at),
+                ),
+                Iter::from_range(
+                    self.front_node(),
+                    start_node,
+                    #[cfg(feature = "length")]
+                    at,
+                ),
+            )
+        }
+    }
+
+    /// Provides a forward iterator which loops over the elements endlessly,
+    /// skipping the ghost node. It returns `None` only if the list is empty.
     ///
-    /// list2.prepend(&mut list1);
+    /// Unlike the cursor iterators, which yield `None` once at the ghost node
+    /// on each round, it can be used with adapters like `take(n)`.
     ///
-    /// let mut iter = list2.iter();
-    /// assert_eq!(iter.next(), Some(&'a'));
-    /// assert_eq!(iter.next(), Some(&'b'));
-    /// assert_eq!(iter.next(), Some(&'c'));
-    /// assert!(iter.next().is_none());
+    /// # Examples
     ///
-    /// assert!(list1.is_empty());
     /// ```
-    pub fn prepend(&mut self, other: &mut Self) {
-        if let Some(detached) = other.detach_all_nodes() {
-            // `self.ghost_node()` and `self.front_node()` are valid
-            // nodes in the list and they are adjacent, so it is safe.
-            unsafe { self.attach_nodes(self.front_node(), detached) }
-        }
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let list = List::from_iter([1, 2, 3]);
+    /// assert_eq!(Vec::from_iter(list.cycle_iter().take(7)), vec![&1, &2, &3, &1, &2, &3, &1]);
+    ///
+    /// let empty = List::<i32>::new();
+    /// assert_eq!(empty.cycle_iter().next(), None);
+    /// ```
+    pub fn cycle_iter(&self) -> Cycle<Iter<'_, T>> {
+        self.iter().cycle()
     }
 
-    /// Splits the list into two at the given index. Returns everything after
-    /// the given index (inclusive).
+    /// Provides a forward iterator over the elements, together with the cursors
+    /// pointing to them.
+    ///
+    /// It is useful to remember the positions of interesting elements while
+    /// scanning the list, and come back to them later without seeking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let list = List::from_iter([3, 1, 4, 1, 5]);
+    /// let ones = Vec::from_iter(
+    ///     list.iter_with_cursors()
+    ///         .filter(|&(_, &x)| x == 1)
+    ///         .map(|(cursor, _)| cursor),
+    /// );
+    ///
+    /// assert_eq!(ones.len(), 2);
+    /// assert_eq!(ones[0].previous(), Some(&3));
+    /// assert_eq!(ones[1].peek_nth(1), Some(&5));
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(ones[1].index(), 3);
+    /// ```
+    pub fn iter_with_cursors(&self) -> IterWithCursors<'_, T, A> {
+        IterWithCursors::new(self)
+    }
+
+    /// Provides a borrowed view of the specified range of the list.
+    ///
+    /// See [`ListView`] for details.
     ///
     /// # Complexity
     ///
-    /// This operation should compute in *O*(*n*) time.
+    /// This operation should compute in *O*(*n*) time for seeking the range.
     ///
     /// # Panics
     ///
-    /// Panics if `at > len`
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
     ///
-    /// let mut list = List::new();
+    /// let list = List::from_iter([1, 2, 3, 4, 5]);
+    /// let view = list.view(1..=3);
     ///
-    /// list.push_front(1);
-    /// list.push_front(2);
-    /// list.push_front(3);
+    /// assert_eq!(view.front(), Some(&2));
+    /// assert_eq!(view.back(), Some(&4));
+    /// assert_eq!(Vec::from_iter(view), vec![&2, &3, &4]);
+    /// ```
+    pub fn view<R: RangeBounds<usize>>(&self, range: R) -> ListView<'_, T> {
+        let (start, end) = self.resolve_range(range);
+        let (start_node, end_node) = self.range_nodes(start, end);
+        // SAFETY: `start_node..end_node` is a valid range in the list, and its
+        // length is `end - start`.
+        unsafe {
+            ListView::from_range(
+                start_node,
+                end_node,
+                #[cfg(feature = "length")]
+                (end.unwrap_or(self.len) - start),
+            )
+        }
+    }
+
+    /// Provides a mutable borrowed view of the specified range of the list,
+    /// whose elements can be mutated, but not the linking structure.
     ///
-    /// let mut split = list.split_off(2);
+    /// See [`ListViewMut`] for details.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time for seeking the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(split.pop_front(), Some(1));
-    /// assert_eq!(split.pop_front(), None);
     /// ```
-    pub fn split_off(&mut self, at: usize) -> List<T> {
-        #[cfg(feature = "length")]
-        assert!(at <= self.len, "Cannot split off at a nonexistent index");
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4, 5]);
+    /// let mut view = list.view_mut(1..=3);
+    /// *view.front_mut().unwrap() = 0;
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![1, 0, 3, 4, 5]);
+    /// ```
+    pub fn view_mut<R: RangeBounds<usize>>(&mut self, range: R) -> ListViewMut<'_, T, A> {
+        let (start, end) = self.resolve_range(range);
+        let (start_node, end_node) = self.range_nodes(start, end);
         #[cfg(feature = "length")]
-        if at == self.len {
-            return List::new();
+        let len = end.unwrap_or(self.len) - start;
+        // SAFETY: `start_node..end_node` is a valid range in the list, and its
+        // length is `end - start`.
+        unsafe {
+            ListViewMut::from_range(
+                self,
+                start_node,
+                end_node,
+                #[cfg(feature = "length")]
+                len,
+                #[cfg(feature = "length")]
+                start,
+            )
         }
-        self.cursor_mut(at).split().unwrap_or_default()
     }
 
-    /// Removes the element at the given index and returns it.
+    /// Provides an iterator over `size` elements of the list at a time,
+    /// starting at the front of the list. Each chunk is an [`Iter`].
+    ///
+    /// The chunks do not overlap. If `size` does not divide the length of
+    /// the list, then the last chunk will not have length `size`.
     ///
     /// # Complexity
     ///
-    /// This operation should compute in *O*(*n*) time.
+    /// Iterating over all the chunks should compute in *O*(*n*) time.
     ///
     /// # Panics
     ///
-    /// Panics if `at >= len`
+    /// Panics if `size` is 0.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
     ///
-    /// let mut list = List::new();
-    ///
-    /// list.push_front(1);
-    /// list.push_front(2);
-    /// list.push_front(3);
+    /// let list = List::from([1, 2, 3, 4, 5]);
+    /// let mut chunks = list.chunks(2);
     ///
-    /// assert_eq!(list.remove(1), 2);
-    /// assert_eq!(list.remove(0), 3);
-    /// assert_eq!(list.remove(0), 1);
+    /// assert!(chunks.next().unwrap().eq(&[1, 2]));
+    /// assert!(chunks.next().unwrap().eq(&[3, 4]));
+    /// assert!(chunks.next().unwrap().eq(&[5]));
+    /// assert!(chunks.next().is_none());
     /// ```
-    pub fn remove(&mut self, at: usize) -> T {
-        #[cfg(feature = "length")]
-        assert!(
-            at < self.len,
-            "Cannot remove at an index outside of the list bounds"
-        );
-
-        self.cursor_mut(at)
-            .remove()
-            .expect("Cannot remove at an index outside of the list bounds")
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        assert!(size != 0, "Cannot create chunks of size 0");
+        Chunks::new(self, size)
     }
 
-    /// Adds an element at the given index in the list.
+    /// Converts the list into an iterator over `size` elements at a time,
+    /// starting at the front of the list. Each chunk is a `List` that reuses
+    /// the nodes of this list.
+    ///
+    /// The chunks do not overlap. If `size` does not divide the length of
+    /// the list, then the last chunk will not have length `size`.
     ///
     /// # Complexity
     ///
-    /// This operation should compute in *O*(*n*) time.
+    /// Iterating over all the chunks should compute in *O*(*n*) time.
     ///
     /// # Panics
     ///
-    /// Panics if `at >= len`
+    /// Panics if `size` is 0.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
-    /// use std::iter::FromIterator;
     ///
-    /// let mut list = List::from_iter([1, 2, 3]);
-    ///
-    /// list.insert(2, 4);
-    /// list.insert(4, 5);
+    /// let list = List::from([1, 2, 3, 4, 5]);
+    /// let mut chunks = list.into_chunks(2);
     ///
-    /// assert_eq!(Vec::from_iter(list), vec![1, 2, 4, 3, 5]);
+    /// assert_eq!(chunks.next(), Some(List::from([1, 2])));
+    /// assert_eq!(chunks.next(), Some(List::from([3, 4])));
+    /// assert_eq!(chunks.next(), Some(List::from([5])));
+    /// assert_eq!(chunks.next(), None);
     /// ```
-    pub fn insert(&mut self, at: usize, elm: T) {
-        #[cfg(feature = "length")]
-        assert!(
-            at <= self.len,
-            "Cannot insert at an index outside of the list bounds"
-        );
-
-        self.cursor_mut(at).insert(elm);
+    pub fn into_chunks(self, size: usize) -> IntoChunks<T, A>
+    where
+        A: Clone,
+    {
+        assert!(size != 0, "Cannot create chunks of size 0");
+        IntoChunks::new(self, size)
     }
 
-    /// Splices another list at the given index.
+    /// Provides an iterator over all contiguous windows of length `size`,
+    /// starting at the front of the list. Each window is an [`Iter`].
+    ///
+    /// The windows overlap. If the list is shorter than `size`, the iterator
+    /// returns no windows.
     ///
     /// # Complexity
     ///
-    /// This operation should compute in *O*(*n*) time.
+    /// Iterating over all the windows should compute in *O*(*n*) time.
     ///
     /// # Panics
     ///
-    /// Panics if `at > len`
+    /// Panics if `size` is 0.
     ///
     /// # Examples
     ///
     /// ```
     /// use cyclic_list::List;
-    /// use std::iter::FromIterator;
     ///
-    /// let mut list = List::from_iter([1, 2, 3]);
+    /// let list = List::from(['r', 'u', 's', 't']);
+    /// let mut windows = list.windows(3);
     ///
-    /// let other = List::from_iter([4, 5, 6]);
+    /// assert!(windows.next().unwrap().eq(&['r', 'u', 's']));
+    /// assert!(windows.next().unwrap().eq(&['u', 's', 't']));
+    /// assert!(windows.next().is_none());
     ///
-    /// list.splice_at(2, other);
+    /// assert!(list.windows(5).next().is_none());
+    /// ```
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size != 0, "Cannot create windows of size 0");
+        Windows::new(self, size)
+    }
+
+    /// Provides an iterator over all the pairs of adjacent elements,
+    /// starting at the front of the list.
+    ///
+    /// It is like [`windows(2)`](List::windows), but yields the pairs as
+    /// tuples.
+    ///
+    /// # Complexity
+    ///
+    /// Iterating over all the pairs should compute in *O*(*n*) time.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(Vec::from_iter(list), vec![1, 2, 4, 5, 6, 3]);
     /// ```
-    pub fn splice_at(&mut self, at: usize, other: Self) {
-        #[cfg(feature = "length")]
-        assert!(at <= self.len, "Cannot split at a nonexistent node");
-        let mut cursor_mut = self.cursor_start_mut();
-        cursor_mut
-            .seek_forward(at)
-            .expect("Cannot splice at a nonexistent node");
-        cursor_mut.splice(other);
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([1, 4, 9, 16]);
+    ///
+    /// let differences: Vec<_> = list.windows2().map(|(a, b)| b - a).collect();
+    /// assert_eq!(differences, vec![3, 5, 7]);
+    /// ```
+    pub fn windows2(&self) -> Windows2<'_, T> {
+        Windows2::new(self)
     }
 
-    /// Converts `self` into a vector without clones.
+    /// Provides an iterator over all contiguous windows of length `size` in
+    /// the cyclic order of the list, i.e. including the windows that wrap
+    /// around from the back to the front of the list.
+    ///
+    /// There is a window starting at each element of the list, so a list with
+    /// length *n* has *n* windows. If the list is shorter than `size`, the
+    /// iterator returns no windows.
+    ///
+    /// # Complexity
+    ///
+    /// Iterating over all the windows should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
     ///
     /// # Examples
+    ///
     /// ```
     /// use cyclic_list::List;
-    /// let s = List::from([10, 40, 30]);
-    /// let x = s.into_vec();
-    /// // `s` cannot be used anymore because it has been converted into `x`.
     ///
-    /// assert_eq!(x, vec![10, 40, 30]);
+    /// // The vertices of a polygon
+    /// let polygon = List::from([(0, 0), (4, 0), (4, 3)]);
+    ///
+    /// let edges: Vec<Vec<_>> = polygon.cyclic_windows(2).map(|w| w.collect()).collect();
+    /// assert_eq!(
+    ///     edges,
+    ///     vec![
+    ///         vec![&(0, 0), &(4, 0)],
+    ///         vec![&(4, 0), &(4, 3)],
+    ///         vec![&(4, 3), &(0, 0)],
+    ///     ],
+    /// );
     /// ```
-    pub fn into_vec(self) -> Vec<T> {
-        Vec::from_iter(self)
+    pub fn cyclic_windows(&self, size: usize) -> CyclicWindows<'_, T> {
+        assert!(size != 0, "Cannot create windows of size 0");
+        CyclicWindows::new(self, size)
     }
 
-    /// Copies `self` into a new `Vec`.
+    /// Moves all elements from `other` to the end of the list.
+    ///
+    /// This reuses all the nodes from `other` and moves them into `self`. After
+    /// this operation, `other` becomes empty.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time and *O*(1) memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocator of `other` is not equal to that of the list.
     ///
     /// # Examples
+    ///
     /// ```
     /// use cyclic_list::List;
     ///
-    /// let s = List::from([10, 40, 30]);
-    /// let x = s.to_vec();
-    /// // Here, `s` and `x` can be modified independently.
+    /// let mut list1 = List::new();
+    /// list1.push_back('a');
+    ///
+    /// let mut list2 = List::new();
+    /// list2.push_back('b');
+    /// list2.push_back('c');
+    ///
+    /// list1.append(&mut list2);
+    ///
+    /// let mut iter = list1.iter();
+    /// assert_eq!(iter.next(), Some(&'a'));
+    /// assert_eq!(iter.next(), Some(&'b'));
+    /// assert_eq!(iter.next(), Some(&'c'));
+    /// assert!(iter.next().is_none());
+    ///
+    /// assert!(list2.is_empty());
     /// ```
-    pub fn to_vec(&self) -> Vec<T>
-    where
-        T: Clone,
-    {
-        self.iter().cloned().collect()
+    pub fn append(&mut self, other: &mut Self) {
+        self.check_same_alloc(other);
+        if let Some(detached) = other.detach_all_nodes() {
+            // `self.back_node()` and `self.ghost_node()` are valid
+            // nodes in the list and they are adjacent, so it is safe.
+            unsafe { self.attach_nodes(self.ghost_node(), detached) }
+        }
     }
-}
 
-impl<T: Debug> Debug for List<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_list().entries(self.iter()).finish()
+    /// Moves all elements from `other` to the begin of the list.
+    /// This reuses all the nodes from `other` and moves them into `self`. After
+    /// this operation, `other` becomes empty.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time and *O*(1) memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocator of `other` is not equal to that of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list1 = List::new();
+    /// list1.push_back('a');
+    ///
+    /// let mut list2 = List::new();
+    /// list2.push_back('b');
+    /// list2.push_back('c');
+    ///
+    /// list2.prepend(&mut list1);
+    ///
+    /// let mut iter = list2.iter();
+    /// assert_eq!(iter.next(), Some(&'a'));
+    /// assert_eq!(iter.next(), Some(&'b'));
+    /// assert_eq!(iter.next(), Some(&'c'));
+    /// assert!(iter.next().is_none());
+    ///
+    /// assert!(list1.is_empty());
+    /// ```
+    pub fn prepend(&mut self, other: &mut Self) {
+        self.check_same_alloc(other);
+        if let Some(detached) = other.detach_all_nodes() {
+            // `self.ghost_node()` and `self.front_node()` are valid
+            // nodes in the list and they are adjacent, so it is safe.
+            unsafe { self.attach_nodes(self.front_node(), detached) }
+        }
     }
-}
 
-impl<T> Default for List<T> {
-    fn default() -> Self {
-        Self::new()
+    /// Inserts the items of `iter` before the front of the list, preserving
+    /// the order of the iterator.
+    ///
+    /// The items are linked into a detached chain first, which is then
+    /// spliced to the front at once, so the list is left unchanged if the
+    /// iterator panics.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*m*) time, where *m* is the
+    /// number of the items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from([4, 5]);
+    /// list.extend_front(1..4);
+    /// assert_eq!(list.into_vec(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if let Some(detached) = DetachedNodes::from_iter_in(iter.into_iter(), &self.alloc) {
+            // SAFETY: `self.front_node()` is a valid node in the list.
+            unsafe { self.attach_nodes(self.front_node(), detached) }
+        }
     }
-}
+
+    /// Interleaves the elements of `other` into the list, so that the
+    /// elements are taken alternately from `self` and `other`, starting with
+    /// `self`. When one of the lists runs out, the rest of the other list is
+    /// appended.
+    ///
+    /// This reuses all the nodes from both lists, so no elements are moved
+    /// and no nodes are allocated.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(min(*m*, *n*)) time and *O*(1)
+    /// memory, where *m* and *n* are the lengths of the two lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list1 = List::from([1, 3, 5, 7, 8]);
+    /// let list2 = List::from([2, 4, 6]);
+    ///
+    /// assert_eq!(list1.interleave(list2).into_vec(), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// let list1 = List::from(['a', 'c']);
+    /// let list2 = List::from(['b', 'd', 'e', 'f']);
+    ///
+    /// assert_eq!(list1.interleave(list2).into_vec(), vec!['a', 'b', 'c', 'd', 'e', 'f']);
+    /// ```
+    pub fn interleave(mut self, mut other: Self) -> Self {
+        if other.is_empty() {
+            return self;
+        }
+        let ghost = self.ghost_node();
+        let mut a = self.front_node();
+        let mut b = other.front_node();
+        self.append(&mut other);
+        // Move the nodes `b` of the second part one by one after the nodes `a`
+        // of the first part, until either part runs out.
+        // SAFETY: `a` and `b` are valid nodes in the list, and `a` is always
+        // before `b`, so the relinking keeps the list well-formed.
+        unsafe {
+            while b != ghost {
+                let a_next = a.as_ref().next;
+                if a_next == b {
+                    // The first part runs out, and the rest of the second part
+                    // is already in place.
+                    break;
+                }
+                let b_next = b.as_ref().next;
+                connect(b.as_ref().prev, b_next);
+                connect(a, b);
+                connect(b, a_next);
+                a = a_next;
+                b = b_next;
+            }
+        }
+        self
+    }
+
+    /// Concatenates a sequence of lists into a single list.
+    ///
+    /// This reuses all the nodes from the lists, so no elements are moved and
+    /// no nodes are allocated.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*k*) time, where *k* is the number
+    /// of lists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocators of the lists are not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let lists = vec![List::from([1, 2]), List::new(), List::from([3]), List::from([4, 5])];
+    ///
+    /// assert_eq!(List::concat(lists).into_vec(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn concat<I>(lists: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+        A: Default,
+    {
+        let mut lists = lists.into_iter();
+        let mut list = lists.next().unwrap_or_else(|| Self::new_in(A::default()));
+        lists.for_each(|mut other| list.append(&mut other));
+        list
+    }
+
+    /// Concatenates a sequence of lists into a single list, placing a clone
+    /// of `sep` between each two adjacent lists.
+    ///
+    /// Like [`slice::join`], empty lists are not skipped, so the separators
+    /// around them are adjacent. The nodes of the lists are reused, and only
+    /// the separator nodes are allocated.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*k*) time, where *k* is the number
+    /// of lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let words = vec![List::from(['a', 'b']), List::from(['c']), List::from(['d', 'e'])];
+    ///
+    /// assert_eq!(List::join(words, ' ').into_vec(), vec!['a', 'b', ' ', 'c', ' ', 'd', 'e']);
+    ///
+    /// let lists = vec![List::from([1]), List::new(), List::from([2])];
+    ///
+    /// assert_eq!(List::join(lists, 0).into_vec(), vec![1, 0, 0, 2]);
+    /// ```
+    pub fn join<I>(lists: I, sep: T) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+        T: Clone,
+        A: Default,
+    {
+        let mut lists = lists.into_iter();
+        let mut list = lists.next().unwrap_or_else(|| Self::new_in(A::default()));
+        for mut other in lists {
+            list.push_back(sep.clone());
+            list.append(&mut other);
+        }
+        list
+    }
+
+    /// Rotates the list in-place such that the first `mid` elements of the
+    /// list move to the end while the last `len - mid` elements move to the
+    /// front. After calling `rotate_left`, the element previously at index
+    /// `mid` will become the first element in the list.
+    ///
+    /// Since the list is cyclic, only the ghost node is relinked.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time for seeking (from the
+    /// nearer end with `feature = "length"`), and *O*(1) time for relinking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(['a', 'b', 'c', 'd', 'e', 'f']);
+    /// list.rotate_left(2);
+    /// assert_eq!(list.to_vec(), vec!['c', 'd', 'e', 'f', 'a', 'b']);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        #[cfg(feature = "length")]
+        assert!(mid <= self.len, "Cannot rotate at a nonexistent index");
+        let mut cursor = self.cursor_start();
+        cursor
+            .try_seek_to(mid)
+            .expect("Cannot rotate at a nonexistent index");
+        let front = cursor.current;
+        // SAFETY: `front` is a valid node in the list.
+        unsafe { self.rebase_ghost(front) };
+    }
+
+    /// Rotates the list in-place such that the first `len - k` elements of
+    /// the list move to the end while the last `k` elements move to the
+    /// front. After calling `rotate_right`, the element previously at index
+    /// `len - k` will become the first element in the list.
+    ///
+    /// Since the list is cyclic, only the ghost node is relinked.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time for seeking (from the
+    /// nearer end with `feature = "length"`), and *O*(1) time for relinking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(['a', 'b', 'c', 'd', 'e', 'f']);
+    /// list.rotate_right(2);
+    /// assert_eq!(list.to_vec(), vec!['e', 'f', 'a', 'b', 'c', 'd']);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        let mut cursor = self.cursor_end();
+        #[cfg(feature = "length")]
+        {
+            assert!(k <= self.len, "Cannot rotate at a nonexistent index");
+            cursor.seek_to(self.len - k);
+        }
+        #[cfg(not(feature = "length"))]
+        cursor
+            .seek_backward(k)
+            .expect("Cannot rotate at a nonexistent index");
+        let front = cursor.current;
+        // SAFETY: `front` is a valid node in the list.
+        unsafe { self.rebase_ghost(front) };
+    }
+
+    /// Reverses the order of elements in the list, in place.
+    ///
+    /// The links of every node are swapped, so no elements are moved and no
+    /// nodes are allocated.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time and *O*(1) memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3]);
+    /// list.reverse();
+    /// assert_eq!(list.into_vec(), vec![3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        // SAFETY: `front..ghost` is a valid range of the list.
+        unsafe { reverse_nodes(self.front_node(), self.ghost_node()) };
+    }
+
+    /// Reverses the order of elements in the specified range of the list,
+    /// in place, leaving the rest of the list untouched.
+    ///
+    /// Like [`reverse`](List::reverse), the links of the nodes are swapped,
+    /// so no elements are moved and no nodes are allocated.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time and *O*(1) memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// // A 2-opt move on a tour, which reverses the path between two edges.
+    /// let mut tour = List::from_iter(['a', 'b', 'c', 'd', 'e', 'f']);
+    /// tour.reverse_range(1..5);
+    /// assert_eq!(tour.to_vec(), vec!['a', 'e', 'd', 'c', 'b', 'f']);
+    ///
+    /// tour.reverse_range(3..);
+    /// assert_eq!(tour.to_vec(), vec!['a', 'e', 'd', 'f', 'b', 'c']);
+    /// ```
+    pub fn reverse_range<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = self.resolve_range(range);
+        let (start_node, end_node) = self.range_nodes(start, end);
+        // SAFETY: `start_node..end_node` is a valid range of the list.
+        unsafe { reverse_nodes(start_node, end_node) };
+    }
+
+    /// Calls a closure on each element in the specified range of the list,
+    /// with a mutable reference.
+    ///
+    /// The start of the range is sought only once, which is cheaper than
+    /// accessing the elements by indices.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4, 5]);
+    /// list.for_each_mut_range(1..4, |x| *x *= 10);
+    /// assert_eq!(list.to_vec(), vec![1, 20, 30, 40, 5]);
+    /// ```
+    pub fn for_each_mut_range<R, F>(&mut self, range: R, f: F)
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T),
+    {
+        self.view_mut(range).iter_mut().for_each(f);
+    }
+
+    /// Fills the specified range of the list with clones of `value`.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4, 5]);
+    /// list.fill_range(3.., 0);
+    /// assert_eq!(list.to_vec(), vec![1, 2, 3, 0, 0]);
+    /// ```
+    pub fn fill_range<R>(&mut self, range: R, value: T)
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        self.view_mut(range).fill(value);
+    }
+
+    /// Splits the list into two at the given index. Returns everything after
+    /// the given index (inclusive).
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(min(*at*, *n* - *at*)) time with
+    /// the `length` feature, seeking from the nearer end of the list.
+    /// Otherwise, it takes *O*(*at*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::new();
+    ///
+    /// list.push_front(1);
+    /// list.push_front(2);
+    /// list.push_front(3);
+    ///
+    /// let mut split = list.split_off(2);
+    ///
+    /// assert_eq!(split.pop_front(), Some(1));
+    /// assert_eq!(split.pop_front(), None);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> List<T, A>
+    where
+        A: Clone,
+    {
+        #[cfg(feature = "length")]
+        assert!(at <= self.len, "Cannot split off at a nonexistent index");
+        #[cfg(feature = "length")]
+        if at == self.len {
+            return List::new_in(self.alloc.clone());
+        }
+        let alloc = self.alloc.clone();
+        self.cursor_mut(at)
+            .split()
+            .unwrap_or_else(|| List::new_in(alloc))
+    }
+
+    /// Splits the list into two at the given index. Returns everything before
+    /// the given index (exclusive), while the list retains everything after
+    /// the given index (inclusive).
+    ///
+    /// It is the complement of [`split_off`](List::split_off).
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time for seeking (from the
+    /// nearer end with `feature = "length"`), and *O*(1) time for detaching.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4]);
+    /// let front = list.take_front(1);
+    ///
+    /// assert_eq!(front.to_vec(), vec![1]);
+    /// assert_eq!(list.to_vec(), vec![2, 3, 4]);
+    /// ```
+    pub fn take_front(&mut self, at: usize) -> List<T, A>
+    where
+        A: Clone,
+    {
+        #[cfg(feature = "length")]
+        assert!(at <= self.len, "Cannot take front at a nonexistent index");
+        let alloc = self.alloc.clone();
+        self.cursor_mut(at)
+            .split_before()
+            .unwrap_or_else(|| List::new_in(alloc))
+    }
+
+    /// Splits the list into two at the given index, consuming the list.
+    /// Returns everything before the given index (exclusive), and
+    /// everything after the given index (inclusive).
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let list = List::from_iter([1, 2, 3, 4, 5]);
+    ///
+    /// let (left, right) = list.split_at(2);
+    ///
+    /// assert_eq!(left.into_vec(), vec![1, 2]);
+    /// assert_eq!(right.into_vec(), vec![3, 4, 5]);
+    /// ```
+    pub fn split_at(mut self, at: usize) -> (List<T, A>, List<T, A>)
+    where
+        A: Clone,
+    {
+        let back = self.split_off(at);
+        (self, back)
+    }
+
+    /// Removes the element at the given index and returns it.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at >= len`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::new();
+    ///
+    /// list.push_front(1);
+    /// list.push_front(2);
+    /// list.push_front(3);
+    ///
+    /// assert_eq!(list.remove(1), 2);
+    /// assert_eq!(list.remove(0), 3);
+    /// assert_eq!(list.remove(0), 1);
+    /// ```
+    pub fn remove(&mut self, at: usize) -> T {
+        #[cfg(feature = "length")]
+        assert!(
+            at < self.len,
+            "Cannot remove at an index outside of the list bounds"
+        );
+
+        self.cursor_mut(at)
+            .remove()
+            .expect("Cannot remove at an index outside of the list bounds")
+    }
+
+    /// Adds an element at the given index in the list.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at >= len`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3]);
+    ///
+    /// list.insert(2, 4);
+    /// list.insert(4, 5);
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![1, 2, 4, 3, 5]);
+    /// ```
+    pub fn insert(&mut self, at: usize, elm: T) {
+        #[cfg(feature = "length")]
+        assert!(
+            at <= self.len,
+            "Cannot insert at an index outside of the list bounds"
+        );
+
+        self.cursor_mut(at).insert(elm);
+    }
+
+    /// Swaps two elements in the list.
+    ///
+    /// The elements are swapped in place, and the nodes stay put.
+    /// See [`swap_nodes`](List::swap_nodes) for swapping by relinking the nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * a - The index of the first element
+    /// * b - The index of the second element
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(["a", "b", "c", "d", "e"]);
+    /// list.swap(2, 4);
+    /// assert_eq!(list.into_vec(), vec!["a", "b", "e", "d", "c"]);
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let (node_a, node_b) = self.swap_pair_nodes(a, b);
+        if node_a != node_b {
+            // SAFETY: `node_a` and `node_b` are different non-ghost nodes in the list,
+            // so both of them hold a valid element and they do not overlap.
+            unsafe {
+                std::ptr::swap(
+                    &mut (*node_a.as_ptr()).element,
+                    &mut (*node_b.as_ptr()).element,
+                )
+            };
+        }
+    }
+
+    /// Swaps two elements in the list by relinking their nodes.
+    ///
+    /// Unlike [`swap`](List::swap), the elements are not moved in memory, so
+    /// large elements are not copied, and the [`NodeHandle`]s to them keep
+    /// tracking them at their new positions.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time for locating the nodes,
+    /// and *O*(1) time for relinking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(["a", "b", "c", "d", "e"]);
+    /// let handle = list.cursor(2).handle().unwrap();
+    ///
+    /// list.swap_nodes(2, 4);
+    /// assert_eq!(Vec::from_iter(&list), vec![&"a", &"b", &"e", &"d", &"c"]);
+    ///
+    /// // The handle follows its element.
+    /// let cursor = unsafe { list.cursor_at(&handle) };
+    /// assert_eq!(cursor.current(), Some(&"c"));
+    /// #[cfg(feature = "length")]
+    /// assert_eq!(cursor.index(), 4);
+    /// ```
+    pub fn swap_nodes(&mut self, a: usize, b: usize) {
+        let (node_a, node_b) = self.swap_pair_nodes(a, b);
+        // SAFETY: `node_a` and `node_b` are non-ghost nodes in the list.
+        unsafe { swap_linked(node_a, node_b) };
+    }
+
+    /// Swaps the first and the last elements of the list by relinking their
+    /// nodes. Nothing happens if the list has less than two elements.
+    ///
+    /// Like [`swap_nodes`](List::swap_nodes), the elements are not moved, and
+    /// the [`NodeHandle`]s to them keep tracking them.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4]);
+    /// list.swap_ends();
+    /// assert_eq!(list.to_vec(), vec![4, 2, 3, 1]);
+    ///
+    /// let mut list = List::from_iter([1, 2]);
+    /// list.swap_ends();
+    /// assert_eq!(list.to_vec(), vec![2, 1]);
+    /// ```
+    pub fn swap_ends(&mut self) {
+        // SAFETY: the front and back nodes are linked nodes in the list, and they
+        // are the same node (either an element or the ghost node) if the list
+        // has less than two elements, where nothing happens.
+        unsafe { swap_linked(self.front_node(), self.back_node()) };
+    }
+
+    /// Locate the non-ghost nodes at `a` and `b` for swapping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap_pair_nodes(&self, a: usize, b: usize) -> (NonNull<Node<T>>, NonNull<Node<T>>) {
+        #[cfg(feature = "length")]
+        assert!(
+            a < self.len && b < self.len,
+            "Cannot swap at an index outside of the list bounds"
+        );
+        let mut cursor = self.cursor(a);
+        let node_a = cursor.current;
+        cursor
+            .try_seek_to(b)
+            .expect("Cannot swap at an index outside of the list bounds");
+        let node_b = cursor.current;
+        let ghost = self.ghost_node();
+        assert!(
+            node_a != ghost && node_b != ghost,
+            "Cannot swap at an index outside of the list bounds"
+        );
+        (node_a, node_b)
+    }
+
+    /// Rearranges the elements of the list by the permutation `order`, so that
+    /// the element at index `i` after reordering is the element at index
+    /// `order[i]` before reordering.
+    ///
+    /// The nodes are relinked in the new order, so no elements are moved.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time and *O*(*n*) memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is not a permutation of `0..len`. In this case, the
+    /// list is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let mut list = List::from(['a', 'b', 'c', 'd']);
+    ///
+    /// list.reorder_by_indices(&[2, 0, 3, 1]);
+    /// assert_eq!(list.into_vec(), vec!['c', 'a', 'd', 'b']);
+    /// ```
+    pub fn reorder_by_indices(&mut self, order: &[usize]) {
+        let mut nodes = Vec::from_iter(self.nodes().map(Some));
+        assert_eq!(
+            order.len(),
+            nodes.len(),
+            "Cannot reorder a list of length {} by {} indices",
+            nodes.len(),
+            order.len()
+        );
+        // Each node can be taken only once, so an index that is out of range or
+        // taken twice makes `order` not a permutation.
+        let nodes = Vec::from_iter(order.iter().map(|&i| {
+            nodes.get_mut(i).and_then(Option::take).unwrap_or_else(|| {
+                panic!(
+                    "Cannot reorder a list by a duplicate or nonexistent index {}",
+                    i
+                )
+            })
+        }));
+        // SAFETY: `nodes` is a permutation of all the non-ghost nodes in the list.
+        unsafe { self.relink_nodes(nodes) };
+    }
+
+    /// Splices another list at the given index.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(min(*at*, *n* - *at*)) time with
+    /// the `length` feature, seeking from the nearer end of the list.
+    /// Otherwise, it takes *O*(*at*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3]);
+    ///
+    /// let other = List::from_iter([4, 5, 6]);
+    ///
+    /// list.splice_at(2, other);
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![1, 2, 4, 5, 6, 3]);
+    /// ```
+    pub fn splice_at(&mut self, at: usize, other: Self) {
+        #[cfg(feature = "length")]
+        assert!(at <= self.len, "Cannot split at a nonexistent node");
+        let mut cursor_mut = self.cursor_start_mut();
+        cursor_mut
+            .try_seek_to(at)
+            .expect("Cannot splice at a nonexistent node");
+        cursor_mut.splice(other);
+    }
+
+    /// Replaces the specified range in the list with the nodes of another
+    /// list, and returns the removed range as a new list.
+    ///
+    /// This generalizes [`splice_at`], which is the same as replacing an empty
+    /// range. The nodes of `other` and the removed nodes are reused without
+    /// allocations.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time for seeking the range,
+    /// and *O*(1) time for relinking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4, 5]);
+    /// let other = List::from_iter([7, 8, 9]);
+    ///
+    /// let removed = list.splice_range(1..3, other);
+    ///
+    /// assert_eq!(Vec::from_iter(list), vec![1, 7, 8, 9, 4, 5]);
+    /// assert_eq!(Vec::from_iter(removed), vec![2, 3]);
+    /// ```
+    ///
+    /// [`splice_at`]: List::splice_at
+    pub fn splice_range<R>(&mut self, range: R, other: Self) -> List<T, A>
+    where
+        R: RangeBounds<usize>,
+        A: Clone,
+    {
+        let (start, end) = self.resolve_range(range);
+        let (start_node, end_node) = self.range_nodes(start, end);
+        // SAFETY: `start_node..end_node` is a valid range in the list, and its
+        // length is `end - start`.
+        let removed = unsafe {
+            self.detach_range(
+                start_node,
+                end_node,
+                #[cfg(feature = "length")]
+                (end.unwrap_or(self.len) - start),
+            )
+        };
+        CursorMut::new(
+            self,
+            end_node,
+            #[cfg(feature = "length")]
+            start,
+        )
+        .splice(other);
+        removed
+    }
+
+    /// Reallocates all the nodes of the list in iteration order, so that
+    /// subsequent traversals are more likely to access memory sequentially.
+    ///
+    /// A list that has been heavily mutated has its nodes scattered over the
+    /// heap. Since every node is allocated individually, how close the new
+    /// nodes are depends on the global allocator, but allocating them in a row
+    /// usually gives a nearly contiguous layout.
+    ///
+    /// The elements are moved (not cloned) into the new nodes.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time and *O*(*n*) memory.
+    /// All the new nodes are allocated before the old ones are released, so the
+    /// peak memory usage of the nodes is doubled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(0..10);
+    /// list.drain_filter(|x| *x % 3 == 0).for_each(drop);
+    ///
+    /// list.compact();
+    /// assert_eq!(list.into_vec(), vec![1, 2, 4, 5, 7, 8]);
+    /// ```
+    pub fn compact(&mut self) {
+        // Allocate the new nodes while the old ones are still alive, so that
+        // the released memory will not be handed out again in a random order.
+        let fresh = Vec::from_iter(self.iter().map(|_| Node::<T>::new_uninit_in(&self.alloc)));
+        for node in fresh {
+            // SAFETY: the number of fresh nodes is equal to the length of the
+            // list, so the list is not empty here, and its front node is a valid
+            // non-ghost node in the list. After all the fresh nodes are moved to
+            // the back, the old nodes are all released in order.
+            let old = unsafe { self.detach_node(self.front_node()) };
+            // SAFETY: `node.element` is initialized here, and `node.prev`,
+            // `node.next` are initialized when attaching to the list.
+            unsafe {
+                let element = self.free_node(old);
+                std::ptr::addr_of_mut!((*node.as_ptr()).element).write(element);
+                self.attach_node(self.ghost_node(), node);
+            }
+        }
+    }
+
+    /// Converts the list into a list of another type, by applying `f` to
+    /// each element in order.
+    ///
+    /// When `Node<T>` and `Node<U>` have the same memory layout (e.g. `T`
+    /// and `U` have the same size and alignment), the nodes of this list are
+    /// reused, and the new elements are written in place. Otherwise, it is
+    /// the same as `self.into_iter().map(f).collect()`.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated, and all the elements, converted
+    /// or not, are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([1u32, 2, 3]);
+    ///
+    /// // `u32` and `i32` have the same layout, so the nodes are reused.
+    /// let list = list.map(|x| -(x as i32));
+    /// assert_eq!(list.into_vec(), vec![-1, -2, -3]);
+    ///
+    /// let list = List::from([1u8, 2, 3]);
+    ///
+    /// // `u8` and `String` have different layouts, so new nodes are allocated.
+    /// let list = list.map(|x| x.to_string());
+    /// assert_eq!(list.into_vec(), vec!["1", "2", "3"]);
+    /// ```
+    pub fn map<U, F>(self, mut f: F) -> List<U, A>
+    where
+        A: Clone,
+        F: FnMut(T) -> U,
+    {
+        match self.try_map(|element| Ok::<U, Infallible>(f(element))) {
+            Ok(mapped) => mapped,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Converts the list into a list of another type, by applying the
+    /// fallible function `f` to each element in order.
+    ///
+    /// It stops at the first error and returns it. In this case, all the
+    /// elements, converted or not, are dropped.
+    ///
+    /// Like [`map`], the nodes of this list are reused if `Node<T>` and
+    /// `Node<U>` have the same memory layout.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated, and all the elements, converted
+    /// or not, are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let tokens = List::from(["1", "2", "3"]);
+    /// let numbers = tokens.try_map(|s| s.parse::<i32>());
+    /// assert_eq!(numbers, Ok(List::from([1, 2, 3])));
+    ///
+    /// let tokens = List::from(["1", "x", "3"]);
+    /// let numbers = tokens.try_map(|s| s.parse::<i32>());
+    /// assert!(numbers.is_err());
+    /// ```
+    ///
+    /// [`map`]: List::map
+    pub fn try_map<U, E, F>(mut self, mut f: F) -> Result<List<U, A>, E>
+    where
+        A: Clone,
+        F: FnMut(T) -> Result<U, E>,
+    {
+        /// Releases the allocation of a node whose element is moved out.
+        struct Guard<'a, U, A: RawAlloc> {
+            node: NonNull<Node<U>>,
+            alloc: &'a A,
+        }
+
+        impl<U, A: RawAlloc> Drop for Guard<'_, U, A> {
+            fn drop(&mut self) {
+                // SAFETY: the node is allocated by `alloc`, and it has no element.
+                unsafe { Node::dealloc_in(self.node, self.alloc) };
+            }
+        }
+
+        let mut mapped = List::new_in(self.alloc.clone());
+        if Layout::new::<Node<T>>() != Layout::new::<Node<U>>() {
+            for element in self {
+                mapped.push_back(f(element)?);
+            }
+            return Ok(mapped);
+        }
+        while !self.is_empty() {
+            // SAFETY: the list is not empty here, so its front node is a valid
+            // non-ghost node in the list.
+            let node = unsafe { self.detach_node(self.front_node()) };
+            // SAFETY: the element is moved out of the node, and then the node is
+            // reinterpreted as an uninitialized `Node<U>` with the same layout,
+            // so that only its allocation is released if `f` fails or panics.
+            let element = unsafe { std::ptr::read(std::ptr::addr_of!((*node.as_ptr()).element)) };
+            let guard = Guard {
+                node: node.cast::<Node<U>>(),
+                alloc: &self.alloc,
+            };
+            let element = f(element)?;
+            let node = guard.node;
+            std::mem::forget(guard);
+            // SAFETY: `node.element` is initialized here, and `node.prev`,
+            // `node.next` are initialized when attaching to `mapped`.
+            unsafe {
+                std::ptr::addr_of_mut!((*node.as_ptr()).element).write(element);
+                mapped.attach_node(mapped.ghost_node(), node);
+            }
+        }
+        Ok(mapped)
+    }
+
+    /// Converts `self` into a vector without clones.
+    ///
+    /// With the `length` feature, the vector is allocated with the exact
+    /// capacity up front.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    /// ```
+    /// use cyclic_list::List;
+    /// let s = List::from([10, 40, 30]);
+    /// let x = s.into_vec();
+    /// // `s` cannot be used anymore because it has been converted into `x`.
+    ///
+    /// assert_eq!(x, vec![10, 40, 30]);
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        #[cfg(feature = "length")]
+        let mut vec = Vec::with_capacity(self.len);
+        #[cfg(not(feature = "length"))]
+        let mut vec = Vec::new();
+        vec.extend(self);
+        vec
+    }
+
+    /// Copies `self` into a new `Vec`.
+    ///
+    /// With the `length` feature, the vector is allocated with the exact
+    /// capacity up front.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let s = List::from([10, 40, 30]);
+    /// let x = s.to_vec();
+    /// // Here, `s` and `x` can be modified independently.
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        #[cfg(feature = "length")]
+        let mut vec = Vec::with_capacity(self.len);
+        #[cfg(not(feature = "length"))]
+        let mut vec = Vec::new();
+        vec.extend(self.iter().cloned());
+        vec
+    }
+
+    /// Copies the elements of `self` into `out`.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` differs from the length of the list, in
+    /// which case `out` is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([1, 2, 3]);
+    /// let mut buf = [0; 3];
+    /// list.copy_to_slice(&mut buf);
+    /// assert_eq!(buf, [1, 2, 3]);
+    /// ```
+    pub fn copy_to_slice(&self, out: &mut [T])
+    where
+        T: Copy,
+    {
+        self.clone_into_slice(out);
+    }
+
+    /// Clones the elements of `self` into `out`, reusing the resources of the
+    /// elements in `out` via [`Clone::clone_from`].
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` differs from the length of the list, in
+    /// which case `out` is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from(["a".to_string(), "b".to_string()]);
+    /// let mut buf = vec![String::new(), String::new()];
+    /// list.clone_into_slice(&mut buf);
+    /// assert_eq!(buf, ["a", "b"]);
+    /// ```
+    pub fn clone_into_slice(&self, out: &mut [T])
+    where
+        T: Clone,
+    {
+        #[cfg(feature = "length")]
+        let len = self.len;
+        #[cfg(not(feature = "length"))]
+        let len = self.iter().count();
+        assert!(
+            len == out.len(),
+            "Cannot copy a list into a slice of a different length"
+        );
+        out.iter_mut()
+            .zip(self)
+            .for_each(|(dst, src)| dst.clone_from(src));
+    }
+
+    /// Moves all the elements of `deque` to the back of the list, in order,
+    /// leaving `deque` empty but keeping its allocated capacity.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*m*) time, where *m* is the
+    /// length of `deque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut list = List::from([1, 2]);
+    /// let mut deque = VecDeque::from(vec![4, 5]);
+    /// deque.push_front(3);
+    ///
+    /// list.extend_from_deque(&mut deque);
+    /// assert!(deque.is_empty());
+    /// assert_eq!(list.into_vec(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn extend_from_deque(&mut self, deque: &mut VecDeque<T>) {
+        self.extend(deque.drain(..));
+    }
+
+    /// Returns an adapter that implements [`Display`] by
+    /// rendering the elements joined by `sep`, without collecting them first.
+    ///
+    /// The formatting options, such as width and precision, are applied to
+    /// each element rather than to the whole output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([1.0, 2.5, 3.25]);
+    ///
+    /// assert_eq!(list.display_with(" -> ").to_string(), "1 -> 2.5 -> 3.25");
+    /// assert_eq!(format!("{:.1}", list.display_with(", ")), "1.0, 2.5, 3.2");
+    /// assert_eq!(List::<f64>::new().display_with(", ").to_string(), "");
+    /// ```
+    pub fn display_with<'a>(&'a self, sep: &'a str) -> DisplayWith<'a, T, A> {
+        DisplayWith { list: self, sep }
+    }
+
+    /// Returns an adapter that implements [`Debug`] by rendering at most the
+    /// first `k` and the last `k` elements, with an ellipsis `..` in between
+    /// for the omitted ones.
+    ///
+    /// Like the [`Debug`] implementation of `List`, the alternate flag `{:#?}`
+    /// renders one element per line. Call [`DebugTruncated::with_indices`] to
+    /// prefix each element with its index.
+    ///
+    /// # Complexity
+    ///
+    /// Formatting the adapter should compute in *O*(*k*) time with the
+    /// `length` feature. Otherwise, it takes *O*(*n*) time to count the
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let list = List::from_iter(0..100);
+    ///
+    /// assert_eq!(format!("{:?}", list.debug_truncated(2)), "[0, 1, .., 98, 99]");
+    /// assert_eq!(
+    ///     format!("{:?}", list.debug_truncated(1).with_indices()),
+    ///     "[0: 0, .., 99: 99]",
+    /// );
+    /// assert_eq!(format!("{:#?}", list.debug_truncated(1)), "[\n    0,\n    ..,\n    99,\n]");
+    ///
+    /// // Short lists are not truncated.
+    /// assert_eq!(format!("{:?}", List::from([1, 2, 3]).debug_truncated(2)), "[1, 2, 3]");
+    /// ```
+    pub fn debug_truncated(&self, k: usize) -> DebugTruncated<'_, T, A> {
+        DebugTruncated {
+            list: self,
+            k,
+            indexed: false,
+        }
+    }
+
+    /// Clones the elements in the specified range into a new list, without
+    /// cloning the rest of the list.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, or if
+    /// the end point is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let s = List::from([10, 40, 30, 20]);
+    /// let x = s.clone_range(1..3);
+    ///
+    /// assert_eq!(x.into_vec(), vec![40, 30]);
+    /// assert_eq!(s.into_vec(), vec![10, 40, 30, 20]);
+    /// ```
+    pub fn clone_range<R>(&self, range: R) -> List<T, A>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+        A: Clone,
+    {
+        List::from_iter_in(self.view(range).iter().cloned(), self.alloc.clone())
+    }
+}
+
+impl<T, A: RawAlloc + Default> List<List<T, A>, A> {
+    /// Flattens a list of lists into a single list.
+    ///
+    /// This reuses all the nodes from the inner lists, so no elements are
+    /// moved and no nodes are allocated except the nodes of the outer list,
+    /// which are freed.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*k*) time, where *k* is the length
+    /// of the outer list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    ///
+    /// let nested = List::from([List::from([1, 2]), List::new(), List::from([3, 4])]);
+    ///
+    /// assert_eq!(nested.flatten().into_vec(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn flatten(self) -> List<T, A> {
+        List::concat(self)
+    }
+}
+
+#[cfg(feature = "debug-tools")]
+impl<T: Debug, A: RawAlloc> List<T, A> {
+    /// Writes the node graph of the list in the Graphviz DOT format, for
+    /// visualizing pointer bugs in code built on the unsafe APIs. Enabled by
+    /// `feature = "debug-tools"`.
+    ///
+    /// Each node is labeled with its element (or `ghost`) and its address,
+    /// and has a solid `next` edge and a dashed `prev` edge. The nodes are
+    /// walked from the ghost node by `next` until a node is visited twice,
+    /// so an ill-formed list does not hang the dump, but every pointer read
+    /// must still point to a live node.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![cfg(feature = "debug-tools")]
+    /// use cyclic_list::List;
+    ///
+    /// let list = List::from([1, 2]);
+    ///
+    /// let mut dot = String::new();
+    /// list.dump_dot(&mut dot).unwrap();
+    /// assert!(dot.starts_with("digraph List {"));
+    /// assert_eq!(dot.matches("[label=\"next\"]").count(), 3);
+    /// assert_eq!(dot.matches("[label=\"prev\", style=dashed]").count(), 3);
+    /// ```
+    pub fn dump_dot<W: std::fmt::Write>(&self, mut w: W) -> std::fmt::Result {
+        let ghost = self.ghost_node();
+        let mut visited = std::collections::HashSet::new();
+        let mut node = ghost;
+        writeln!(w, "digraph List {{")?;
+        writeln!(w, "    node [shape=box];")?;
+        while visited.insert(node) {
+            if node == ghost {
+                writeln!(
+                    w,
+                    "    \"{:p}\" [label=\"ghost\\n{:p}\", shape=doublecircle];",
+                    node, node
+                )?;
+            } else {
+                // SAFETY: every non-ghost node reachable from the ghost holds
+                // a valid element.
+                let element = format!("{:?}", unsafe { &node.as_ref().element });
+                let element = element.replace('\\', "\\\\").replace('"', "\\\"");
+                writeln!(
+                    w,
+                    "    \"{:p}\" [label=\"{}\\n{:p}\"];",
+                    node, element, node
+                )?;
+            }
+            // SAFETY: `node` is the ghost node or reached by `next` pointers
+            // from it.
+            let (next, prev) = unsafe { (node.as_ref().next, node.as_ref().prev) };
+            writeln!(w, "    \"{:p}\" -> \"{:p}\" [label=\"next\"];", node, next)?;
+            writeln!(
+                w,
+                "    \"{:p}\" -> \"{:p}\" [label=\"prev\", style=dashed];",
+                node, prev
+            )?;
+            node = next;
+        }
+        writeln!(w, "}}")
+    }
+}
+
+impl<T: Debug, A: RawAlloc> Debug for List<T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An adapter that displays the elements of a [`List`] joined by a separator.
+///
+/// This `struct` is created by [`List::display_with`].
+/// See its documentation for more.
+pub struct DisplayWith<'a, T: 'a, A: RawAlloc = Global> {
+    list: &'a List<T, A>,
+    sep: &'a str,
+}
+
+impl<T: Display, A: RawAlloc> Display for DisplayWith<'_, T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut iter = self.list.iter();
+        if let Some(first) = iter.next() {
+            first.fmt(f)?;
+            for item in iter {
+                f.write_str(self.sep)?;
+                item.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug, A: RawAlloc> Debug for DisplayWith<'_, T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DisplayWith")
+            .field("list", self.list)
+            .field("sep", &self.sep)
+            .finish()
+    }
+}
+
+/// An adapter that formats a [`List`] for debugging, omitting the elements
+/// in the middle of a long list.
+///
+/// This `struct` is created by [`List::debug_truncated`].
+/// See its documentation for more.
+pub struct DebugTruncated<'a, T: 'a, A: RawAlloc = Global> {
+    list: &'a List<T, A>,
+    k: usize,
+    indexed: bool,
+}
+
+impl<'a, T: 'a, A: RawAlloc> DebugTruncated<'a, T, A> {
+    /// Prefix each rendered element with its index in the list.
+    pub fn with_indices(mut self) -> Self {
+        self.indexed = true;
+        self
+    }
+}
+
+impl<T: Debug, A: RawAlloc> Debug for DebugTruncated<'_, T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        /// An element rendered with its index, if any.
+        struct Entry<'a, T>(Option<usize>, &'a T);
+
+        impl<T: Debug> Debug for Entry<'_, T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                if let Some(index) = self.0 {
+                    write!(f, "{}: ", index)?;
+                }
+                self.1.fmt(f)
+            }
+        }
+
+        /// The placeholder of the omitted elements.
+        struct Ellipsis;
+
+        impl Debug for Ellipsis {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str("..")
+            }
+        }
+
+        #[cfg(feature = "length")]
+        let len = self.list.len();
+        #[cfg(not(feature = "length"))]
+        let len = self.list.iter().count();
+
+        let indexed = self.indexed;
+        let entry = |(i, elem)| Entry(Some(i).filter(|_| indexed), elem);
+        let mut list = f.debug_list();
+        if len <= self.k.saturating_mul(2) {
+            list.entries(self.list.iter().enumerate().map(entry));
+        } else {
+            // Seeking from the back reaches the tail in *O*(*k*) time with the
+            // `length` feature.
+            let tail = self
+                .list
+                .cursor(len - self.k)
+                .iter_to(&self.list.cursor_end());
+            list.entries(self.list.iter().take(self.k).enumerate().map(entry))
+                .entry(&Ellipsis)
+                .entries((len - self.k..).zip(tail).map(entry));
+        }
+        list.finish()
+    }
+}
+
+/// Creates an empty list with the [`Global`] allocator. See
+/// [`List::new_in`] for other allocators.
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl<T> Node<T> {
-    /// Create a detached node with given element.
-    pub(crate) fn new_detached(element: T) -> NonNull<Node<T>> {
+    /// Allocate an uninitialized node (except its generation) by `alloc`.
+    pub(crate) fn new_uninit_in<A: RawAlloc>(alloc: &A) -> NonNull<Node<T>> {
+        let node = alloc.allocate(Layout::new::<Node<T>>()).cast::<Node<T>>();
+        // SAFETY: only `node.generation` is written, so it is safe.
+        #[cfg(any(debug_assertions, feature = "paranoid"))]
+        unsafe {
+            std::ptr::addr_of_mut!((*node.as_ptr()).generation).write(next_generation());
+        }
+        node
+    }
+
+    /// Create a detached node with given element by `alloc`.
+    pub(crate) fn new_detached_in<A: RawAlloc>(element: T, alloc: &A) -> NonNull<Node<T>> {
+        let node = Self::new_uninit_in(alloc);
         // SAFETY:
         // - `node.element` is manually written, so it is safe;
-        // - `node.prev` and `node.next` is dangling, but need unsafe blocks for dereference,
-        //   so it is also safe.
-        NonNull::from(unsafe {
-            // `node.prev` and `node.next` will not be read, so it is ok to be
-            // uninitialized. `node.element` is initialized manually by `ptr::write`.
-            #[allow(invalid_value, clippy::uninit_assumed_init)]
-            let node = Box::<Node<T>>::leak(Box::new(MaybeUninit::uninit().assume_init()));
-            std::ptr::write(&mut node.element, element);
-            node
+        // - `node.prev` and `node.next` is uninitialized, but they are always written
+        //   before being read when the node is attached to a list, so it is also safe.
+        unsafe { std::ptr::addr_of_mut!((*node.as_ptr()).element).write(element) };
+        node
+    }
+
+    /// Free the memory of a node by `alloc`, without dropping its element.
+    ///
+    /// It is unsafe because `node` must be allocated by an allocator of type `A`,
+    /// and must not be used after being freed.
+    pub(crate) unsafe fn dealloc_in<A: RawAlloc>(node: NonNull<Node<T>>, alloc: &A) {
+        alloc.deallocate(node.cast(), Layout::new::<Node<T>>());
+    }
+}
+
+impl<T> DetachedNodes<T> {
+    /// If is unsafe because it must be guaranteed that `front..=back` is
+    /// a valid range and its length must be equal to `len` (with
+    /// `#[cfg(feature = "length")]`).
+    unsafe fn new(
+        front: NonNull<Node<T>>,
+        back: NonNull<Node<T>>,
+        #[cfg(feature = "length")] len: usize,
+    ) -> Self {
+        let _marker = PhantomData;
+        #[cfg(feature = "length")]
+        debug_assert!(len > 0, "Cannot detach nodes of length 0");
+        Self {
+            front,
+            back,
+            #[cfg(feature = "length")]
+            len,
+            _marker,
+        }
+    }
+}
+
+impl<T> DetachedNodes<T> {
+    /// Allocate a detached node for each item of `iter` and link them in
+    /// order, or return `None` if `iter` is empty.
+    ///
+    /// If `iter` panics, the nodes allocated so far are freed along with
+    /// their elements.
+    pub(crate) fn from_iter_in<I: Iterator<Item = T>, A: RawAlloc>(
+        mut iter: I,
+        alloc: &A,
+    ) -> Option<Self> {
+        /// Owns the detached nodes `front..=back` until they are all linked.
+        struct Guard<'a, T, A: RawAlloc> {
+            front: NonNull<Node<T>>,
+            back: NonNull<Node<T>>,
+            alloc: &'a A,
+        }
+
+        impl<T, A: RawAlloc> Drop for Guard<'_, T, A> {
+            fn drop(&mut self) {
+                let mut current = self.front;
+                loop {
+                    // SAFETY: the nodes `front..=back` are linked by `next`,
+                    // and only `front.prev` and `back.next` are uninitialized,
+                    // so each node is freed as an uninitialized node after
+                    // dropping its element.
+                    unsafe {
+                        // `back.next` is uninitialized and must not be read.
+                        let next = if current == self.back {
+                            None
+                        } else {
+                            Some(std::ptr::addr_of!((*current.as_ptr()).next).read())
+                        };
+                        std::ptr::drop_in_place(std::ptr::addr_of_mut!(
+                            (*current.as_ptr()).element
+                        ));
+                        Node::dealloc_in(current, self.alloc);
+                        match next {
+                            Some(next) => current = next,
+                            None => break,
+                        }
+                    }
+                }
+            }
+        }
+
+        let front = Node::new_detached_in(iter.next()?, alloc);
+        let mut guard = Guard {
+            front,
+            back: front,
+            alloc,
+        };
+        #[cfg(feature = "length")]
+        let mut len = 1;
+        for item in iter {
+            let node = Node::new_detached_in(item, alloc);
+            // SAFETY: `guard.back` and `node` are valid detached nodes.
+            unsafe { connect(guard.back, node) };
+            guard.back = node;
+            #[cfg(feature = "length")]
+            {
+                len += 1;
+            }
+        }
+        let (front, back) = (guard.front, guard.back);
+        std::mem::forget(guard);
+        // SAFETY: `front..=back` are linked in order, and there are `len` of them.
+        Some(unsafe {
+            Self::new(
+                front,
+                back,
+                #[cfg(feature = "length")]
+                len,
+            )
         })
     }
-}
 
-impl<T> DetachedNodes<T> {
-    /// If is unsafe because it must be guaranteed that `front..=back` is
-    /// a valid range and its length must be equal to `len` (with
-    /// `#[cfg(feature = "length")]`).
-    unsafe fn new(
-        front: NonNull<Node<T>>,
-        back: NonNull<Node<T>>,
-        #[cfg(feature = "length")] len: usize,
-    ) -> Self {
-        let _marker = PhantomData;
+    /// Panics if the detached nodes is not a valid range (or its length is
+    /// not `len`), with `feature = "paranoid"`.
+    ///
+    /// Since `front.prev` and `back.next` is invalid, only the inner links are
+    /// checked.
+    #[inline]
+    pub(crate) fn check_range(&self) {
+        #[cfg(feature = "paranoid")]
+        {
+            let mut current = self.front;
+            #[cfg(feature = "length")]
+            let mut count = 1_usize;
+            while current != self.back {
+                // SAFETY: the nodes in `front..=back` are valid.
+                let next = unsafe { current.as_ref().next };
+                assert!(
+                    unsafe { next.as_ref().prev } == current,
+                    "Detached nodes are ill-formed at node {:p}",
+                    next
+                );
+                current = next;
+                #[cfg(feature = "length")]
+                {
+                    count += 1;
+                }
+            }
+            #[cfg(feature = "length")]
+            assert_eq!(count, self.len, "Length mismatches the detached nodes");
+        }
+    }
+}
+
+/// Return the generation of a newly allocated node.
+#[cfg(any(debug_assertions, feature = "paranoid"))]
+fn next_generation() -> usize {
+    static GENERATION: AtomicUsize = AtomicUsize::new(0);
+    GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+fn new_ghost_in<A: RawAlloc>(alloc: &A) -> NonNull<Node<Erased>> {
+    let ghost_ptr = Node::new_detached_in(Erased, alloc);
+    // SAFETY:
+    // - `ghost.next`, `ghost.prev` is initialized before using `ghost`.
+    // - `ghost.element` is never read, so it is erased out.
+    unsafe {
+        std::ptr::addr_of_mut!((*ghost_ptr.as_ptr()).next).write(ghost_ptr);
+        std::ptr::addr_of_mut!((*ghost_ptr.as_ptr()).prev).write(ghost_ptr);
+    }
+    ghost_ptr
+}
+
+/// Reverse the nodes in a range `start..end` in place by swapping their links.
+///
+/// It is unsafe because `start..end` must be a valid range of a list.
+pub(crate) unsafe fn reverse_nodes<T>(start: NonNull<Node<T>>, end: NonNull<Node<T>>) {
+    if start == end {
+        return;
+    }
+    let (before, back) = (start.as_ref().prev, end.as_ref().prev);
+    let mut node = start;
+    while node != end {
+        let current = &mut *node.as_ptr();
+        std::mem::swap(&mut current.prev, &mut current.next);
+        // The original next node is now `current.prev`.
+        node = current.prev;
+    }
+    connect(before, back);
+    connect(start, end);
+}
+
+/// Panics if `node` is not linked with its neighbours, with `debug_assertions`
+/// or `feature = "paranoid"`.
+///
+/// It is unsafe because `node`, `node.prev` and `node.next` must be valid.
+#[inline]
+#[cfg_attr(
+    not(any(debug_assertions, feature = "paranoid")),
+    allow(unused_variables)
+)]
+pub(crate) unsafe fn check_linked<T>(node: NonNull<Node<T>>) {
+    #[cfg(any(debug_assertions, feature = "paranoid"))]
+    {
+        let current = node.as_ref();
+        assert!(
+            current.prev.as_ref().next == node && current.next.as_ref().prev == node,
+            "Node {:p} is not linked with its neighbours",
+            node
+        );
+    }
+}
+
+pub(crate) unsafe fn connect<T>(mut prev: NonNull<Node<T>>, mut next: NonNull<Node<T>>) {
+    prev.as_mut().next = next;
+    next.as_mut().prev = prev;
+}
+
+/// Swap the positions of two nodes `a` and `b` in the same list by relinking,
+/// where nothing happens if they are the same node.
+///
+/// It is unsafe because `a` and `b` must be linked nodes of the same list, and
+/// neither of them can be the ghost node of a list with only one element.
+pub(crate) unsafe fn swap_linked<T>(a: NonNull<Node<T>>, b: NonNull<Node<T>>) {
+    if a == b {
+        return;
+    }
+    let (a_prev, a_next) = (a.as_ref().prev, a.as_ref().next);
+    let (b_prev, b_next) = (b.as_ref().prev, b.as_ref().next);
+    if a_next == b {
+        // a_prev <-> b <-> a <-> b_next
+        connect(a_prev, b);
+        connect(b, a);
+        connect(a, b_next);
+    } else if b_next == a {
+        // b_prev <-> a <-> b <-> a_next
+        connect(b_prev, a);
+        connect(a, b);
+        connect(b, a_next);
+    } else {
+        connect(a_prev, b);
+        connect(b, a_next);
+        connect(b_prev, a);
+        connect(a, b_next);
+    }
+}
+
+impl<T, A: RawAlloc> Drop for List<T, A> {
+    fn drop(&mut self) {
+        /// Frees the ghost node even if dropping an element panics.
+        struct Guard<'a, T, A: RawAlloc>(&'a mut List<T, A>);
+
+        impl<T, A: RawAlloc> Drop for Guard<'_, T, A> {
+            fn drop(&mut self) {
+                // SAFETY: the ghost node is allocated by `alloc`, and is never
+                // used after dropping the list.
+                unsafe { Node::dealloc_in(self.0.ghost, &self.0.alloc) };
+            }
+        }
+
+        let guard = Guard(self);
+        guard.0.clear();
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for List<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from_iter(array)
+    }
+}
+
+impl<T> From<Vec<T>> for List<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self::from_iter(vec)
+    }
+}
+
+impl<T> From<VecDeque<T>> for List<T> {
+    fn from(deque: VecDeque<T>) -> Self {
+        Self::from_iter(deque)
+    }
+}
+
+impl<T> From<LinkedList<T>> for List<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        Self::from_iter(list)
+    }
+}
+
+impl<T> From<List<T>> for Vec<T> {
+    fn from(list: List<T>) -> Self {
+        list.into_vec()
+    }
+}
+
+impl<T> From<List<T>> for VecDeque<T> {
+    fn from(list: List<T>) -> Self {
+        Self::from(list.into_vec())
+    }
+}
+
+unsafe impl<T: Send, A: RawAlloc + Send> Send for List<T, A> {}
+
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for List<T, A> {}
+
+// Ensure that `List` and its read-only iterators are covariant in their type parameters.
+#[allow(dead_code)]
+fn assert_covariance() {
+    fn a<'a>(x: List<&'static str>) -> List<&'a str> {
+        x
+    }
+    fn b<'i, 'a>(x: Iter<'i, &'static str>) -> Iter<'i, &'a str> {
+        x
+    }
+    fn c<'a>(x: IntoIter<&'static str>) -> IntoIter<&'a str> {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::list::alloc::{Global, RawAlloc};
+    use crate::list::cursor::{Cursor, Entry};
+    use crate::list::view::ListView;
+    use crate::list::List;
+    use std::alloc::Layout;
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashSet;
+    use std::fmt::Debug;
+    use std::iter::FromIterator;
+    use std::ptr::NonNull;
+    use std::rc::Rc;
+
+    #[test]
+    fn list_create() {
+        let mut list = List::<i32>::new();
+        assert!(list.is_empty());
+        list.push_back(1);
+        assert!(!list.is_empty());
+        assert_eq!(list.pop_back(), Some(1));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn list_drop() {
+        #[derive(Debug)]
+        struct DropChecker<'a, T: Copy> {
+            value: T,
+            dropped: &'a RefCell<Vec<T>>,
+        }
+        impl<'a, T: Copy> DropChecker<'a, T> {
+            fn new(value: T, dropped: &'a RefCell<Vec<T>>) -> Self {
+                Self { value, dropped }
+            }
+        }
+        impl<'a, T: Copy> Drop for DropChecker<'a, T> {
+            fn drop(&mut self) {
+                self.dropped.borrow_mut().push(self.value);
+            }
+        }
+        let dropped = RefCell::new(Vec::<i32>::new());
+        let mut list = List::new();
+        list.push_back(DropChecker::new(1, &dropped));
+        list.push_back(DropChecker::new(2, &dropped));
+        list.push_back(DropChecker::new(3, &dropped));
+        drop(list);
+        assert_eq!(dropped.borrow().as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn list_push_and_pop() {
+        let mut list = List::new();
+        assert!(list.is_empty());
+        #[cfg(feature = "length")]
+        assert_eq!(list.len(), 0);
+
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        list.push_back(1);
+        assert_eq!(list.back(), Some(&1));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+        #[cfg(feature = "length")]
+        assert_eq!(list.len(), 0);
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_back(3);
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), Some(3));
+
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+        assert!(list.is_empty());
+        #[cfg(feature = "length")]
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn list_insert_and_remove() {
+        fn list_eq<T, I>(list: &List<T>, expected: I)
+        where
+            T: Debug + Clone + Eq,
+            I: IntoIterator<Item = T>,
+        {
+            assert_eq!(
+                Vec::from_iter(list.iter().cloned()),
+                Vec::from_iter(expected)
+            );
+        }
+
+        let mut list = List::from_iter(0..10);
+        list.insert(5, 10);
+        list_eq(&list, (0..5).chain(Some(10)).chain(5..10));
+
+        assert_eq!(list.remove(10), 9);
+        assert_eq!(list.back(), Some(&8));
+        list_eq(&list, (0..5).chain(Some(10)).chain(5..9));
+
+        list.insert(0, 11);
+        assert_eq!(list.front(), Some(&11));
+        list_eq(&list, (11..=11).chain((0..5).chain(Some(10)).chain(5..9)));
+
+        assert_eq!(list.remove(0), 11);
+        assert_eq!(list.front(), Some(&0));
+        list_eq(&list, (0..5).chain(Some(10)).chain(5..9));
+
+        list.insert(10, 12);
+        assert_eq!(list.back(), Some(&12));
+        list_eq(&list, (0..5).chain(Some(10)).chain(5..9).chain(Some(12)));
+    }
+
+    #[test]
+    fn list_swap() {
+        let mut vec = Vec::from_iter(0..10);
+        let mut list = List::from_iter(0..10);
+        for (a, b) in [(0, 9), (9, 0), (3, 3), (2, 7), (8, 1), (4, 5)] {
+            vec.swap(a, b);
+            list.swap(a, b);
+            assert_eq!(list, List::from_iter(vec.iter().copied()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn list_swap_out_of_bounds() {
+        let mut list = List::from_iter(0..3);
+        list.swap(1, 3);
+    }
+
+    #[test]
+    fn list_min_max_cursor() {
+        fn test_list_min_max_cursor(
+            items: &[(i32, usize)],
+            min: Option<usize>,
+            max: Option<usize>,
+        ) {
+            let mut list = List::from_iter(items.iter().copied());
+            let position = |cursor: Cursor<'_, (i32, usize)>| cursor.current().unwrap().1;
+            assert_eq!(list.min_cursor_by_key(|&(k, _)| k).map(position), min);
+            assert_eq!(list.max_cursor_by_key(|&(k, _)| k).map(position), max);
+            #[cfg(feature = "length")]
+            {
+                assert_eq!(list.min_cursor_by_key(|&(k, _)| k).map(|c| c.index()), min);
+                assert_eq!(
+                    list.max_cursor_by_key_mut(|&(k, _)| k).map(|c| c.index()),
+                    max
+                );
+            }
+            if let Some(mut cursor) = list.min_cursor_by_key_mut(|&(k, _)| k) {
+                assert_eq!(cursor.remove().map(|(_, i)| i), min);
+            }
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), items.len().saturating_sub(1));
+        }
+        test_list_min_max_cursor(&[], None, None);
+        test_list_min_max_cursor(&[(1, 0)], Some(0), Some(0));
+        test_list_min_max_cursor(&[(2, 0), (1, 1), (3, 2), (1, 3), (3, 4)], Some(1), Some(4));
+        test_list_min_max_cursor(&[(5, 0), (4, 1), (3, 2)], Some(2), Some(0));
+        test_list_min_max_cursor(&[(7, 0), (7, 1), (7, 2)], Some(0), Some(2));
+    }
+
+    #[test]
+    fn list_rfind_cursor() {
+        let list = List::from_iter([3, 1, 4, 1, 5, 9, 2, 6]);
+        for (x, position) in [(3, Some(0)), (1, Some(3)), (6, Some(7)), (7, None)] {
+            let cursor = list.rfind_cursor(|&y| y == x);
+            assert_eq!(
+                cursor.as_ref().and_then(|c| c.current()),
+                position.map(|_| &x)
+            );
+            #[cfg(feature = "length")]
+            assert_eq!(cursor.map(|c| c.index()), position);
+        }
+    }
+
+    #[test]
+    fn list_insert_sorted() {
+        for len in 0..5 {
+            for x in 0..=len {
+                let mut list = List::from_iter((0..len).map(|i| i * 2 + 1));
+                let cursor = list.insert_sorted(x * 2);
+                assert_eq!(cursor.current(), Some(&(x * 2)));
+                #[cfg(feature = "length")]
+                assert_eq!(cursor.index(), x);
+                let mut expected = Vec::from_iter((0..len).map(|i| i * 2 + 1));
+                expected.insert(x, x * 2);
+                assert!(list.iter().eq(expected.iter()));
+                assert!(list.iter().rev().eq(expected.iter().rev()));
+            }
+        }
+
+        // Equal elements are inserted after the existing ones.
+        let mut list = List::from_iter([(1, 'a'), (2, 'b'), (2, 'c')]);
+        let cursor = list.insert_sorted_by((2, 'd'), |a, b| a.0.cmp(&b.0));
         #[cfg(feature = "length")]
-        debug_assert!(len > 0, "Cannot detach nodes of length 0");
-        Self {
-            front,
-            back,
+        assert_eq!(cursor.index(), 3);
+        assert_eq!(cursor.current(), Some(&(2, 'd')));
+        assert_eq!(list.back(), Some(&(2, 'd')));
+    }
+
+    #[test]
+    fn list_search_by() {
+        let list = List::<i32>::new();
+        let cursor = list.search_by(|x| x.cmp(&0)).unwrap_err();
+        assert_eq!(cursor.current(), None);
+
+        let list = List::from_iter([1, 3, 3, 5]);
+        for (target, found, index) in [
+            (0, false, 0),
+            (1, true, 0),
+            (2, false, 1),
+            (3, true, 1),
+            (5, true, 3),
+            (6, false, 4),
+        ] {
+            let result = list.search_by(|x| x.cmp(&target));
+            assert_eq!(result.is_ok(), found);
+            let cursor = result.unwrap_or_else(|cursor| cursor);
+            assert_eq!(cursor.current(), list.iter().nth(index));
             #[cfg(feature = "length")]
-            len,
-            _marker,
+            assert_eq!(cursor.index(), index);
+        }
+
+        // Inserting at the ghost node appends to the back.
+        let mut list = List::from_iter([1]);
+        list.search_by_mut(|x| x.cmp(&2)).unwrap_err().insert(2);
+        list.search_by_mut(|x| x.cmp(&0)).unwrap_err().insert(0);
+        assert!(list.iter().copied().eq(0..3));
+        assert!(list.iter().rev().copied().eq((0..3).rev()));
+    }
+
+    #[test]
+    fn list_dedup_by() {
+        let mut list = List::<i32>::new();
+        list.dedup();
+        assert!(list.is_empty());
+
+        let mut list = List::from_iter([1]);
+        list.dedup();
+        assert_eq!(list.to_vec(), vec![1]);
+
+        let mut list = List::from_iter([2, 2, 2, 2]);
+        list.dedup();
+        assert_eq!(list.to_vec(), vec![2]);
+
+        // Merge the counts of equal keys into their first occurrence.
+        let mut list = List::from_iter([(1, 1), (1, 2), (2, 3), (2, 4), (2, 5), (1, 6)]);
+        list.dedup_by(|a, b| {
+            a.0 == b.0 && {
+                b.1 += a.1;
+                true
+            }
+        });
+        assert_eq!(list.to_vec(), vec![(1, 3), (2, 12), (1, 6)]);
+        assert!(list.iter().rev().eq([(1, 6), (2, 12), (1, 3)].iter()));
+        #[cfg(feature = "length")]
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn list_group_by() {
+        fn test_list_group_by(items: &[i32], groups: &[&[i32]]) {
+            let list = List::from_iter(items.iter().copied());
+            let result = list.group_by(|a, b| a / 10 == b / 10);
+            assert!(result
+                .iter()
+                .map(|l| l.to_vec())
+                .eq(groups.iter().map(|g| g.to_vec())));
+            for (group, expected) in result.iter().rev().zip(groups.iter().rev()) {
+                assert!(group.iter().rev().eq(expected.iter().rev()));
+                #[cfg(feature = "length")]
+                assert_eq!(group.len(), expected.len());
+            }
+            #[cfg(feature = "length")]
+            assert_eq!(result.len(), groups.len());
+        }
+        test_list_group_by(&[], &[]);
+        test_list_group_by(&[1], &[&[1]]);
+        test_list_group_by(&[1, 2, 3], &[&[1, 2, 3]]);
+        test_list_group_by(&[1, 12, 23], &[&[1], &[12], &[23]]);
+        test_list_group_by(&[1, 2, 13, 14, 5, 36], &[&[1, 2], &[13, 14], &[5], &[36]]);
+    }
+
+    #[test]
+    fn list_split_when() {
+        fn test_list_split_when(items: &[i32], pieces: &[&[i32]], inclusive: &[&[i32]]) {
+            let check = |result: Vec<List<i32>>, expected: &[&[i32]]| {
+                assert_eq!(result.len(), expected.len());
+                for (piece, expected) in result.iter().zip(expected) {
+                    assert!(piece.iter().eq(expected.iter()));
+                    assert!(piece.iter().rev().eq(expected.iter().rev()));
+                    #[cfg(feature = "length")]
+                    assert_eq!(piece.len(), expected.len());
+                }
+            };
+            let list = List::from_iter(items.iter().copied());
+            check(list.clone().split_when(|&x| x == 0), pieces);
+            check(list.split_when_inclusive(|&x| x == 0), inclusive);
+        }
+        test_list_split_when(&[], &[&[]], &[]);
+        test_list_split_when(&[0], &[&[], &[]], &[&[0]]);
+        test_list_split_when(&[1, 2], &[&[1, 2]], &[&[1, 2]]);
+        test_list_split_when(
+            &[0, 1, 0, 0, 2],
+            &[&[], &[1], &[], &[2]],
+            &[&[0], &[1, 0], &[0], &[2]],
+        );
+        test_list_split_when(&[1, 0, 2, 0], &[&[1], &[2], &[]], &[&[1, 0], &[2, 0]]);
+    }
+
+    #[test]
+    fn list_interleave() {
+        fn test_list_interleave(m: usize, n: usize) {
+            let mut expected = Vec::new();
+            for i in 0..m.max(n) {
+                expected.extend((i < m).then_some(i));
+                expected.extend((i < n).then_some(100 + i));
+            }
+            let list = List::from_iter(0..m).interleave(List::from_iter(100..100 + n));
+            assert!(list.iter().eq(expected.iter()));
+            assert!(list.iter().rev().eq(expected.iter().rev()));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), m + n);
+        }
+        for (m, n) in [
+            (0, 0),
+            (0, 3),
+            (3, 0),
+            (1, 1),
+            (3, 3),
+            (2, 5),
+            (5, 2),
+            (1, 4),
+        ] {
+            test_list_interleave(m, n);
+        }
+    }
+
+    /// Records its value in the shared log when it is dropped.
+    struct Dropped<'a>(&'a RefCell<Vec<i64>>, i64);
+
+    impl Drop for Dropped<'_> {
+        fn drop(&mut self) {
+            self.0.borrow_mut().push(self.1);
+        }
+    }
+
+    #[test]
+    fn list_map() {
+        // The nodes are reused if the layouts are the same.
+        let list = List::from_iter(0..10u64);
+        let addresses = Vec::from_iter(list.iter().map(|x| x as *const u64 as usize));
+        let list = list.map(|x| x as f64 * 0.5);
+        assert!(list.iter().map(|x| x as *const f64 as usize).eq(addresses));
+        assert!(list
+            .iter()
+            .rev()
+            .copied()
+            .eq((0..10).rev().map(|x| x as f64 * 0.5)));
+        #[cfg(feature = "length")]
+        assert_eq!(list.len(), 10);
+
+        // All the elements are dropped if `f` panics.
+        let dropped = RefCell::new(Vec::new());
+        let list = List::from_iter((0..10).map(|i| Dropped(&dropped, i)));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.map(|x| {
+                if x.1 == 5 {
+                    panic!("map panics");
+                }
+                Dropped(x.0, -x.1)
+            })
+        }));
+        assert!(result.is_err());
+        let mut dropped = dropped.borrow().clone();
+        dropped.sort_unstable();
+        // 0..5 are mapped to -4..=0 (and their old values dropped), and
+        // 5..10 are dropped without being mapped.
+        assert_eq!(dropped, Vec::from_iter((-4..=0).chain(0..10)));
+    }
+
+    #[test]
+    fn list_try_map() {
+        let dropped = RefCell::new(Vec::new());
+        let list = List::from_iter((0..10).map(|i| Dropped(&dropped, i)));
+        let result = list.try_map(|x| match x.1 {
+            5 => Err(x.1),
+            _ => Ok(Dropped(x.0, -x.1)),
+        });
+        assert_eq!(result.err(), Some(5));
+        let mut dropped = dropped.borrow().clone();
+        dropped.sort_unstable();
+        assert_eq!(dropped, Vec::from_iter((-4..=0).chain(0..10)));
+
+        let list = List::from_iter(0..10u32);
+        let mapped = list.try_map(|x| Ok::<_, ()>(x as i32 - 5)).unwrap();
+        assert!(mapped.iter().rev().copied().eq((-5..5).rev()));
+        #[cfg(feature = "length")]
+        assert_eq!(mapped.len(), 10);
+    }
+
+    #[test]
+    fn list_drain_matching_into() {
+        let mut list = List::from_iter(0..20);
+        let mut target = List::from_iter([-1]);
+        list.drain_matching_into(|x| *x % 3 != 1, &mut target);
+        assert!(list
+            .iter()
+            .rev()
+            .copied()
+            .eq((0..20).filter(|x| x % 3 == 1).rev()));
+        let expected = Vec::from_iter(Some(-1).into_iter().chain((0..20).filter(|x| x % 3 != 1)));
+        assert!(target.iter().rev().eq(expected.iter().rev()));
+        #[cfg(feature = "length")]
+        {
+            assert_eq!(list.len(), 7);
+            assert_eq!(target.len(), expected.len());
+        }
+    }
+
+    #[test]
+    fn list_drain_filter_range() {
+        for len in 0..5 {
+            for start in 0..=len {
+                for end in start..=len {
+                    let drained_at = |x: &usize| (start..end).contains(x) && x % 2 == 1;
+                    let mut list = List::from_iter(0..len);
+                    let drained =
+                        Vec::from_iter(list.drain_filter_range(start..end, |x| *x % 2 == 1));
+                    assert!(drained.iter().copied().eq((0..len).filter(drained_at)));
+                    let kept = Vec::from_iter((0..len).filter(|x| !drained_at(x)));
+                    assert!(list.iter().eq(kept.iter()));
+                    assert!(list.iter().rev().eq(kept.iter().rev()));
+                    #[cfg(feature = "length")]
+                    assert_eq!(list.len(), kept.len());
+                }
+            }
+        }
+
+        // Ranges touching the ghost node.
+        let mut list = List::from_iter(0..6);
+        assert_eq!(list.drain_filter_range(..0, |_| true).next(), None);
+        assert_eq!(list.drain_filter_range(6.., |_| true).next(), None);
+        assert!(list.drain_filter_range(4.., |_| true).eq(4..6));
+        assert!(list.drain_filter_range(..=1, |_| true).eq(0..2));
+        assert!(list.drain_filter_range(.., |_| true).eq(2..4));
+        assert!(list.is_empty());
+
+        let mut list = List::<i32>::new();
+        assert_eq!(list.drain_filter_range(.., |_| true).next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "length")]
+    #[should_panic(expected = "Range end 4 is out of range for list of length 3")]
+    fn list_drain_filter_range_out_of_bounds() {
+        let mut list = List::from_iter(0..3);
+        list.drain_filter_range(1..4, |_| true);
+    }
+
+    #[test]
+    fn list_unique() {
+        fn test_list_unique(items: &[i32], unique: &[i32], unique_by_abs: &[i32]) {
+            let check = |list: List<i32>, expected: &[i32]| {
+                assert!(list.iter().eq(expected.iter()));
+                assert!(list.iter().rev().eq(expected.iter().rev()));
+                #[cfg(feature = "length")]
+                assert_eq!(list.len(), expected.len());
+            };
+            let mut list = List::from_iter(items.iter().copied());
+            list.unique();
+            check(list, unique);
+            let mut list = List::from_iter(items.iter().copied());
+            list.unique_by_key(|x| x.abs());
+            check(list, unique_by_abs);
+        }
+        test_list_unique(&[], &[], &[]);
+        test_list_unique(&[1, 1, 1], &[1], &[1]);
+        test_list_unique(&[1, -1, 2, 1, -2, 3], &[1, -1, 2, -2, 3], &[1, 2, 3]);
+        test_list_unique(&[-3, 2, 3, -3, 2], &[-3, 2, 3], &[-3, 2]);
+    }
+
+    #[test]
+    fn list_reorder_by_indices() {
+        let mut list = List::from_iter(0..6);
+        list.reorder_by_indices(&[5, 3, 1, 0, 2, 4]);
+        assert!(list.iter().eq(&[5, 3, 1, 0, 2, 4]));
+        assert!(list.iter().rev().eq(&[4, 2, 0, 1, 3, 5]));
+        list.reorder_by_indices(&[3, 2, 4, 1, 5, 0]);
+        assert!(list.iter().copied().eq(0..6));
+
+        let mut empty = List::<i32>::new();
+        empty.reorder_by_indices(&[]);
+        assert!(empty.is_empty());
+
+        for order in [&[0, 1, 1][..], &[0, 1, 3], &[0, 1], &[0, 1, 2, 3]] {
+            let mut list = List::from_iter(0..3);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.reorder_by_indices(order)
+            }));
+            assert!(result.is_err());
+            assert!(list.iter().copied().eq(0..3));
+            assert!(list.iter().rev().copied().eq((0..3).rev()));
+        }
+    }
+
+    #[test]
+    fn list_remove_every_nth() {
+        fn test_list_remove_every_nth(len: usize, n: usize) {
+            let mut vec = Vec::from_iter(0..len);
+            let mut expected = Vec::new();
+            let mut at = 0;
+            while !vec.is_empty() {
+                at = (at + n - 1) % vec.len();
+                expected.push(vec.remove(at));
+            }
+            let mut list = List::from_iter(0..len);
+            let removed = list.remove_every_nth(n);
+            #[cfg(feature = "length")]
+            assert_eq!(removed.len(), len);
+            assert_eq!(Vec::from_iter(removed), expected);
+            assert!(list.is_empty());
+        }
+        for len in 0..8 {
+            for n in 1..10 {
+                test_list_remove_every_nth(len, n);
+            }
+        }
+    }
+
+    #[test]
+    fn list_canonicalize_rotation() {
+        fn test_list_canonicalize_rotation(items: &[u8]) {
+            let n = items.len();
+            let rotation = |k: usize| Vec::from_iter(items[k..].iter().chain(&items[..k]).copied());
+            let least = (0..n).min_by_key(|&k| rotation(k)).unwrap_or(0);
+            let mut list = List::from_iter(items.iter().copied());
+            assert_eq!(list.canonicalize_rotation(), least);
+            let expected = rotation(least);
+            assert!(list.iter().eq(expected.iter()));
+            assert!(list.iter().rev().eq(expected.iter().rev()));
+        }
+        test_list_canonicalize_rotation(&[]);
+        test_list_canonicalize_rotation(&[1]);
+        test_list_canonicalize_rotation(&[1, 1, 1]);
+        test_list_canonicalize_rotation(&[2, 1, 2, 1]);
+        // All the sequences of length 0..=8 over the alphabet {0, 1, 2}.
+        for len in 0..=8 {
+            for code in 0..3usize.pow(len) {
+                let items = Vec::from_iter((0..len).map(|i| (code / 3usize.pow(i) % 3) as u8));
+                test_list_canonicalize_rotation(&items);
+            }
+        }
+    }
+
+    #[test]
+    fn list_node_handle() {
+        let mut list = List::from_iter([3, 1, 2]);
+        let handles = Vec::from_iter((0..3).map(|i| list.cursor(i).handle().unwrap()));
+        assert!(list.cursor_end().handle().is_none());
+
+        // Handles survive relinking and edits of other elements.
+        list.sort();
+        list.push_front(0);
+        list.cursor_mut(4).insert(4);
+        assert!(handles.iter().all(|handle| list.contains_handle(handle)));
+        assert_eq!(list.find_handle(|&x| x == 1), Some(handles[1]));
+        assert_eq!(list.find_handle(|&x| x > 4), None);
+
+        let mut cursor = list.cursor_mut(3);
+        let handle = cursor.insert_with_handle(5);
+        assert_eq!(cursor.remove(), Some(3));
+        assert!(!list.contains_handle(&handles[0]));
+        assert!(list.contains_handle(&handles[1]));
+        assert!(list.contains_handle(&handle));
+        assert_eq!(Vec::from_iter(list.iter().copied()), vec![0, 1, 2, 5, 4]);
+
+        let other = list.clone();
+        assert!(!other.contains_handle(&handle));
+
+        for (i, handle) in handles.iter().enumerate().skip(1) {
+            // SAFETY: the elements of the handles are in the list.
+            let cursor = unsafe { list.cursor_at(handle) };
+            assert_eq!(cursor.current(), Some(&i));
+            #[cfg(feature = "length")]
+            assert_eq!(cursor.index(), i);
+        }
+        // SAFETY: the element of the handle is in the list.
+        let mut cursor = unsafe { list.cursor_mut_at(&handle) };
+        #[cfg(feature = "length")]
+        assert_eq!(cursor.index(), 3);
+        assert_eq!(cursor.remove(), Some(5));
+        assert_eq!(cursor.current(), Some(&4));
+    }
+
+    #[test]
+    #[cfg(feature = "length")]
+    fn list_cursor_at_lazy_index() {
+        for len in 1..6 {
+            for at in 0..len {
+                let mut list = List::from_iter(0..len);
+                let handle = list.cursor(at).handle().unwrap();
+                // SAFETY: the element of the handle is in the list.
+                let mut cursor = unsafe { list.cursor_mut_at(&handle) };
+                // Edit and move before the index is ever computed.
+                cursor.push_front(len);
+                cursor.push_back(len);
+                cursor.insert(len);
+                assert_eq!(cursor.index(), at + 2);
+                cursor.move_next_cyclic();
+                assert_eq!(cursor.index(), at + 3);
+                assert!(cursor.try_seek_to(0).is_ok());
+                assert_eq!(cursor.current(), Some(&len));
+
+                // SAFETY: the element of the handle is in the list.
+                let mut cursor = unsafe { list.cursor_mut_at(&handle) };
+                let tail = cursor.split().unwrap();
+                assert_eq!(tail.len(), len + 1 - at);
+                assert_eq!(list.len(), at + 2);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "paranoid"))]
+    fn list_node_handle_reused_memory() {
+        let mut list = List::from_iter([1]);
+        let old = list.cursor(0).handle().unwrap();
+        list.pop_front();
+        // The new node may or may not reuse the memory of the removed one.
+        list.push_front(2);
+        let new = list.cursor(0).handle().unwrap();
+        assert_ne!(old, new);
+        assert!(!list.contains_handle(&old));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Cannot locate a handle whose element is not in the list")]
+    fn list_cursor_at_removed_handle() {
+        let mut list = List::from_iter(0..3);
+        let handle = list.cursor(1).handle().unwrap();
+        list.cursor_mut(1).remove();
+        // The removed node is freed, and it is never read by the check.
+        let _ = unsafe { list.cursor_at(&handle) };
+    }
+
+    #[test]
+    fn list_swap_nodes() {
+        for len in 1..6 {
+            for a in 0..len {
+                for b in 0..len {
+                    let mut list = List::from_iter(0..len);
+                    let handle = list.cursor(a).handle().unwrap();
+                    list.swap_nodes(a, b);
+                    let mut expected = Vec::from_iter(0..len);
+                    expected.swap(a, b);
+                    assert_eq!(Vec::from_iter(list.iter().copied()), expected);
+                    assert!(list.iter().rev().copied().eq(expected.into_iter().rev()));
+                    // SAFETY: the element of the handle is in the list.
+                    let cursor = unsafe { list.cursor_at(&handle) };
+                    assert_eq!(cursor.current(), Some(&a));
+                    #[cfg(feature = "length")]
+                    assert_eq!(cursor.index(), b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn list_reverse_range() {
+        for len in 0..6 {
+            for start in 0..=len {
+                for end in start..=len {
+                    let mut list = List::from_iter(0..len);
+                    list.reverse_range(start..end);
+                    let mut expected = Vec::from_iter(0..len);
+                    expected[start..end].reverse();
+                    assert_eq!(Vec::from_iter(list.iter().copied()), expected);
+                    assert!(list.iter().rev().copied().eq(expected.into_iter().rev()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn list_fill_range() {
+        for len in 0..5 {
+            for start in 0..=len {
+                for end in start..=len {
+                    let mut list = List::from_iter(0..len);
+                    list.fill_range(start..end, len);
+                    let mut expected = Vec::from_iter(0..len);
+                    expected[start..end].fill(len);
+                    assert_eq!(Vec::from_iter(list.iter().copied()), expected);
+
+                    let mut visited = Vec::new();
+                    list.for_each_mut_range(start.., |x| {
+                        visited.push(*x);
+                        *x += 1;
+                    });
+                    assert_eq!(visited, expected[start..]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn list_clone_range() {
+        let list = List::from_iter((0..6).map(|i| i.to_string()));
+        for start in 0..=6 {
+            for end in start..=6 {
+                let cloned = list.clone_range(start..end);
+                #[cfg(feature = "length")]
+                assert_eq!(cloned.len(), end - start);
+                assert!(cloned
+                    .iter()
+                    .eq(&Vec::from_iter(list.iter().cloned())[start..end]));
+            }
+        }
+        assert_eq!(list.clone_range(..), list);
+        assert_eq!(list.clone_range(4..), list.clone_range(4..=5));
+    }
+
+    #[test]
+    fn list_view() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<H: Hash>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let list = List::from_iter(0..6);
+        for start in 0..=6 {
+            for end in start..=6 {
+                let view = list.view(start..end);
+                let expected = List::from_iter(start..end);
+                #[cfg(feature = "length")]
+                assert_eq!(view.len(), end - start);
+                assert_eq!(view.is_empty(), start == end);
+                assert_eq!(view.front(), expected.front());
+                assert_eq!(view.back(), expected.back());
+                assert!(view.iter().rev().eq(expected.iter().rev()));
+                assert_eq!(view, expected);
+                assert_eq!(expected, view);
+                assert_eq!(view, ListView::from(&expected));
+                assert_eq!(view, list.cursor(start).view_to(&list.cursor(end)));
+                assert_eq!(hash(&view), hash(&expected));
+                assert_eq!(view.to_list(), expected);
+                assert_eq!(view.contains(&start), start < end);
+            }
+        }
+        assert!(list.view(..2) < list.view(1..));
+        assert!(list.view(..2) > list.view(..1));
+        assert_eq!(format!("{:?}", list.view(4..)), "[4, 5]");
+    }
+
+    #[test]
+    fn list_view_mut() {
+        let mut list = List::from_iter(0..6);
+        for start in 0..=6 {
+            for end in start..=6 {
+                let mut view = list.view_mut(start..end);
+                #[cfg(feature = "length")]
+                assert_eq!(view.len(), end - start);
+                assert_eq!(view.is_empty(), start == end);
+                assert_eq!(view.as_view(), List::from_iter(start..end));
+                if let Some(front) = view.front_mut() {
+                    *front += 10;
+                }
+                if let Some(back) = view.back_mut() {
+                    *back += 10;
+                }
+                for i in 0..(end - start) / 2 {
+                    view.swap(i, end - start - 1 - i);
+                }
+                view.iter_mut().for_each(|x| *x %= 10);
+                assert!(view.iter().copied().eq((start..end).rev()));
+                list.reverse_range(start..end);
+                assert!(list.iter().copied().eq(0..6));
+            }
+        }
+        let mut view = list.view_mut(2..5);
+        view.fill_with(Default::default);
+        assert_eq!(view.into_view(), List::from_iter([0, 0, 0]));
+        assert_eq!(format!("{:?}", list.view_mut(1..3)), "[1, 0]");
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot swap at an index outside of the view bounds")]
+    fn list_view_mut_swap_out_of_bounds() {
+        List::from_iter(0..5).view_mut(1..3).swap(0, 2);
+    }
+
+    #[test]
+    fn list_iter_range() {
+        let list = List::from_iter(0..6);
+        for start in 0..=6 {
+            for end in start..=6 {
+                let iter = list.iter_range(start..end);
+                #[cfg(feature = "length")]
+                assert_eq!(iter.size_hint(), (end - start, Some(end - start)));
+                assert!(iter.clone().copied().eq(start..end));
+                assert!(iter.rev().copied().eq((start..end).rev()));
+            }
+        }
+        assert!(list.iter_range(..=2).copied().eq(0..3));
+        assert!(list.iter_range(3..).copied().eq(3..6));
+    }
+
+    #[test]
+    fn list_iter_cyclic_from() {
+        for len in 0..5 {
+            let list = List::from_iter(0..len);
+            for at in 0..=len {
+                let iter = list.iter_cyclic_from(at);
+                #[cfg(feature = "length")]
+                assert_eq!(iter.len(), len);
+                let expected = Vec::from_iter((at..len).chain(0..at));
+                assert!(iter.clone().copied().eq(expected.iter().copied()));
+                assert!(iter.rev().copied().eq(expected.into_iter().rev()));
+            }
+        }
+    }
+
+    #[test]
+    fn list_cycle_iter() {
+        for len in 1..5 {
+            let list = List::from_iter(0..len);
+            assert!(list
+                .cycle_iter()
+                .copied()
+                .take(20)
+                .eq((0..20).map(|i| i % len)));
+        }
+        assert_eq!(List::<usize>::new().cycle_iter().next(), None);
+    }
+
+    #[test]
+    fn list_iter_with_cursors() {
+        for len in 0..5 {
+            let list = List::from_iter(0..len);
+            let iter = list.iter_with_cursors();
+            #[cfg(feature = "length")]
+            assert_eq!(iter.len(), len);
+            let mut count = 0;
+            for (i, (cursor, item)) in iter.enumerate() {
+                assert_eq!(*item, i);
+                assert_eq!(cursor.current(), Some(item));
+                assert_eq!(cursor, list.cursor(i));
+                count += 1;
+            }
+            assert_eq!(count, len);
+        }
+    }
+
+    #[test]
+    fn list_ends_mut() {
+        assert!(List::<i32>::new().ends_mut().is_none());
+        assert!(List::from_iter([1]).ends_mut().is_none());
+        let mut list = List::from_iter([1, 2]);
+        let (front, back) = list.ends_mut().unwrap();
+        *front += 10;
+        *back += 20;
+        assert_eq!(list.to_vec(), vec![11, 22]);
+    }
+
+    #[test]
+    fn list_pop_n() {
+        for len in 0..5 {
+            for n in 0..=len + 1 {
+                let k = n.min(len);
+                let mut list = List::from_iter(0..len);
+                let popped = list.pop_front_n(n);
+                assert!(popped.iter().copied().eq(0..k));
+                assert!(popped.iter().rev().copied().eq((0..k).rev()));
+                assert!(list.iter().copied().eq(k..len));
+                assert!(list.iter().rev().copied().eq((k..len).rev()));
+                #[cfg(feature = "length")]
+                assert_eq!((popped.len(), list.len()), (k, len - k));
+
+                let mut list = List::from_iter(0..len);
+                let popped = list.pop_back_n(n);
+                assert!(popped.iter().copied().eq(len - k..len));
+                assert!(popped.iter().rev().copied().eq((len - k..len).rev()));
+                assert!(list.iter().copied().eq(0..len - k));
+                assert!(list.iter().rev().copied().eq((0..len - k).rev()));
+                #[cfg(feature = "length")]
+                assert_eq!((popped.len(), list.len()), (k, len - k));
+            }
+        }
+    }
+
+    #[test]
+    fn list_take_front() {
+        for len in 0..5 {
+            for at in 0..=len {
+                let mut list = List::from_iter(0..len);
+                let front = list.take_front(at);
+                assert!(front.iter().copied().eq(0..at));
+                assert!(front.iter().rev().copied().eq((0..at).rev()));
+                assert!(list.iter().copied().eq(at..len));
+                assert!(list.iter().rev().copied().eq((at..len).rev()));
+                #[cfg(feature = "length")]
+                assert_eq!((front.len(), list.len()), (at, len - at));
+            }
+        }
+    }
+
+    #[test]
+    fn list_entry_by() {
+        for len in 0..5 {
+            for key in 0..=2 * len {
+                // Only the even numbers are in the list.
+                let mut list = List::from_iter((0..len).map(|i| 2 * i));
+                let entry = list.entry_by(|x| x.cmp(&key));
+                assert_eq!(
+                    matches!(entry, Entry::Occupied(_)),
+                    key % 2 == 0 && key < 2 * len
+                );
+                assert_eq!(*entry.or_insert(key), key);
+                let mut expected = Vec::from_iter((0..len).map(|i| 2 * i));
+                if let Err(i) = expected.binary_search(&key) {
+                    expected.insert(i, key);
+                }
+                assert_eq!(list.to_vec(), expected);
+                assert!(list.iter().rev().eq(expected.iter().rev()));
+                #[cfg(feature = "length")]
+                assert_eq!(list.len(), expected.len());
+
+                let cursor = list.lower_bound_mut(|x| x.cmp(&key));
+                assert_eq!(cursor.current(), Some(&key));
+            }
         }
     }
-}
 
-fn new_ghost() -> Box<Node<Erased>> {
-    let ghost_ptr = Node::new_detached(Erased::default());
-    // SAFETY:
-    // - `ghost.next`, `ghost.prev` is initialized immediately after creating `ghost`.
-    // - `ghost.element` is never read, so it is erased out.
-    let mut ghost = unsafe { Box::from_raw(ghost_ptr.as_ptr()) };
-    ghost.next = ghost_ptr;
-    ghost.prev = ghost_ptr;
-    ghost
-}
+    #[test]
+    fn list_repeat_and_from_fn() {
+        for n in 0..5 {
+            let list = List::repeat(Rc::new(0), n);
+            assert_eq!(list.iter().count(), n);
+            assert!(list.iter().rev().all(|x| **x == 0));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), n);
+            // The value is moved into the list rather than cloned once more.
+            if let Some(x) = list.front() {
+                assert_eq!(Rc::strong_count(x), n);
+            }
 
-pub(crate) unsafe fn connect<T>(mut prev: NonNull<Node<T>>, mut next: NonNull<Node<T>>) {
-    prev.as_mut().next = next;
-    next.as_mut().prev = prev;
-}
+            let mut i = 0;
+            let list = List::repeat_with(n, || {
+                i += 1;
+                i
+            });
+            assert!(list.iter().copied().eq(1..=n));
+            assert!(list.iter().rev().copied().eq((1..=n).rev()));
 
-impl<T> Drop for List<T> {
-    fn drop(&mut self) {
-        self.clear();
+            let list = List::from_fn(n, |i| i * 2);
+            assert!(list.iter().copied().eq((0..n).map(|i| i * 2)));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), n);
+        }
     }
-}
 
-impl<T, const N: usize> From<[T; N]> for List<T> {
-    fn from(array: [T; N]) -> Self {
-        Self::from_iter(array)
-    }
-}
+    #[test]
+    fn list_from_collections() {
+        use std::collections::{LinkedList, VecDeque};
 
-unsafe impl<T: Send> Send for List<T> {}
+        for len in 0..5 {
+            let expected = List::from_iter(0..len);
+            assert_eq!(List::from(Vec::from_iter(0..len)), expected);
+            assert_eq!(List::from(VecDeque::from_iter(0..len)), expected);
+            assert_eq!(List::from(LinkedList::from_iter(0..len)), expected);
+            assert!(List::from(Vec::from_iter(0..len))
+                .iter()
+                .rev()
+                .copied()
+                .eq((0..len).rev()));
 
-unsafe impl<T: Sync> Sync for List<T> {}
+            assert_eq!(Vec::from(expected.clone()), Vec::from_iter(0..len));
+            assert_eq!(VecDeque::from(expected), VecDeque::from_iter(0..len));
+        }
 
-// Ensure that `List` and its read-only iterators are covariant in their type parameters.
-#[allow(dead_code)]
-fn assert_covariance() {
-    fn a<'a>(x: List<&'static str>) -> List<&'a str> {
-        x
-    }
-    fn b<'i, 'a>(x: Iter<'i, &'static str>) -> Iter<'i, &'a str> {
-        x
-    }
-    fn c<'a>(x: IntoIter<&'static str>) -> IntoIter<&'a str> {
-        x
+        let mut list = List::from([0, 1]);
+        let mut deque = VecDeque::from_iter(3..5);
+        deque.push_front(2);
+        list.extend_from_deque(&mut deque);
+        assert!(deque.is_empty());
+        assert!(list.iter().copied().eq(0..5));
+        assert!(list.iter().rev().copied().eq((0..5).rev()));
+        #[cfg(feature = "length")]
+        assert_eq!(list.len(), 5);
+
+        assert_eq!(List::from([0; 0]), List::new());
+        assert_eq!(List::from([1]), List::from_iter([1]));
+        let list = List::from([5, 4, 1, 3, 2]);
+        assert!(list.iter().copied().eq([5, 4, 1, 3, 2]));
+        assert!(list.iter().rev().copied().eq([2, 3, 1, 4, 5]));
+        #[cfg(feature = "length")]
+        assert_eq!(list.len(), 5);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::list::List;
-    use std::cell::RefCell;
-    use std::fmt::Debug;
-    use std::iter::FromIterator;
+    #[test]
+    fn list_into_vec() {
+        for len in 0..5 {
+            let list = List::from_iter(0..len);
+            let vec = list.to_vec();
+            assert_eq!(vec, Vec::from_iter(0..len));
+            #[cfg(feature = "length")]
+            assert!(vec.capacity() >= len);
+
+            let vec = list.into_vec();
+            assert_eq!(vec, Vec::from_iter(0..len));
+            #[cfg(feature = "length")]
+            assert!(vec.capacity() >= len);
+        }
+    }
 
     #[test]
-    fn list_create() {
-        let mut list = List::<i32>::new();
-        assert!(list.is_empty());
-        list.push_back(1);
-        assert!(!list.is_empty());
-        assert_eq!(list.pop_back(), Some(1));
-        assert!(list.is_empty());
+    fn list_debug() {
+        let list = List::from_iter(0..5);
+        assert_eq!(format!("{:?}", list), "[0, 1, 2, 3, 4]");
+        assert_eq!(format!("{:#?}", List::from([1, 2])), "[\n    1,\n    2,\n]");
+
+        let truncated = |k| format!("{:?}", list.debug_truncated(k));
+        assert_eq!(truncated(0), "[..]");
+        assert_eq!(truncated(1), "[0, .., 4]");
+        assert_eq!(truncated(2), "[0, 1, .., 3, 4]");
+        assert_eq!(truncated(3), "[0, 1, 2, 3, 4]");
+        assert_eq!(truncated(usize::MAX), "[0, 1, 2, 3, 4]");
+        assert_eq!(format!("{:?}", List::<i32>::new().debug_truncated(0)), "[]");
+
+        let indexed = |k| format!("{:?}", list.debug_truncated(k).with_indices());
+        assert_eq!(indexed(2), "[0: 0, 1: 1, .., 3: 3, 4: 4]");
+        assert_eq!(indexed(3), "[0: 0, 1: 1, 2: 2, 3: 3, 4: 4]");
+        assert_eq!(
+            format!(
+                "{:#?}",
+                List::from(["a", "b", "c"])
+                    .debug_truncated(1)
+                    .with_indices()
+            ),
+            "[\n    0: \"a\",\n    ..,\n    2: \"c\",\n]"
+        );
     }
 
     #[test]
-    fn list_drop() {
-        #[derive(Debug)]
-        struct DropChecker<'a, T: Copy> {
-            value: T,
-            dropped: &'a RefCell<Vec<T>>,
-        }
-        impl<'a, T: Copy> DropChecker<'a, T> {
-            fn new(value: T, dropped: &'a RefCell<Vec<T>>) -> Self {
-                Self { value, dropped }
-            }
-        }
-        impl<'a, T: Copy> Drop for DropChecker<'a, T> {
-            fn drop(&mut self) {
-                self.dropped.borrow_mut().push(self.value);
+    #[cfg(feature = "debug-tools")]
+    fn list_dump_dot() {
+        for len in 0..4 {
+            let list = List::from_iter((0..len).map(|i| format!("a\"{}", i)));
+            let mut dot = String::new();
+            list.dump_dot(&mut dot).unwrap();
+            assert!(dot.starts_with("digraph List {\n"));
+            assert!(dot.ends_with("}\n"));
+            assert_eq!(dot.matches("shape=doublecircle").count(), 1);
+            assert_eq!(dot.matches(r#"[label="next"]"#).count(), len + 1);
+            assert_eq!(dot.matches("style=dashed").count(), len + 1);
+            // The quotes and backslashes of the `Debug` output are escaped.
+            for i in 0..len {
+                assert!(dot.contains(&format!(r#"[label="\"a\\\"{}\"\n"#, i)));
             }
         }
-        let dropped = RefCell::new(Vec::<i32>::new());
-        let mut list = List::new();
-        list.push_back(DropChecker::new(1, &dropped));
-        list.push_back(DropChecker::new(2, &dropped));
-        list.push_back(DropChecker::new(3, &dropped));
-        drop(list);
-        assert_eq!(dropped.borrow().as_slice(), &[1, 2, 3]);
     }
 
     #[test]
-    fn list_push_and_pop() {
-        let mut list = List::new();
-        assert!(list.is_empty());
-        #[cfg(feature = "length")]
-        assert_eq!(list.len(), 0);
-
-        assert_eq!(list.front(), None);
-        assert_eq!(list.back(), None);
-        assert_eq!(list.pop_front(), None);
-        assert_eq!(list.pop_back(), None);
-
-        list.push_back(1);
-        assert_eq!(list.back(), Some(&1));
-        assert_eq!(list.pop_front(), Some(1));
-        assert_eq!(list.pop_back(), None);
-        assert!(list.is_empty());
-        #[cfg(feature = "length")]
-        assert_eq!(list.len(), 0);
+    fn list_copy_to_slice() {
+        for len in 0..4 {
+            let list = List::from_iter(0..len);
+            let mut buf = vec![0; len];
+            list.copy_to_slice(&mut buf);
+            assert_eq!(buf, Vec::from_iter(0..len));
 
-        list.push_front(1);
-        list.push_front(2);
-        list.push_back(3);
-        assert_eq!(list.back(), Some(&3));
-        assert_eq!(list.front(), Some(&2));
-        assert_eq!(list.pop_front(), Some(2));
-        assert_eq!(list.pop_back(), Some(3));
+            let list = List::from_iter((0..len).map(|i| i.to_string()));
+            let mut buf = vec![String::new(); len];
+            list.clone_into_slice(&mut buf);
+            assert!(list.iter().eq(&buf));
+        }
+    }
 
-        assert_eq!(list.front(), Some(&1));
-        assert_eq!(list.pop_front(), Some(1));
-        assert_eq!(list.front(), None);
-        assert_eq!(list.back(), None);
-        assert!(list.is_empty());
-        #[cfg(feature = "length")]
-        assert_eq!(list.len(), 0);
+    #[test]
+    #[should_panic(expected = "Cannot copy a list into a slice of a different length")]
+    fn list_copy_to_slice_mismatched() {
+        List::from_iter(0..3).copy_to_slice(&mut [0; 2]);
     }
 
     #[test]
-    fn list_insert_and_remove() {
-        fn list_eq<T, I>(list: &List<T>, expected: I)
-        where
-            T: Debug + Clone + Eq,
-            I: IntoIterator<Item = T>,
-        {
-            assert_eq!(
-                Vec::from_iter(list.iter().cloned()),
-                Vec::from_iter(expected)
-            );
+    fn list_extend_front() {
+        for len in 0..4 {
+            for n in 0..4 {
+                let mut list = List::from_iter(n..n + len);
+                list.extend_front(0..n);
+                assert!(list.iter().copied().eq(0..n + len));
+                assert!(list.iter().rev().copied().eq((0..n + len).rev()));
+                #[cfg(feature = "length")]
+                assert_eq!(list.len(), n + len);
+            }
         }
+    }
 
-        let mut list = List::from_iter(0..10);
-        list.insert(5, 10);
-        list_eq(&list, (0..5).chain(Some(10)).chain(5..10));
+    #[test]
+    fn list_clone_from() {
+        for len in 0..5 {
+            for other_len in 0..5 {
+                let mut list = List::from_iter((0..len).map(|i| vec![i; 4]));
+                let other = List::from_iter((10..10 + other_len).map(|i| vec![i]));
+                // The elements in the common prefix keep their buffers.
+                let buffers = Vec::from_iter(list.iter().take(other_len).map(|v| v.as_ptr()));
+                list.clone_from(&other);
+                assert_eq!(list, other);
+                assert!(list.iter().rev().eq(other.iter().rev()));
+                #[cfg(feature = "length")]
+                assert_eq!(list.len(), other_len);
+                assert!(list
+                    .iter()
+                    .map(|v| v.as_ptr())
+                    .zip(buffers)
+                    .all(|(a, b)| a == b));
+            }
+        }
+    }
 
-        assert_eq!(list.remove(10), 9);
-        assert_eq!(list.back(), Some(&8));
-        list_eq(&list, (0..5).chain(Some(10)).chain(5..9));
+    #[test]
+    fn list_swap_ends() {
+        for len in 0..5 {
+            let mut list = List::from_iter(0..len);
+            let handles = Vec::from_iter((0..len).map(|i| list.cursor(i).handle().unwrap()));
+            list.swap_ends();
+            let mut expected = Vec::from_iter(0..len);
+            if len > 0 {
+                expected.swap(0, len - 1);
+            }
+            assert_eq!(Vec::from_iter(list.iter().copied()), expected);
+            assert!(list.iter().rev().copied().eq(expected.into_iter().rev()));
+            assert!(handles.iter().all(|handle| list.contains_handle(handle)));
+        }
+    }
 
-        list.insert(0, 11);
-        assert_eq!(list.front(), Some(&11));
-        list_eq(&list, (11..=11).chain((0..5).chain(Some(10)).chain(5..9)));
+    #[test]
+    #[should_panic(expected = "Cannot swap at an index outside of the list bounds")]
+    fn list_swap_nodes_out_of_bounds() {
+        List::from_iter(0..3).swap_nodes(1, 3);
+    }
 
-        assert_eq!(list.remove(0), 11);
-        assert_eq!(list.front(), Some(&0));
-        list_eq(&list, (0..5).chain(Some(10)).chain(5..9));
+    #[test]
+    fn list_split_at() {
+        for len in 0..5 {
+            for at in 0..=len {
+                let (front, back) = List::from_iter(0..len).split_at(at);
+                assert!(front.iter().copied().eq(0..at));
+                assert!(front.iter().rev().copied().eq((0..at).rev()));
+                assert!(back.iter().copied().eq(at..len));
+                assert!(back.iter().rev().copied().eq((at..len).rev()));
+                #[cfg(feature = "length")]
+                assert_eq!((front.len(), back.len()), (at, len - at));
+            }
+        }
+    }
 
-        list.insert(10, 12);
-        assert_eq!(list.back(), Some(&12));
-        list_eq(&list, (0..5).chain(Some(10)).chain(5..9).chain(Some(12)));
+    #[test]
+    #[cfg(feature = "length")]
+    #[should_panic(expected = "Cannot split off at a nonexistent index")]
+    fn list_split_at_out_of_bounds() {
+        List::from_iter(0..3).split_at(4);
     }
 
     #[test]
@@ -1240,6 +5205,7 @@ mod tests {
         }
         test_list_splice(0..5, 5..7, 5, 0..7);
         test_list_splice(0..5, 5..7, 2, (0..2).chain(5..7).chain(2..5));
+        test_list_splice(0..5, 5..7, 4, (0..4).chain(5..7).chain(4..5));
         test_list_splice(0..5, 5..7, 0, (5..7).chain(0..5));
         test_list_splice(0..5, Some(5), 5, 0..6);
         test_list_splice(0..5, Some(5), 2, (0..2).chain(Some(5)).chain(2..5));
@@ -1251,6 +5217,317 @@ mod tests {
         test_list_splice::<i32, _, _, _>(None, None, 0, None);
     }
 
+    #[test]
+    fn list_rotate() {
+        fn test_list_rotate(len: usize, k: usize) {
+            let mut vec = Vec::from_iter(0..len);
+            let mut list = List::from_iter(0..len);
+            vec.rotate_left(k);
+            list.rotate_left(k);
+            assert_eq!(list, List::from_iter(vec.iter().copied()));
+            assert!(list.iter().rev().eq(vec.iter().rev()));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), len);
+
+            vec.rotate_right(k);
+            list.rotate_right(k);
+            assert_eq!(list, List::from_iter(0..len));
+            assert!(list.iter().rev().eq(vec.iter().rev()));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), len);
+        }
+        test_list_rotate(10, 0);
+        test_list_rotate(10, 1);
+        test_list_rotate(10, 3);
+        test_list_rotate(10, 8);
+        test_list_rotate(10, 9);
+        test_list_rotate(10, 10);
+        test_list_rotate(2, 1);
+        test_list_rotate(1, 1);
+        test_list_rotate(1, 0);
+        test_list_rotate(0, 0);
+    }
+
+    #[test]
+    fn list_reverse() {
+        fn test_list_reverse(len: usize) {
+            let mut list = List::from_iter(0..len);
+            list.reverse();
+            assert_eq!(list, List::from_iter((0..len).rev()));
+            assert!(list.iter().rev().eq((0..len).collect::<Vec<_>>().iter()));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), len);
+            list.push_back(len);
+            list.push_front(len + 1);
+            assert_eq!(list.front(), Some(&(len + 1)));
+            assert_eq!(list.back(), Some(&len));
+        }
+        test_list_reverse(10);
+        test_list_reverse(2);
+        test_list_reverse(1);
+        test_list_reverse(0);
+    }
+
+    #[test]
+    fn list_truncate() {
+        fn test_list_truncate(len: usize, at: usize) {
+            let mut list = List::from_iter(0..len);
+            list.truncate(at);
+            assert_eq!(list, List::from_iter(0..at.min(len)));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), at.min(len));
+
+            let mut list = List::from_iter(0..len);
+            list.truncate_front(at);
+            assert_eq!(list, List::from_iter(len.saturating_sub(at)..len));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), at.min(len));
+        }
+        test_list_truncate(10, 0);
+        test_list_truncate(10, 3);
+        test_list_truncate(10, 9);
+        test_list_truncate(10, 10);
+        test_list_truncate(10, 11);
+        test_list_truncate(1, 0);
+        test_list_truncate(1, 1);
+        test_list_truncate(0, 0);
+        test_list_truncate(0, 1);
+
+        // The rest of the truncated elements are dropped if a drop panics.
+        let dropped = RefCell::new(Vec::new());
+        struct Dropped<'a>(&'a RefCell<Vec<i32>>, i32);
+        impl Drop for Dropped<'_> {
+            fn drop(&mut self) {
+                self.0.borrow_mut().push(self.1);
+                if self.1 == 5 {
+                    panic!("drop panics");
+                }
+            }
+        }
+        let mut list = List::from_iter((0..8).map(|i| Dropped(&dropped, i)));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.truncate(3);
+        }));
+        assert!(result.is_err());
+        assert_eq!(*dropped.borrow(), vec![3, 4, 5, 6, 7]);
+        assert!(list.iter().map(|x| x.1).eq(0..3));
+    }
+
+    #[test]
+    fn list_splice_range() {
+        fn test_list_splice_range<R>(len: usize, range: R, other: std::ops::Range<usize>)
+        where
+            R: std::ops::RangeBounds<usize> + Clone,
+        {
+            let mut vec = Vec::from_iter(0..len);
+            let mut list = List::from_iter(0..len);
+            let removed = list.splice_range(range.clone(), List::from_iter(other.clone()));
+            assert_eq!(
+                Vec::from_iter(removed),
+                Vec::from_iter(vec.splice(range, other))
+            );
+            assert_eq!(list, List::from_iter(vec.iter().copied()));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), vec.len());
+        }
+        test_list_splice_range(10, 3..7, 10..12);
+        test_list_splice_range(10, 3..7, 0..0);
+        test_list_splice_range(10, 3..3, 10..12);
+        test_list_splice_range(10, .., 10..12);
+        test_list_splice_range(10, 8.., 10..15);
+        test_list_splice_range(10, ..2, 10..15);
+        test_list_splice_range(0, .., 10..12);
+        test_list_splice_range(0, .., 0..0);
+    }
+
+    #[test]
+    fn list_drain_range() {
+        fn test_list_drain_range<R>(len: usize, range: R)
+        where
+            R: std::ops::RangeBounds<usize> + Clone,
+        {
+            let mut vec = Vec::from_iter(0..len);
+            let mut list = List::from_iter(0..len);
+            let drained = Vec::from_iter(list.drain_range(range.clone()));
+            assert_eq!(drained, Vec::from_iter(vec.drain(range)));
+            assert_eq!(list, List::from_iter(vec.iter().copied()));
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), vec.len());
+        }
+        test_list_drain_range(10, 3..7);
+        test_list_drain_range(10, 0..10);
+        test_list_drain_range(10, ..);
+        test_list_drain_range(10, 5..);
+        test_list_drain_range(10, ..=4);
+        test_list_drain_range(10, 4..4);
+        test_list_drain_range(10, 10..10);
+        test_list_drain_range(1, 0..1);
+        test_list_drain_range(0, ..);
+    }
+
+    #[test]
+    fn list_compact() {
+        fn test_list_compact<T, I>(input: I)
+        where
+            T: Clone + Eq + Debug,
+            I: IntoIterator<Item = T>,
+        {
+            let mut list = List::from_iter(input);
+            let expected = list.clone();
+            list.compact();
+            assert_eq!(list, expected);
+            #[cfg(feature = "length")]
+            assert_eq!(list.len(), expected.len());
+            assert_eq!(
+                list.iter().rev().collect::<Vec<_>>(),
+                expected.iter().rev().collect::<Vec<_>>()
+            );
+        }
+        test_list_compact(0..10);
+        test_list_compact((0..5).map(|i| i.to_string()));
+        test_list_compact(Some(0));
+        test_list_compact::<i32, _>(None);
+    }
+
+    #[test]
+    fn list_new_in() {
+        /// Counts the live allocations made through it.
+        #[derive(Clone)]
+        struct Counting(Rc<Cell<usize>>);
+
+        impl PartialEq for Counting {
+            fn eq(&self, other: &Self) -> bool {
+                Rc::ptr_eq(&self.0, &other.0)
+            }
+        }
+
+        unsafe impl RawAlloc for Counting {
+            fn allocate(&self, layout: Layout) -> NonNull<u8> {
+                self.0.set(self.0.get() + 1);
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                self.0.set(self.0.get() - 1);
+                Global.deallocate(ptr, layout)
+            }
+        }
+
+        let live = Rc::new(Cell::new(0));
+        let mut list = List::new_in(Counting(live.clone()));
+        assert_eq!(live.get(), 1);
+        list.extend((0..10).map(|i| i.to_string()));
+        list.extend_front(["a".to_string()]);
+        list.cursor_mut(3)
+            .insert_iter(["b".to_string(), "c".to_string()]);
+        assert_eq!(live.get(), 14);
+
+        let mut back = list.split_off(8);
+        assert_eq!(live.get(), 15);
+        list.truncate(6);
+        list.truncate_front(5);
+        assert_eq!(live.get(), 12);
+        list.append(&mut back);
+        drop(back);
+        assert_eq!(live.get(), 11);
+
+        list.compact();
+        list.remove_all(&"6".to_string());
+        assert_eq!(live.get(), 10);
+        assert_eq!(list.clone(), list);
+        assert_eq!(live.get(), 10);
+
+        let lens = list.map(|s| s.len());
+        assert_eq!(live.get(), 10);
+        let mapped = lens.try_map(|len| if len < 2 { Ok(len as u8) } else { Err(len) });
+        assert_eq!(mapped.unwrap().into_vec(), vec![1; 9]);
+        assert_eq!(live.get(), 0);
+
+        let mut list = List::new_in(Counting(live.clone()));
+        list.extend(0..4);
+        // The nodes are reused for the same layout.
+        let list = list.map(|i| i as u32 * 2);
+        assert_eq!(live.get(), 5);
+        assert_eq!(list.try_map(|i| if i < 4 { Ok(i) } else { Err(i) }), Err(4));
+        assert_eq!(live.get(), 0);
+    }
+
+    /// Records the live blocks allocated through it, and checks that each
+    /// block is deallocated by an equal allocator exactly once.
+    #[derive(Clone, Default)]
+    struct Tracking(Rc<RefCell<HashSet<usize>>>);
+
+    impl PartialEq for Tracking {
+        fn eq(&self, other: &Self) -> bool {
+            Rc::ptr_eq(&self.0, &other.0)
+        }
+    }
+
+    unsafe impl RawAlloc for Tracking {
+        fn allocate(&self, layout: Layout) -> NonNull<u8> {
+            let ptr = Global.allocate(layout);
+            assert!(self.0.borrow_mut().insert(ptr.as_ptr() as usize));
+            ptr
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            assert!(
+                self.0.borrow_mut().remove(&(ptr.as_ptr() as usize)),
+                "Deallocating a block not allocated by this allocator"
+            );
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    #[test]
+    fn list_alloc_tracking() {
+        let alloc = Tracking::default();
+        {
+            let mut list = List::from_iter_in((0..10).map(|i| i.to_string()), alloc.clone());
+            let mut other = List::new_in(alloc.clone());
+            other.extend_front(["a".to_string(), "b".to_string()]);
+            list.prepend(&mut other);
+            list.cursor_mut(4).splice(other.split_off(0));
+
+            let mut back = list.split_off(6);
+            back.remove_all(&"7".to_string());
+            list.drain_matching_into(|s| s.len() > 1, &mut back);
+            list.truncate(3);
+            back.truncate_front(4);
+            let list = List::concat([list, back, other]);
+            let mut cloned = list.clone();
+            cloned.dedup();
+            cloned.append(&mut list.clone());
+
+            let mut iter = cloned.into_iter();
+            iter.next();
+            iter.next_back();
+            assert!(!alloc.0.borrow().is_empty());
+        }
+        assert!(alloc.0.borrow().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot move nodes between lists with different allocators")]
+    fn list_append_different_allocs() {
+        let mut list = List::new_in(Tracking::default());
+        list.push_back(1);
+        let mut other = List::new_in(Tracking::default());
+        other.push_back(2);
+        list.append(&mut other);
+    }
+
+    #[cfg(feature = "paranoid")]
+    #[test]
+    #[should_panic(expected = "does not belong to the list")]
+    fn list_paranoid_foreign_node() {
+        let mut list = List::from_iter(0..3);
+        let other = List::from_iter(0..3);
+        unsafe {
+            list.detach_node(other.front_node());
+        }
+    }
+
     #[cfg(feature = "length")]
     #[test]
     fn list_len() {
@@ -0,0 +1,554 @@
+use crate::list::alloc::{Global, RawAlloc};
+use crate::list::{List, Node};
+use crate::{Iter, IterMut};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// A borrowed view of a contiguous segment of a [`List`].
+///
+/// A `ListView` is like a slice of a list. It uses a pair of nodes `start..end`
+/// to represent a half-open subrange of the list, so functions can accept a
+/// piece of a list without splitting it out.
+///
+/// It is created by [`List::view`], [`Cursor::view_to`] or [`Iter::remaining`].
+/// A whole list can also be viewed by `ListView::from(&list)`.
+///
+/// # Examples
+///
+/// ```
+/// use cyclic_list::{List, ListView};
+/// use std::iter::FromIterator;
+///
+/// fn sum(view: ListView<'_, i32>) -> i32 {
+///     view.iter().sum()
+/// }
+///
+/// let list = List::from_iter([1, 2, 3, 4, 5]);
+/// let view = list.view(1..4);
+///
+/// #[cfg(feature = "length")]
+/// assert_eq!(view.len(), 3);
+/// assert_eq!(view.front(), Some(&2));
+/// assert_eq!(view.back(), Some(&4));
+/// assert_eq!(sum(view), 9);
+/// assert_eq!(sum(ListView::from(&list)), 15);
+///
+/// // Views are compared by their elements only.
+/// let other = List::from_iter([0, 2, 3, 4]);
+/// assert_eq!(view, other.view(1..));
+/// assert!(view < list.view(2..));
+/// ```
+///
+/// [`Cursor::view_to`]: crate::list::cursor::Cursor::view_to
+pub struct ListView<'a, T: 'a> {
+    start: NonNull<Node<T>>,
+    end: NonNull<Node<T>>,
+    #[cfg(feature = "length")]
+    len: usize,
+    _marker: PhantomData<&'a List<T>>,
+}
+
+impl<'a, T: 'a> ListView<'a, T> {
+    /// Create a view of the range `start..end` with `len` elements.
+    ///
+    /// It is unsafe because `start..end` must be a valid range of a list
+    /// that is borrowed for `'a`.
+    pub(crate) unsafe fn from_range(
+        start: NonNull<Node<T>>,
+        end: NonNull<Node<T>>,
+        #[cfg(feature = "length")] len: usize,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            #[cfg(feature = "length")]
+            len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Return an iterator over the elements of the view.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn iter(&self) -> Iter<'a, T> {
+        // SAFETY: `start..end` is a valid range of a list borrowed for `'a`.
+        unsafe {
+            Iter::from_range(
+                self.start,
+                self.end,
+                #[cfg(feature = "length")]
+                self.len,
+            )
+        }
+    }
+
+    /// Return the number of elements in the view.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[cfg(feature = "length")]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the view contains no elements.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Return a reference to the first element of the view,
+    /// or `None` if the view is empty.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn front(&self) -> Option<&'a T> {
+        self.iter().next()
+    }
+
+    /// Return a reference to the last element of the view,
+    /// or `None` if the view is empty.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn back(&self) -> Option<&'a T> {
+        self.iter().next_back()
+    }
+
+    /// Return `true` if the view contains an element equal to the given value.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|e| e == x)
+    }
+
+    /// Clone the elements of the view into a new list.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    pub fn to_list(&self) -> List<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<'a, T: 'a, A: RawAlloc> From<&'a List<T, A>> for ListView<'a, T> {
+    fn from(list: &'a List<T, A>) -> Self {
+        // SAFETY: `front..ghost` is the whole range of the list.
+        unsafe {
+            Self::from_range(
+                list.front_node(),
+                list.ghost_node(),
+                #[cfg(feature = "length")]
+                list.len(),
+            )
+        }
+    }
+}
+
+// Not derived, since copying a view does not require `T: Copy`.
+impl<T> Clone for ListView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListView<'_, T> {}
+
+impl<'a, T: fmt::Debug + 'a> fmt::Debug for ListView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for ListView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, 'b, T: PartialEq> PartialEq<ListView<'b, T>> for ListView<'a, T> {
+    fn eq(&self, other: &ListView<'b, T>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ListView<'_, T> {}
+
+impl<T: PartialEq, A: RawAlloc> PartialEq<List<T, A>> for ListView<'_, T> {
+    fn eq(&self, other: &List<T, A>) -> bool {
+        self.iter().eq(other)
+    }
+}
+
+impl<T: PartialEq, A: RawAlloc> PartialEq<ListView<'_, T>> for List<T, A> {
+    fn eq(&self, other: &ListView<'_, T>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, 'b, T: PartialOrd> PartialOrd<ListView<'b, T>> for ListView<'a, T> {
+    fn partial_cmp(&self, other: &ListView<'b, T>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for ListView<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashed in the same way as a [`List`] with the same elements.
+impl<T: Hash> Hash for ListView<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut len = 0_usize;
+        for elt in self.iter() {
+            elt.hash(state);
+            len += 1;
+        }
+        len.hash(state);
+    }
+}
+
+unsafe impl<T: Sync> Send for ListView<'_, T> {}
+
+unsafe impl<T: Sync> Sync for ListView<'_, T> {}
+
+/// A mutable borrowed view of a contiguous segment of a [`List`].
+///
+/// A `ListViewMut` is like a mutable slice of a list. The elements in the view
+/// can be mutated, but the linking structure of the list cannot be changed, so
+/// the rest of the list is statically protected while the view is alive.
+///
+/// It is created by [`List::view_mut`].
+///
+/// # Examples
+///
+/// ```
+/// use cyclic_list::List;
+/// use std::iter::FromIterator;
+///
+/// let mut list = List::from_iter([1, 2, 3, 4, 5]);
+/// let mut view = list.view_mut(1..4);
+///
+/// for x in view.iter_mut() {
+///     *x *= 10;
+/// }
+/// view.swap(0, 2);
+/// *view.back_mut().unwrap() += 1;
+///
+/// assert_eq!(Vec::from_iter(list), vec![1, 40, 30, 21, 5]);
+/// ```
+pub struct ListViewMut<'a, T: 'a, A: RawAlloc = Global> {
+    start: NonNull<Node<T>>,
+    end: NonNull<Node<T>>,
+    #[cfg(feature = "length")]
+    len: usize,
+    /// The index of `start`.
+    #[cfg(feature = "length")]
+    index: usize,
+    list: &'a mut List<T, A>,
+}
+
+impl<'a, T: 'a, A: RawAlloc> ListViewMut<'a, T, A> {
+    /// Create a mutable view of the range `start..end` of `list` with `len`
+    /// elements, where `index` is the index of `start`.
+    ///
+    /// It is unsafe because `start..end` must be a valid range of `list`.
+    pub(crate) unsafe fn from_range(
+        list: &'a mut List<T, A>,
+        start: NonNull<Node<T>>,
+        end: NonNull<Node<T>>,
+        #[cfg(feature = "length")] len: usize,
+        #[cfg(feature = "length")] index: usize,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            #[cfg(feature = "length")]
+            len,
+            #[cfg(feature = "length")]
+            index,
+            list,
+        }
+    }
+
+    /// Re-borrow the mutable view as a short-lived immutable one.
+    pub fn as_view(&self) -> ListView<'_, T> {
+        // SAFETY: `start..end` is a valid range of the borrowed list.
+        unsafe {
+            ListView::from_range(
+                self.start,
+                self.end,
+                #[cfg(feature = "length")]
+                self.len,
+            )
+        }
+    }
+
+    /// Convert the mutable view to an immutable one.
+    pub fn into_view(self) -> ListView<'a, T> {
+        // SAFETY: `start..end` is a valid range of the list borrowed for `'a`.
+        unsafe {
+            ListView::from_range(
+                self.start,
+                self.end,
+                #[cfg(feature = "length")]
+                self.len,
+            )
+        }
+    }
+
+    /// Return an iterator over the elements of the view.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_view().iter()
+    }
+
+    /// Return an iterator over the elements of the view with mutable references.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, A> {
+        // SAFETY: `start..end` is a valid range of the borrowed list.
+        unsafe {
+            IterMut::from_range(
+                self.list,
+                self.start,
+                self.end,
+                #[cfg(feature = "length")]
+                self.len,
+                #[cfg(feature = "length")]
+                self.index,
+            )
+        }
+    }
+
+    /// Return the number of elements in the view.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[cfg(feature = "length")]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the view contains no elements.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Return a reference to the first element of the view,
+    /// or `None` if the view is empty.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn front(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    /// Return a reference to the last element of the view,
+    /// or `None` if the view is empty.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn back(&self) -> Option<&T> {
+        self.iter().next_back()
+    }
+
+    /// Return a mutable reference to the first element of the view,
+    /// or `None` if the view is empty.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.iter_mut().next()
+    }
+
+    /// Return a mutable reference to the last element of the view,
+    /// or `None` if the view is empty.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.iter_mut().next_back()
+    }
+
+    /// Fill the view with clones of `value`.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4]);
+    /// list.view_mut(2..).fill(0);
+    /// assert_eq!(Vec::from_iter(list), vec![1, 2, 0, 0]);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.iter_mut().for_each(|item| item.clone_from(&value));
+    }
+
+    /// Fill the view with values returned by calling a closure repeatedly.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter([1, 2, 3, 4]);
+    /// let mut next = 10;
+    /// list.view_mut(1..3).fill_with(|| {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(Vec::from_iter(list), vec![1, 11, 12, 4]);
+    /// ```
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.iter_mut().for_each(|item| *item = f());
+    }
+
+    /// Swap two elements in the view, where `a` and `b` are indices relative
+    /// to the start of the view.
+    ///
+    /// The elements are swapped in place, and the nodes stay put.
+    ///
+    /// # Complexity
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclic_list::List;
+    /// use std::iter::FromIterator;
+    ///
+    /// let mut list = List::from_iter(["a", "b", "c", "d", "e"]);
+    /// list.view_mut(1..4).swap(0, 2);
+    /// assert_eq!(Vec::from_iter(list), vec!["a", "d", "c", "b", "e"]);
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let node_a = self.node_at(a);
+        let node_b = self.node_at(b);
+        if node_a != node_b {
+            // SAFETY: `node_a` and `node_b` are different nodes in the view,
+            // so both of them hold a valid element and they do not overlap.
+            unsafe {
+                std::ptr::swap(
+                    &mut (*node_a.as_ptr()).element,
+                    &mut (*node_b.as_ptr()).element,
+                )
+            };
+        }
+    }
+
+    /// Return the node at index `at` relative to the start of the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is out of bounds of the view.
+    fn node_at(&self, at: usize) -> NonNull<Node<T>> {
+        #[cfg(feature = "length")]
+        assert!(
+            at < self.len,
+            "Cannot swap at an index outside of the view bounds"
+        );
+        let mut node = self.start;
+        for _ in 0..=at {
+            assert!(
+                node != self.end,
+                "Cannot swap at an index outside of the view bounds"
+            );
+            // SAFETY: `node` is a node in the view before `end`.
+            node = unsafe { node.as_ref().next };
+        }
+        // SAFETY: the same as above, and `node` is not `start`.
+        unsafe { node.as_ref().prev }
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a, A: RawAlloc> fmt::Debug for ListViewMut<'a, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: 'a, A: RawAlloc> IntoIterator for ListViewMut<'a, T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // SAFETY: `start..end` is a valid range of the list borrowed for `'a`.
+        unsafe {
+            IterMut::from_range(
+                self.list,
+                self.start,
+                self.end,
+                #[cfg(feature = "length")]
+                self.len,
+                #[cfg(feature = "length")]
+                self.index,
+            )
+        }
+    }
+}
+
+unsafe impl<T: Send, A: RawAlloc + Send> Send for ListViewMut<'_, T, A> {}
+
+unsafe impl<T: Sync, A: RawAlloc + Sync> Sync for ListViewMut<'_, T, A> {}